@@ -0,0 +1,107 @@
+//! Criterion benchmarks for a representative slice of the library's core
+//! algorithms: one graph, one sort, one string, and one tree algorithm.
+//! Run with `cargo bench`.
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use dsa::collections::bk_tree::BkTree;
+use dsa::collections::skip_list::SkipList;
+use dsa::graph::dijkstra::dijkstra;
+use dsa::graph::types::Edge;
+use dsa::sorting::introsort::introsort;
+use dsa::string::myers_levenshtein::edit_distance_dp;
+use dsa::string::rabin_karp::rabin_karp;
+use dsa::tree::utilities::diameter;
+
+fn bench_dijkstra(c: &mut Criterion) {
+    let n = 200;
+    let mut graph = vec![Vec::new(); n];
+    for i in 0..n - 1 {
+        graph[i].push(Edge { to: i + 1, weight: 1 });
+        graph[i + 1].push(Edge { to: i, weight: 1 });
+    }
+    c.bench_function("dijkstra_chain_200", |b| b.iter(|| dijkstra(black_box(&graph), 0)));
+}
+
+fn bench_introsort(c: &mut Criterion) {
+    let data: Vec<i64> = (0..2000).rev().collect();
+    c.bench_function("introsort_2000_reverse", |b| {
+        b.iter(|| {
+            let mut v = data.clone();
+            introsort(black_box(&mut v));
+            v
+        })
+    });
+}
+
+fn bench_rabin_karp(c: &mut Criterion) {
+    let text = "ab".repeat(5000);
+    c.bench_function("rabin_karp_10000", |b| b.iter(|| rabin_karp(black_box(&text), "abab")));
+}
+
+fn bench_tree_diameter(c: &mut Criterion) {
+    let n = 2000;
+    let mut adj = vec![Vec::new(); n];
+    for i in 1..n {
+        adj[i].push(i - 1);
+        adj[i - 1].push(i);
+    }
+    c.bench_function("tree_diameter_chain_2000", |b| b.iter(|| diameter(black_box(&adj))));
+}
+
+fn dictionary_words() -> Vec<String> {
+    (0..500).map(|i| format!("word{i:04}")).collect()
+}
+
+fn bench_bk_tree_lookup(c: &mut Criterion) {
+    let words = dictionary_words();
+    let mut tree = BkTree::new();
+    for word in &words {
+        tree.insert(word.as_bytes());
+    }
+    c.bench_function("bk_tree_find_within_2_of_500", |b| b.iter(|| tree.find_within(black_box(b"wrod0250"), 2)));
+}
+
+fn bench_linear_scan_lookup(c: &mut Criterion) {
+    let words = dictionary_words();
+    c.bench_function("linear_scan_find_within_2_of_500", |b| {
+        b.iter(|| {
+            words
+                .iter()
+                .filter(|w| edit_distance_dp(w.as_bytes(), black_box(b"wrod0250")) <= 2)
+                .count()
+        })
+    });
+}
+
+fn bench_skip_list_lookup(c: &mut Criterion) {
+    let mut list = SkipList::new();
+    for v in 0..2000i64 {
+        list.insert(v);
+    }
+    c.bench_function("skip_list_contains_2000", |b| b.iter(|| list.contains(black_box(&1500))));
+}
+
+fn bench_btreemap_lookup(c: &mut Criterion) {
+    let mut map = BTreeMap::new();
+    for v in 0..2000i64 {
+        map.insert(v, ());
+    }
+    c.bench_function("btreemap_contains_key_2000", |b| b.iter(|| map.contains_key(black_box(&1500))));
+}
+
+criterion_group!(
+    benches,
+    bench_dijkstra,
+    bench_introsort,
+    bench_rabin_karp,
+    bench_tree_diameter,
+    bench_bk_tree_lookup,
+    bench_linear_scan_lookup,
+    bench_skip_list_lookup,
+    bench_btreemap_lookup
+);
+criterion_main!(benches);
@@ -54,7 +54,7 @@ fn astar(
     goal: Point,
     width: i32,
     height: i32,
-    obstacles: &Vec<Point>,
+    obstacles: &[Point],
 ) -> Option<Vec<Point>> {
     let mut open_set = BinaryHeap::new();
     open_set.push(Node {
@@ -0,0 +1,20 @@
+use dsa::tree::ahu_tree_hash::{ahu_label, rooted_isomorphic};
+
+fn main() {
+    // Tree A:     0          Tree B:   0
+    //           / | \                / | \
+    //          1  2  3              1  2  3
+    //          |                       |
+    //          4                       4
+    let adj_a = vec![vec![1, 2, 3], vec![0, 4], vec![0], vec![0], vec![1]];
+    let adj_b = vec![vec![1, 2, 3], vec![0], vec![0, 4], vec![0], vec![2]];
+
+    println!("A and B isomorphic (rooted at 0): {}", rooted_isomorphic(&adj_a, 0, &adj_b, 0));
+
+    // Tree C: same shape as A but the branch with the grandchild is under
+    // a different child, still isomorphic as an unordered rooted tree.
+    let adj_c = vec![vec![1, 2, 3], vec![0], vec![0], vec![0, 4], vec![3]];
+    println!("A and C isomorphic (rooted at 0): {}", rooted_isomorphic(&adj_a, 0, &adj_c, 0));
+
+    println!("single node self-label: {}", ahu_label(&[vec![]], 0));
+}
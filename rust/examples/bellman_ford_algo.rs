@@ -0,0 +1,32 @@
+use dsa::graph::bellman_ford::bellman_ford;
+use dsa::graph::types::WeightedEdge;
+
+fn main() {
+    // Example graph (directed, weighted)
+    let vertices = 5;
+    let edges = vec![
+        WeightedEdge { from: 0, to: 1, weight: -1 },
+        WeightedEdge { from: 0, to: 2, weight: 4 },
+        WeightedEdge { from: 1, to: 2, weight: 3 },
+        WeightedEdge { from: 1, to: 3, weight: 2 },
+        WeightedEdge { from: 1, to: 4, weight: 2 },
+        WeightedEdge { from: 3, to: 2, weight: 5 },
+        WeightedEdge { from: 3, to: 1, weight: 1 },
+        WeightedEdge { from: 4, to: 3, weight: -3 },
+    ];
+
+    let source = 0;
+    match bellman_ford(vertices, &edges, source) {
+        Some(distances) => {
+            println!("Vertex\tDistance from Source ({})", source);
+            for (i, &d) in distances.iter().enumerate() {
+                if d == f64::INFINITY {
+                    println!("{}\t∞", i);
+                } else {
+                    println!("{}\t{:.1}", i, d);
+                }
+            }
+        }
+        None => println!("Negative weight cycle detected, no valid shortest paths!"),
+    }
+}
@@ -0,0 +1,11 @@
+use dsa::math::berlekamp_massey::berlekamp_massey;
+
+fn main() {
+    // Fibonacci mod MOD: recurrence should be [1, 1].
+    let mut fib = vec![0i64, 1];
+    for i in 2..10 {
+        fib.push(fib[i - 1] + fib[i - 2]);
+    }
+    println!("sequence:   {:?}", fib);
+    println!("recurrence: {:?}", berlekamp_massey(&fib));
+}
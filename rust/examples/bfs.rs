@@ -0,0 +1,15 @@
+use dsa::graph::bfs::bfs;
+
+fn main() {
+    let adj = vec![
+        vec![1, 2],
+        vec![0, 3],
+        vec![0, 3],
+        vec![1, 2, 4],
+        vec![3],
+    ];
+    let order = bfs(&adj, 0);
+    for v in order {
+        println!("{}", v);
+    }
+}
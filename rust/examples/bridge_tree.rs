@@ -0,0 +1,184 @@
+// Cycle space and bridge tree of an undirected graph. The cycle space is
+// the GF(2) vector space spanned by the graph's simple cycles; a spanning
+// forest gives a basis directly, one fundamental cycle per non-tree edge.
+// The bridge tree contracts each 2-edge-connected component to a point
+// and connects them by the bridges, giving a tree whose edges are
+// exactly the cut edges of the original graph.
+
+/// Builds adjacency lists that also record each edge's index in `edges`,
+/// so DFS can recognize "the edge we just came from" without comparing
+/// endpoints (needed when there are parallel edges).
+fn build_adjacency(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+    let mut adj = vec![Vec::new(); n];
+    for (id, &(u, v)) in edges.iter().enumerate() {
+        adj[u].push((v, id));
+        adj[v].push((u, id));
+    }
+    adj
+}
+
+/// Tarjan's bridge-finding DFS: edge `id` is a bridge iff the subtree
+/// below it has no back edge reaching its top endpoint or above.
+fn find_bridges(n: usize, edges: &[(usize, usize)]) -> Vec<bool> {
+    let adj = build_adjacency(n, edges);
+    let mut disc = vec![usize::MAX; n];
+    let mut low = vec![usize::MAX; n];
+    let mut is_bridge = vec![false; edges.len()];
+    let mut timer = 0;
+
+    fn dfs(
+        u: usize,
+        from_edge: usize,
+        adj: &[Vec<(usize, usize)>],
+        disc: &mut [usize],
+        low: &mut [usize],
+        is_bridge: &mut [bool],
+        timer: &mut usize,
+    ) {
+        disc[u] = *timer;
+        low[u] = *timer;
+        *timer += 1;
+        for &(v, id) in &adj[u] {
+            if id == from_edge {
+                continue;
+            }
+            if disc[v] == usize::MAX {
+                dfs(v, id, adj, disc, low, is_bridge, timer);
+                low[u] = low[u].min(low[v]);
+                if low[v] > disc[u] {
+                    is_bridge[id] = true;
+                }
+            } else {
+                low[u] = low[u].min(disc[v]);
+            }
+        }
+    }
+
+    for start in 0..n {
+        if disc[start] == usize::MAX {
+            dfs(start, usize::MAX, &adj, &mut disc, &mut low, &mut is_bridge, &mut timer);
+        }
+    }
+    is_bridge
+}
+
+/// Assigns each vertex a 2-edge-connected component id: removing every
+/// bridge splits the graph into components with no cut edge inside.
+fn two_edge_connected_components(n: usize, edges: &[(usize, usize)], is_bridge: &[bool]) -> Vec<usize> {
+    let adj = build_adjacency(n, edges);
+    let mut comp = vec![usize::MAX; n];
+    let mut next_id = 0;
+    for start in 0..n {
+        if comp[start] != usize::MAX {
+            continue;
+        }
+        comp[start] = next_id;
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            for &(v, id) in &adj[u] {
+                if !is_bridge[id] && comp[v] == usize::MAX {
+                    comp[v] = next_id;
+                    stack.push(v);
+                }
+            }
+        }
+        next_id += 1;
+    }
+    comp
+}
+
+/// Builds the bridge tree: one node per 2-edge-connected component, with
+/// an edge between two components for each bridge connecting them.
+/// Returns the component id of each original vertex and the tree edges.
+fn bridge_tree(n: usize, edges: &[(usize, usize)]) -> (Vec<usize>, Vec<(usize, usize)>) {
+    let is_bridge = find_bridges(n, edges);
+    let comp = two_edge_connected_components(n, edges, &is_bridge);
+
+    let mut tree_edges = Vec::new();
+    for (id, &(u, v)) in edges.iter().enumerate() {
+        if is_bridge[id] {
+            tree_edges.push((comp[u], comp[v]));
+        }
+    }
+    (comp, tree_edges)
+}
+
+/// A basis for the cycle space over GF(2): one fundamental cycle per
+/// non-tree edge of a spanning forest, each given as its sorted set of
+/// edge indices. Every cycle in the graph is an XOR of basis vectors.
+fn cycle_space_basis(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let adj = build_adjacency(n, edges);
+    let mut parent_edge = vec![usize::MAX; n];
+    let mut parent = vec![usize::MAX; n];
+    let mut depth = vec![0usize; n];
+    let mut visited = vec![false; n];
+    let mut tree_edge = vec![false; edges.len()];
+
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            for &(v, id) in &adj[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    parent[v] = u;
+                    parent_edge[v] = id;
+                    depth[v] = depth[u] + 1;
+                    tree_edge[id] = true;
+                    stack.push(v);
+                }
+            }
+        }
+    }
+
+    let mut basis = Vec::new();
+    for (id, &(u, v)) in edges.iter().enumerate() {
+        if tree_edge[id] {
+            continue;
+        }
+        // Fundamental cycle: walk u and v up to their lowest common
+        // ancestor in the spanning forest, collecting tree edges, then
+        // close the loop with this non-tree edge.
+        let mut cycle = vec![id];
+        let (mut a, mut b) = (u, v);
+        while a != b {
+            if depth[a] < depth[b] {
+                std::mem::swap(&mut a, &mut b);
+            }
+            cycle.push(parent_edge[a]);
+            a = parent[a];
+        }
+        cycle.sort_unstable();
+        basis.push(cycle);
+    }
+    basis
+}
+
+fn main() {
+    // Two triangles (0-1-2 and 3-4-5) joined by a single bridging edge
+    // 2-3, plus a pendant vertex 6 hanging off the second triangle.
+    let edges = vec![
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3), // bridge
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (5, 6), // bridge
+    ];
+    let n = 7;
+
+    let basis = cycle_space_basis(n, &edges);
+    println!("cycle space basis (edge indices per fundamental cycle):");
+    for cycle in &basis {
+        println!("{:?}", cycle);
+    }
+
+    let (comp, tree_edges) = bridge_tree(n, &edges);
+    println!("2-edge-connected component per vertex: {:?}", comp);
+    println!("bridge tree edges: {:?}", tree_edges);
+}
@@ -0,0 +1,12 @@
+use dsa::geometry::cdq_divide_conquer::cdq_dominance_count;
+use dsa::geometry::types::Point;
+
+fn main() {
+    let raw = [(2, 3), (4, 1), (1, 5), (3, 3), (5, 0)];
+    let points: Vec<Point> = raw.iter().map(|&(x, y)| Point { x, y }).collect();
+
+    let counts = cdq_dominance_count(&points);
+    for (id, &(x, y)) in raw.iter().enumerate() {
+        println!("point {} (x={}, y={}) dominated by {} earlier points", id, x, y, counts[id]);
+    }
+}
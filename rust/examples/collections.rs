@@ -0,0 +1,27 @@
+use dsa::collections::dsu::Dsu;
+use dsa::collections::segment_tree::{Min, SegmentTree, Sum};
+
+fn main() {
+    let mut dsu = Dsu::new(6);
+    dsu.union(0, 1);
+    dsu.union(1, 2);
+    dsu.union(3, 4);
+    assert!(dsu.same_set(0, 2));
+    assert!(!dsu.same_set(0, 3));
+    assert_eq!(dsu.set_size(0), 3);
+    assert_eq!(dsu.set_size(5), 1);
+
+    let sums: Vec<Sum> = [1i64, 2, 3, 4, 5].into_iter().map(Sum).collect();
+    let mut tree = SegmentTree::build(&sums);
+    assert_eq!(tree.query(0, 5).0, 15);
+    assert_eq!(tree.query(1, 3).0, 5);
+    tree.update(2, Sum(10));
+    assert_eq!(tree.query(0, 5).0, 22);
+
+    let mins: Vec<Min> = [5i64, 3, 8, 1, 9].into_iter().map(Min).collect();
+    let min_tree = SegmentTree::build(&mins);
+    assert_eq!(min_tree.query(0, 5).0, 1);
+    assert_eq!(min_tree.query(0, 2).0, 3);
+
+    println!("dsu and segment tree checks passed");
+}
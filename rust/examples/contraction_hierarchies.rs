@@ -0,0 +1,17 @@
+use dsa::graph::contraction_hierarchies::{build_graph, preprocess};
+
+fn main() {
+    let edges = [(0, 1, 4), (1, 2, 3), (2, 3, 2), (3, 4, 1), (4, 5, 6), (0, 5, 20), (1, 4, 9)];
+    let mut directed = Vec::new();
+    for &(u, v, w) in &edges {
+        directed.push((u, v, w));
+        directed.push((v, u, w));
+    }
+    let graph = build_graph(6, &directed);
+
+    let ch = preprocess(&graph);
+    println!("contraction order (rank -> vertex doesn't apply directly; printing rank per vertex):");
+    println!("{:?}", ch.rank);
+    println!("distance(0, 5) = {}", ch.query(0, 5));
+    println!("distance(2, 5) = {}", ch.query(2, 5));
+}
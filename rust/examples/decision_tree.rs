@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 /// Calculate entropy of a dataset
-fn entropy(labels: &Vec<String>) -> f64 {
+fn entropy(labels: &[String]) -> f64 {
     let mut counts = HashMap::new();
     for label in labels {
         *counts.entry(label).or_insert(0) += 1;
@@ -107,7 +107,7 @@ fn build_tree(dataset: &Dataset, depth: usize) -> Node {
 }
 
 /// Predict the label for a given sample
-fn predict(node: &Node, sample: &Vec<f64>) -> String {
+fn predict(node: &Node, sample: &[f64]) -> String {
     match node {
         Node::Leaf(label) => label.clone(),
         Node::Decision { feature_index, threshold, left, right } => {
@@ -0,0 +1,14 @@
+use dsa::graph::degeneracy_kcore::{degeneracy_ordering, k_core};
+
+fn main() {
+    // A graph with a dense triangle {0,1,2} plus pendant vertices 3,4.
+    let adj = vec![vec![1, 2, 3], vec![0, 2], vec![0, 1, 4], vec![0], vec![2]];
+
+    let (order, core_number) = degeneracy_ordering(&adj);
+    println!("degeneracy order: {:?}", order);
+    println!("core numbers: {:?}", core_number);
+
+    let degeneracy = core_number.iter().copied().max().unwrap_or(0);
+    println!("graph degeneracy: {}", degeneracy);
+    println!("2-core vertices: {:?}", k_core(&core_number, 2));
+}
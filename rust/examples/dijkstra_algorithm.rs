@@ -0,0 +1,24 @@
+use dsa::graph::dijkstra::dijkstra;
+use dsa::graph::types::Edge;
+
+fn main() {
+    // Graph represented as adjacency list
+    // Each node has a vector of edges (neighbor, weight)
+    let graph = vec![
+        vec![Edge { to: 1, weight: 4 }, Edge { to: 2, weight: 1 }],
+        vec![Edge { to: 3, weight: 1 }],
+        vec![Edge { to: 1, weight: 2 }, Edge { to: 3, weight: 5 }],
+        vec![],
+    ];
+
+    let start = 0;
+    let distances = dijkstra(&graph, start);
+
+    for (i, &d) in distances.iter().enumerate() {
+        if d == i64::MAX {
+            println!("Vertex {} is unreachable", i);
+        } else {
+            println!("Distance from {} to {} is {}", start, i, d);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use dsa::graph::dominator_tree::build_dominator_tree;
+
+fn main() {
+    // A small CFG: entry 0 branches to 1 and 2, both rejoin at 3, which
+    // branches to 4 and 5, both rejoining at 6.
+    let adj = vec![
+        vec![1, 2], // 0: entry
+        vec![3],    // 1
+        vec![3],    // 2
+        vec![4, 5], // 3
+        vec![6],    // 4
+        vec![6],    // 5
+        vec![],     // 6
+    ];
+
+    let dom = build_dominator_tree(&adj, 0);
+    for v in 0..adj.len() {
+        println!("idom[{}] = {}", v, dom.idom[v]);
+    }
+}
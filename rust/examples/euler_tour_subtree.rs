@@ -0,0 +1,31 @@
+use dsa::tree::euler_tour_subtree::{EulerTour, Fenwick};
+
+fn main() {
+    // Tree:        0
+    //            / | \
+    //           1  2  3
+    //          /|
+    //         4 5
+    let adj = vec![vec![1, 2, 3], vec![0, 4, 5], vec![0], vec![0], vec![1], vec![1]];
+    let values = [10, 20, 30, 40, 50, 60];
+
+    let tour = EulerTour::build(&adj, 0);
+    println!("DFS visit order: {:?}", tour.order());
+    let mut fenwick = Fenwick::new(adj.len());
+    for (v, &val) in values.iter().enumerate() {
+        fenwick.add(tour.subtree_range(v).0, val);
+    }
+
+    for v in 0..adj.len() {
+        let (lo, hi) = tour.subtree_range(v);
+        println!("subtree sum of {}: {}", v, fenwick.range_sum(lo, hi));
+    }
+
+    println!("is_ancestor(1, 5) = {}", tour.is_ancestor(1, 5));
+    println!("is_ancestor(2, 5) = {}", tour.is_ancestor(2, 5));
+
+    // Update node 5's value from 60 to 100: adjust the Fenwick tree by the delta.
+    fenwick.add(tour.subtree_range(5).0, 40);
+    let (lo, hi) = tour.subtree_range(1);
+    println!("subtree sum of 1 after updating node 5: {}", fenwick.range_sum(lo, hi));
+}
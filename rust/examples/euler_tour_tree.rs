@@ -0,0 +1,18 @@
+use dsa::tree::euler_tour_tree::EulerTourTree;
+
+fn main() {
+    let mut forest = EulerTourTree::new(6);
+    forest.link(0, 1);
+    forest.link(1, 2);
+    forest.link(3, 4);
+
+    println!("connected(0, 2) = {}", forest.connected(0, 2));
+    println!("connected(0, 3) = {}", forest.connected(0, 3));
+    println!("forest has {} vertices", forest.n());
+
+    forest.link(2, 3);
+    println!("after linking 2-3, connected(0, 4) = {}", forest.connected(0, 4));
+
+    forest.cut(2, 3);
+    println!("after cutting 2-3, connected(0, 4) = {}", forest.connected(0, 4));
+}
@@ -0,0 +1,27 @@
+use dsa::math::fixed_point::{FixedPoint, Rounding};
+
+fn main() {
+    let price = FixedPoint::parse("19.99", 2, Rounding::HalfUp).unwrap();
+    let quantity = FixedPoint::from_scaled(3, 0);
+    let total = price.mul(quantity, Rounding::HalfUp);
+    assert_eq!(total.to_string(), "59.97");
+    println!("{} x {} = {}", price, quantity, total);
+
+    let shipping = FixedPoint::parse("4.50", 2, Rounding::HalfUp).unwrap();
+    let discount = FixedPoint::parse("2.00", 2, Rounding::HalfUp).unwrap();
+    assert_eq!((total + shipping - discount).to_string(), "62.47");
+
+    let third = FixedPoint::from_scaled(1, 0).div(FixedPoint::from_scaled(3, 0), Rounding::HalfEven);
+    assert_eq!(third.to_string(), "0");
+
+    let tax_rate = FixedPoint::parse("0.0825", 4, Rounding::Down).unwrap();
+    let tax = total.mul(tax_rate, Rounding::HalfEven).rescale(2, Rounding::HalfEven);
+    assert_eq!(tax.to_string(), "4.95");
+    println!("tax: {}", tax);
+
+    // "2.5" rounds to even (2) under banker's rounding, but away from
+    // zero (3) under half-up.
+    assert_eq!(FixedPoint::parse("2.5", 0, Rounding::HalfEven).unwrap().to_string(), "2");
+    assert_eq!(FixedPoint::parse("2.5", 0, Rounding::HalfUp).unwrap().to_string(), "3");
+    assert_eq!(FixedPoint::parse("-2.5", 0, Rounding::HalfUp).unwrap().to_string(), "-3");
+}
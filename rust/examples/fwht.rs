@@ -0,0 +1,12 @@
+use dsa::math::fwht::{and_convolution, or_convolution, xor_convolution};
+
+fn main() {
+    let a = vec![1, 2, 3, 4];
+    let b = vec![5, 6, 7, 8];
+
+    println!("a = {:?}", a);
+    println!("b = {:?}", b);
+    println!("XOR convolution: {:?}", xor_convolution(&a, &b));
+    println!("AND convolution: {:?}", and_convolution(&a, &b));
+    println!("OR convolution:  {:?}", or_convolution(&a, &b));
+}
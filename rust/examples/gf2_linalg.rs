@@ -0,0 +1,17 @@
+use dsa::math::gf2_linalg::{gf2_rank, gf2_solve, Gf2Basis};
+
+fn main() {
+    let mut basis = Gf2Basis::new();
+    for v in [5u64, 3, 6, 10] {
+        basis.insert(v);
+    }
+    println!("rank: {}", basis.rank());
+    println!("max xor achievable: {}", basis.max_xor());
+
+    let mut rows = vec![0b110u64, 0b011, 0b101];
+    println!("matrix rank: {}", gf2_rank(&mut rows));
+
+    // x0 ^ x1 = 1, x1 ^ x2 = 1  (cols = 3, rhs packed at bit 3)
+    let augmented = vec![0b1_011u64, 0b1_110];
+    println!("solution: {:?}", gf2_solve(&augmented, 3));
+}
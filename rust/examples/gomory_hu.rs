@@ -0,0 +1,11 @@
+use dsa::graph::gomory_hu::gomory_hu_tree;
+
+fn main() {
+    // 0-1 (1), 0-2 (7), 1-2 (1), 1-3 (3), 2-3 (2)
+    let edges = vec![(0, 1, 1), (0, 2, 7), (1, 2, 1), (1, 3, 3), (2, 3, 2)];
+    let tree = gomory_hu_tree(4, &edges);
+    println!("Gomory-Hu tree edges (parent, child, min-cut weight):");
+    for (p, c, w) in tree {
+        println!("{} - {} : {}", p, c, w);
+    }
+}
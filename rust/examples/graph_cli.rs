@@ -0,0 +1,121 @@
+// CLI binary to run graph algorithms on a plain-text input file.
+//
+// Input format (whitespace-separated):
+//   n m
+//   u1 v1 w1
+//   ...
+//   um vm wm
+// (an unweighted graph can just use weight 1 for every edge)
+//
+// Usage:
+//   graph_cli bfs   <file> <source>
+//   graph_cli dijkstra <file> <source>
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::env;
+use std::fs;
+use std::process;
+
+struct Graph {
+    n: usize,
+    adj: Vec<Vec<(usize, i64)>>,
+}
+
+fn read_graph(path: &str) -> Graph {
+    let content = fs::read_to_string(path).expect("failed to read graph file");
+    let mut tokens = content.split_whitespace().map(|t| t.parse::<i64>().unwrap());
+    let n = tokens.next().expect("missing vertex count") as usize;
+    let m = tokens.next().expect("missing edge count") as usize;
+
+    let mut adj = vec![Vec::new(); n];
+    for _ in 0..m {
+        let u = tokens.next().expect("missing edge endpoint") as usize;
+        let v = tokens.next().expect("missing edge endpoint") as usize;
+        let w = tokens.next().expect("missing edge weight");
+        adj[u].push((v, w));
+        adj[v].push((u, w));
+    }
+    Graph { n, adj }
+}
+
+fn bfs(graph: &Graph, src: usize) -> Vec<i64> {
+    let mut dist = vec![-1i64; graph.n];
+    dist[src] = 0;
+    let mut queue = VecDeque::new();
+    queue.push_back(src);
+    while let Some(u) = queue.pop_front() {
+        for &(v, _) in &graph.adj[u] {
+            if dist[v] == -1 {
+                dist[v] = dist[u] + 1;
+                queue.push_back(v);
+            }
+        }
+    }
+    dist
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    dist: i64,
+    node: usize,
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.cmp(&self.dist)
+    }
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn dijkstra(graph: &Graph, src: usize) -> Vec<i64> {
+    let mut dist = vec![i64::MAX; graph.n];
+    dist[src] = 0;
+    let mut heap = BinaryHeap::new();
+    heap.push(State { dist: 0, node: src });
+    while let Some(State { dist: d, node: u }) = heap.pop() {
+        if d > dist[u] {
+            continue;
+        }
+        for &(v, w) in &graph.adj[u] {
+            if d + w < dist[v] {
+                dist[v] = d + w;
+                heap.push(State { dist: d + w, node: v });
+            }
+        }
+    }
+    dist
+}
+
+fn print_distances(dist: &[i64], unreachable_sentinel: i64) {
+    for (v, &d) in dist.iter().enumerate() {
+        if d == unreachable_sentinel {
+            println!("{}: unreachable", v);
+        } else {
+            println!("{}: {}", v, d);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 4 {
+        eprintln!("Usage: {} <bfs|dijkstra> <graph-file> <source>", args[0]);
+        process::exit(1);
+    }
+
+    let graph = read_graph(&args[2]);
+    let src: usize = args[3].parse().expect("source must be a vertex index");
+
+    match args[1].as_str() {
+        "bfs" => print_distances(&bfs(&graph, src), -1),
+        "dijkstra" => print_distances(&dijkstra(&graph, src), i64::MAX),
+        other => {
+            eprintln!("unknown algorithm: {other}");
+            process::exit(1);
+        }
+    }
+}
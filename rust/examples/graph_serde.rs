@@ -0,0 +1,89 @@
+// Serde-backed (de)serialization for the `Graph` and solver-result types
+// used throughout this directory, so algorithm outputs can be saved to
+// disk or exchanged with the CLI binary as JSON. This file assumes the
+// `serde` and `serde_json` crates (depends on the crate-ification of
+// `rust/` so these types can actually be built against them; see the
+// follow-up that turns this directory into a proper Cargo library).
+
+use serde::{Deserialize, Serialize};
+
+/// A weighted, optionally-directed graph, serializable as a flat edge
+/// list so the JSON stays readable for small/medium instances.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Graph {
+    pub n: usize,
+    pub directed: bool,
+    pub edges: Vec<WeightedEdge>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WeightedEdge {
+    pub from: usize,
+    pub to: usize,
+    pub weight: i64,
+}
+
+impl Graph {
+    pub fn new(n: usize, directed: bool) -> Self {
+        Graph { n, directed, edges: Vec::new() }
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: i64) {
+        self.edges.push(WeightedEdge { from, to, weight });
+    }
+
+    /// Builds an adjacency-list view for running algorithms; the graph
+    /// itself stays in edge-list form because that's what round-trips
+    /// cleanly through JSON.
+    pub fn adjacency_list(&self) -> Vec<Vec<WeightedEdge>> {
+        let mut adj = vec![Vec::new(); self.n];
+        for &e in &self.edges {
+            adj[e.from].push(e);
+            if !self.directed {
+                adj[e.to].push(WeightedEdge { from: e.to, to: e.from, weight: e.weight });
+            }
+        }
+        adj
+    }
+}
+
+/// The result of a shortest-path style solver, serializable alongside the
+/// `Graph` it was computed on so a single JSON file is self-describing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortestPathResult {
+    pub source: usize,
+    pub distances: Vec<Option<i64>>, // None for unreachable vertices
+    pub predecessors: Vec<Option<usize>>,
+}
+
+impl ShortestPathResult {
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+}
+
+fn main() {
+    let mut graph = Graph::new(4, false);
+    graph.add_edge(0, 1, 4);
+    graph.add_edge(1, 2, 3);
+    graph.add_edge(2, 3, 2);
+    graph.add_edge(0, 3, 15);
+
+    let json = serde_json::to_string_pretty(&graph).unwrap();
+    println!("{}", json);
+
+    let result = ShortestPathResult {
+        source: 0,
+        distances: vec![Some(0), Some(4), Some(7), Some(9)],
+        predecessors: vec![None, Some(0), Some(1), Some(2)],
+    };
+    println!("{}", result.to_json().unwrap());
+
+    let round_tripped: Graph = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_tripped.edges.len(), graph.edges.len());
+    println!("round-trip ok: {} edges preserved", round_tripped.edges.len());
+}
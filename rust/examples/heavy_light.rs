@@ -0,0 +1,21 @@
+use dsa::tree::heavy_light::HeavyLight;
+
+fn main() {
+    // Tree rooted at 0, edge weights attached to the child.
+    let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0, 5], vec![1], vec![1], vec![2]];
+    let node_weight = vec![0, 5, 3, 10, 2, 7]; // weight of edge (parent, v)
+
+    let hl = HeavyLight::new(&adj, 0, &node_weight);
+    println!("max edge on path(3, 4) = {}", hl.query_path_max(3, 4));
+    println!("max edge on path(3, 5) = {}", hl.query_path_max(3, 5));
+    println!("max edge on path(4, 2) = {}", hl.query_path_max(4, 2));
+
+    println!("min edge on path(3, 5) = {}", hl.query_path_min(3, 5));
+    println!("sum of edges on path(3, 5) = {}", hl.query_path_sum(3, 5));
+
+    println!("lca(3, 5) = {}", hl.lca(3, 5));
+    println!("path_length(3, 5) = {}", hl.path_length(3, 5));
+    for k in 0..=hl.path_length(3, 5) {
+        println!("kth_node_on_path(3, 5, {}) = {:?}", k, hl.kth_node_on_path(3, 5, k));
+    }
+}
@@ -0,0 +1,219 @@
+// Huffman-based file compression CLI.
+//
+// Usage:
+//   huffman_compress compress <input> <output>
+//   huffman_compress decompress <input> <output>
+//
+// The compressed format is a small header (byte frequency table) followed
+// by the bit-packed Huffman-encoded payload, so the file is self-describing
+// and needs no external dictionary to decompress.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::env;
+use std::fs;
+use std::process;
+
+#[derive(Debug)]
+enum Node {
+    Leaf(u8),
+    Internal(Box<Node>, Box<Node>),
+}
+
+struct HeapEntry {
+    freq: u64,
+    node: Node,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.freq == other.freq
+    }
+}
+impl Eq for HeapEntry {}
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.freq.cmp(&self.freq) // min-heap
+    }
+}
+
+fn build_tree(freqs: &[u64; 256]) -> Option<Node> {
+    let mut heap = BinaryHeap::new();
+    for (byte, &freq) in freqs.iter().enumerate() {
+        if freq > 0 {
+            heap.push(HeapEntry { freq, node: Node::Leaf(byte as u8) });
+        }
+    }
+    if heap.is_empty() {
+        return None;
+    }
+    if heap.len() == 1 {
+        let only = heap.pop().unwrap();
+        return Some(Node::Internal(Box::new(only.node), Box::new(Node::Leaf(0))));
+    }
+    while heap.len() > 1 {
+        let a = heap.pop().unwrap();
+        let b = heap.pop().unwrap();
+        heap.push(HeapEntry {
+            freq: a.freq + b.freq,
+            node: Node::Internal(Box::new(a.node), Box::new(b.node)),
+        });
+    }
+    Some(heap.pop().unwrap().node)
+}
+
+fn build_codes(node: &Node, prefix: Vec<bool>, codes: &mut [Vec<bool>; 256]) {
+    match node {
+        Node::Leaf(byte) => codes[*byte as usize] = if prefix.is_empty() { vec![false] } else { prefix },
+        Node::Internal(left, right) => {
+            let mut left_prefix = prefix.clone();
+            left_prefix.push(false);
+            build_codes(left, left_prefix, codes);
+            let mut right_prefix = prefix;
+            right_prefix.push(true);
+            build_codes(right, right_prefix, codes);
+        }
+    }
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    cur: u8,
+    filled: u8,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        BitWriter { bytes: Vec::new(), cur: 0, filled: 0 }
+    }
+    fn push_bit(&mut self, bit: bool) {
+        if bit {
+            self.cur |= 1 << self.filled;
+        }
+        self.filled += 1;
+        if self.filled == 8 {
+            self.bytes.push(self.cur);
+            self.cur = 0;
+            self.filled = 0;
+        }
+    }
+    fn finish(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.bytes.push(self.cur);
+        }
+        self.bytes
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    bit: u8,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, pos: 0, bit: 0 }
+    }
+    fn next_bit(&mut self) -> Option<bool> {
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+        let bit = (self.bytes[self.pos] >> self.bit) & 1 == 1;
+        self.bit += 1;
+        if self.bit == 8 {
+            self.bit = 0;
+            self.pos += 1;
+        }
+        Some(bit)
+    }
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut freqs = [0u64; 256];
+    for &b in data {
+        freqs[b as usize] += 1;
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    for &f in freqs.iter() {
+        out.extend_from_slice(&f.to_le_bytes());
+    }
+
+    if data.is_empty() {
+        return out;
+    }
+
+    let tree = build_tree(&freqs).unwrap();
+    let mut codes: [Vec<bool>; 256] = std::array::from_fn(|_| Vec::new());
+    build_codes(&tree, Vec::new(), &mut codes);
+
+    let mut writer = BitWriter::new();
+    for &b in data {
+        for &bit in &codes[b as usize] {
+            writer.push_bit(bit);
+        }
+    }
+    out.extend(writer.finish());
+    out
+}
+
+fn decompress(data: &[u8]) -> Vec<u8> {
+    if data.len() < 8 {
+        return Vec::new();
+    }
+    let total_len = u64::from_le_bytes(data[0..8].try_into().unwrap()) as usize;
+    if total_len == 0 {
+        return Vec::new();
+    }
+    let mut freqs = [0u64; 256];
+    let mut offset = 8;
+    for f in freqs.iter_mut() {
+        *f = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+    }
+    let tree = build_tree(&freqs).unwrap();
+
+    let mut reader = BitReader::new(&data[offset..]);
+    let mut result = Vec::with_capacity(total_len);
+    while result.len() < total_len {
+        let mut node = &tree;
+        loop {
+            match node {
+                Node::Leaf(byte) => {
+                    result.push(*byte);
+                    break;
+                }
+                Node::Internal(left, right) => {
+                    node = if reader.next_bit().unwrap_or(false) { right } else { left };
+                }
+            }
+        }
+    }
+    result
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 4 || (args[1] != "compress" && args[1] != "decompress") {
+        eprintln!("Usage: {} <compress|decompress> <input> <output>", args[0]);
+        process::exit(1);
+    }
+
+    let input = fs::read(&args[2]).expect("failed to read input file");
+    let output = if args[1] == "compress" { compress(&input) } else { decompress(&input) };
+    fs::write(&args[3], output).expect("failed to write output file");
+
+    println!(
+        "{}d {} bytes -> {} bytes",
+        if args[1] == "compress" { "Compresse" } else { "Decompresse" },
+        input.len(),
+        fs::metadata(&args[3]).unwrap().len()
+    );
+}
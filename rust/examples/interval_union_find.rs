@@ -0,0 +1,14 @@
+use dsa::collections::interval_union_find::IntervalUnionFind;
+
+fn main() {
+    let mut canvas = IntervalUnionFind::new(10);
+
+    println!("painted {} slots with color 1", canvas.paint(2, 5, 1));
+    println!("painted {} slots with color 2", canvas.paint(4, 8, 2)); // 4,5 already painted
+    println!("painted {} slots with color 3", canvas.paint(0, 9, 3)); // fills the rest
+
+    for i in 0..10 {
+        print!("{} ", canvas.color_of(i).unwrap());
+    }
+    println!();
+}
@@ -0,0 +1,8 @@
+use dsa::sorting::introsort::introsort;
+
+fn main() {
+    let mut v = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
+    println!("before: {:?}", v);
+    introsort(&mut v);
+    println!("after:  {:?}", v);
+}
@@ -0,0 +1,28 @@
+use dsa::graph::johnson::johnson;
+use dsa::graph::types::WeightedEdge;
+
+fn main() {
+    // Graph with one negative edge but no negative cycle.
+    let n = 4;
+    let edges = vec![
+        WeightedEdge { from: 0, to: 1, weight: 4 },
+        WeightedEdge { from: 0, to: 3, weight: 15 },
+        WeightedEdge { from: 1, to: 2, weight: 3 },
+        WeightedEdge { from: 2, to: 3, weight: -5 },
+    ];
+
+    match johnson(n, &edges) {
+        Some(distances) => {
+            for (src, row) in distances.iter().enumerate() {
+                for (dst, &d) in row.iter().enumerate() {
+                    if d == i64::MAX {
+                        println!("{} -> {}: unreachable", src, dst);
+                    } else {
+                        println!("{} -> {}: {}", src, dst, d);
+                    }
+                }
+            }
+        }
+        None => println!("negative weight cycle detected"),
+    }
+}
@@ -0,0 +1,16 @@
+use dsa::tree::lca_sparse_table::LcaSparseTable;
+
+fn main() {
+    // Tree:        0
+    //            / | \
+    //           1  2  3
+    //          /|     |
+    //         4 5      6
+    let adj = vec![vec![1, 2, 3], vec![0, 4, 5], vec![0], vec![0, 6], vec![1], vec![1], vec![3]];
+
+    let lca = LcaSparseTable::new(&adj, 0);
+    println!("lca(4, 5) = {}", lca.lca(4, 5));
+    println!("lca(4, 6) = {}", lca.lca(4, 6));
+    println!("lca(5, 2) = {}", lca.lca(5, 2));
+    println!("lca(6, 6) = {}", lca.lca(6, 6));
+}
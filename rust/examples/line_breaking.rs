@@ -0,0 +1,21 @@
+use dsa::dp::line_breaking::{full_justify, minimum_raggedness_lines};
+
+fn main() {
+    let words = ["This", "is", "an", "example", "of", "text", "justification."];
+
+    let justified = full_justify(&words, 16);
+    assert_eq!(
+        justified,
+        vec!["This    is    an", "example  of text", "justification.  "]
+    );
+    println!("full justify (width 16):");
+    for line in &justified {
+        println!("{:?}", line);
+    }
+
+    let ragged = minimum_raggedness_lines(&words, 16);
+    println!("\nminimum raggedness (width 16):");
+    for line in &ragged {
+        println!("{:?}", line);
+    }
+}
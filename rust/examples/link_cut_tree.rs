@@ -0,0 +1,18 @@
+use dsa::tree::link_cut_tree::LinkCutTree;
+
+fn main() {
+    let mut lct = LinkCutTree::new(6);
+    lct.link(0, 1);
+    lct.link(1, 2);
+    lct.link(3, 4);
+
+    println!("connected(0, 2) = {}", lct.connected(0, 2));
+    println!("connected(0, 3) = {}", lct.connected(0, 3));
+
+    lct.link(2, 3);
+    println!("after linking 2-3, connected(0, 4) = {}", lct.connected(0, 4));
+
+    lct.cut(1, 2);
+    println!("after cutting 1-2, connected(0, 4) = {}", lct.connected(0, 4));
+    println!("connected(2, 4) = {}", lct.connected(2, 4));
+}
@@ -0,0 +1,170 @@
+// Maze generation and solving subsystem: carves a perfect maze with
+// randomized depth-first backtracking, then solves it with BFS to find
+// the shortest path from the entrance to the exit.
+
+use std::collections::VecDeque;
+
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x2545F4914F6CDD1D).wrapping_add(1))
+    }
+    fn next_u64(&mut self) -> u64 {
+        // xorshift64*
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = (self.next_u64() % (i as u64 + 1)) as usize;
+            slice.swap(i, j);
+        }
+    }
+}
+
+const NORTH: usize = 0;
+const SOUTH: usize = 1;
+const EAST: usize = 2;
+const WEST: usize = 3;
+
+struct Maze {
+    width: usize,
+    height: usize,
+    // walls[y][x] is a 4-bit mask of open directions (bit set = passable).
+    open: Vec<Vec<u8>>,
+}
+
+impl Maze {
+    fn generate(width: usize, height: usize, seed: u64) -> Self {
+        let mut open = vec![vec![0u8; width]; height];
+        let mut visited = vec![vec![false; width]; height];
+        let mut rng = Rng::new(seed);
+        let mut stack = vec![(0usize, 0usize)];
+        visited[0][0] = true;
+
+        while let Some(&(x, y)) = stack.last() {
+            let mut dirs = [NORTH, SOUTH, EAST, WEST];
+            rng.shuffle(&mut dirs);
+            let mut moved = false;
+            for &dir in &dirs {
+                let (nx, ny) = match dir {
+                    NORTH if y > 0 => (x, y - 1),
+                    SOUTH if y + 1 < height => (x, y + 1),
+                    EAST if x + 1 < width => (x + 1, y),
+                    WEST if x > 0 => (x - 1, y),
+                    _ => continue,
+                };
+                if !visited[ny][nx] {
+                    open[y][x] |= 1 << dir;
+                    open[ny][nx] |= 1 << opposite(dir);
+                    visited[ny][nx] = true;
+                    stack.push((nx, ny));
+                    moved = true;
+                    break;
+                }
+            }
+            if !moved {
+                stack.pop();
+            }
+        }
+
+        Maze { width, height, open }
+    }
+
+    fn neighbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+        let mask = self.open[y][x];
+        if mask & (1 << NORTH) != 0 {
+            result.push((x, y - 1));
+        }
+        if mask & (1 << SOUTH) != 0 {
+            result.push((x, y + 1));
+        }
+        if mask & (1 << EAST) != 0 {
+            result.push((x + 1, y));
+        }
+        if mask & (1 << WEST) != 0 {
+            result.push((x - 1, y));
+        }
+        result
+    }
+
+    /// Shortest path from `start` to `goal` via BFS, inclusive of both ends.
+    fn solve(&self, start: (usize, usize), goal: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        let mut visited = vec![vec![false; self.width]; self.height];
+        let mut parent = vec![vec![None; self.width]; self.height];
+        let mut queue = VecDeque::new();
+        visited[start.1][start.0] = true;
+        queue.push_back(start);
+
+        while let Some((x, y)) = queue.pop_front() {
+            if (x, y) == goal {
+                let mut path = vec![(x, y)];
+                let mut cur = (x, y);
+                while let Some(p) = parent[cur.1][cur.0] {
+                    path.push(p);
+                    cur = p;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for (nx, ny) in self.neighbors(x, y) {
+                if !visited[ny][nx] {
+                    visited[ny][nx] = true;
+                    parent[ny][nx] = Some((x, y));
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        None
+    }
+
+    fn render(&self, path: &[(usize, usize)]) -> String {
+        let on_path: std::collections::HashSet<_> = path.iter().cloned().collect();
+        let mut s = String::new();
+        s.push_str(&"_".repeat(self.width * 2 + 1));
+        s.push('\n');
+        for y in 0..self.height {
+            s.push('|');
+            for x in 0..self.width {
+                let mask = self.open[y][x];
+                s.push(if on_path.contains(&(x, y)) { '*' } else if mask & (1 << SOUTH) != 0 { ' ' } else { '_' });
+                if mask & (1 << EAST) != 0 {
+                    let south_open = mask & (1 << SOUTH) != 0
+                        || (x + 1 < self.width && self.open[y][x + 1] & (1 << SOUTH) != 0);
+                    s.push(if south_open { ' ' } else { '_' });
+                } else {
+                    s.push('|');
+                }
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+fn opposite(dir: usize) -> usize {
+    match dir {
+        NORTH => SOUTH,
+        SOUTH => NORTH,
+        EAST => WEST,
+        WEST => EAST,
+        _ => unreachable!(),
+    }
+}
+
+fn main() {
+    let maze = Maze::generate(10, 6, 42);
+    let start = (0, 0);
+    let goal = (9, 5);
+    match maze.solve(start, goal) {
+        Some(path) => {
+            println!("solved in {} steps", path.len() - 1);
+            println!("{}", maze.render(&path));
+        }
+        None => println!("no path found"),
+    }
+}
@@ -0,0 +1,17 @@
+use dsa::graph::min_cost_flow::MinCostFlow;
+
+fn main() {
+    // Source 0, sink 3; two parallel paths with different unit costs.
+    let mut mcmf = MinCostFlow::new(4);
+    mcmf.add_edge(0, 1, 3, 1);
+    mcmf.add_edge(0, 2, 2, 2);
+    mcmf.add_edge(1, 3, 2, 1);
+    mcmf.add_edge(2, 3, 3, 1);
+    mcmf.add_edge(1, 2, 1, 0);
+
+    let (flow, cost) = mcmf.min_cost_flow(0, 3, i64::MAX);
+    println!("flow = {}, cost = {}", flow, cost);
+    for (from, to, f, c) in mcmf.edge_flows() {
+        println!("{} -> {}: flow {}, unit cost {}", from, to, f, c);
+    }
+}
@@ -0,0 +1,117 @@
+// Event-driven simulation of an M/M/1 queue: a single server, Poisson
+// arrivals, exponential service times. A min-heap of events (ordered by
+// time) drives the simulation instead of stepping through fixed time
+// slices, which is the standard pattern for discrete-event simulation.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Clone, Copy, PartialEq)]
+enum EventKind {
+    Arrival,
+    Departure,
+}
+
+#[derive(Clone, Copy)]
+struct Event {
+    time: f64,
+    kind: EventKind,
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.time == other.time
+    }
+}
+impl Eq for Event {}
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Event {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.time.partial_cmp(&self.time).unwrap() // min-heap on time
+    }
+}
+
+/// A tiny linear-congruential generator, good enough for a reproducible demo.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 11) as f64) / ((1u64 << 53) as f64)
+    }
+    /// Samples from an exponential distribution with the given rate.
+    fn exponential(&mut self, rate: f64) -> f64 {
+        -(1.0 - self.next_f64()).ln() / rate
+    }
+}
+
+struct SimulationResult {
+    customers_served: usize,
+    total_wait: f64,
+    max_queue_len: usize,
+}
+
+/// Runs an M/M/1 simulation with arrival rate `lambda` and service rate
+/// `mu` until `duration` time units have elapsed.
+fn simulate(lambda: f64, mu: f64, duration: f64, seed: u64) -> SimulationResult {
+    let mut rng = Lcg(seed);
+    let mut events = BinaryHeap::new();
+    events.push(Event { time: rng.exponential(lambda), kind: EventKind::Arrival });
+
+    let mut clock;
+    let mut queue: Vec<f64> = Vec::new(); // arrival times of waiting/served customers
+    let mut server_busy = false;
+    let mut customers_served = 0;
+    let mut total_wait = 0.0;
+    let mut max_queue_len = 0;
+
+    while let Some(event) = events.pop() {
+        if event.time > duration {
+            break;
+        }
+        clock = event.time;
+
+        match event.kind {
+            EventKind::Arrival => {
+                queue.push(clock);
+                max_queue_len = max_queue_len.max(queue.len());
+                events.push(Event { time: clock + rng.exponential(lambda), kind: EventKind::Arrival });
+                if !server_busy {
+                    server_busy = true;
+                    let arrival = queue.remove(0);
+                    total_wait += clock - arrival;
+                    events.push(Event { time: clock + rng.exponential(mu), kind: EventKind::Departure });
+                }
+            }
+            EventKind::Departure => {
+                customers_served += 1;
+                if queue.is_empty() {
+                    server_busy = false;
+                } else {
+                    let arrival = queue.remove(0);
+                    total_wait += clock - arrival;
+                    events.push(Event { time: clock + rng.exponential(mu), kind: EventKind::Departure });
+                }
+            }
+        }
+    }
+
+    SimulationResult { customers_served, total_wait, max_queue_len }
+}
+
+fn main() {
+    let lambda = 4.0; // arrivals per time unit
+    let mu = 5.0; // services per time unit
+    let result = simulate(lambda, mu, 1000.0, 12345);
+
+    println!("customers served: {}", result.customers_served);
+    println!(
+        "average wait: {:.4}",
+        result.total_wait / result.customers_served.max(1) as f64
+    );
+    println!("max queue length observed: {}", result.max_queue_len);
+}
@@ -0,0 +1,24 @@
+use dsa::dp::optimal_bst::optimal_bst;
+use dsa::tree::binary_tree::BinaryTree;
+
+fn main() {
+    let keys = [10, 20, 30, 40];
+    let freq = [4, 2, 6, 3];
+
+    let (cost, tree) = optimal_bst(&keys, &freq);
+    assert_eq!(cost, 26);
+
+    // root 30, with 10 as its left child (itself holding 20 as its right
+    // child) and 40 as its right child.
+    match &tree {
+        BinaryTree::Node { key: 30, left, right } => {
+            assert!(matches!(**left, BinaryTree::Node { key: 10, .. }));
+            assert!(matches!(**right, BinaryTree::Node { key: 40, .. }));
+        }
+        _ => panic!("unexpected tree shape"),
+    }
+    assert_eq!(tree.len(), keys.len());
+
+    println!("optimal BST expected cost: {cost}");
+    println!("{tree:?}");
+}
@@ -0,0 +1,30 @@
+use dsa::collections::persistent_stack_queue::{AmortizedQueue, BankersQueue, PersistentStack};
+
+fn main() {
+    let s0: PersistentStack<i32> = PersistentStack::new();
+    let s1 = s0.push(1);
+    let s2 = s1.push(2);
+    let s3 = s2.push(3);
+    println!("s3 pop: {:?}", s3.pop().map(|(v, _)| v));
+    println!("s1 pop: {:?} (s1 is untouched by later pushes)", s1.pop().map(|(v, _)| v));
+
+    let mut q1 = AmortizedQueue::new();
+    for i in 1..=5 {
+        q1.enqueue(i);
+    }
+    let mut drained = Vec::new();
+    while let Some(v) = q1.dequeue() {
+        drained.push(v);
+    }
+    println!("amortized queue order: {:?}", drained);
+
+    let mut q2 = BankersQueue::new();
+    for i in 1..=5 {
+        q2.enqueue(i);
+    }
+    let mut drained2 = Vec::new();
+    while let Some(v) = q2.dequeue() {
+        drained2.push(v);
+    }
+    println!("persistent bankers queue order: {:?}", drained2);
+}
@@ -0,0 +1,18 @@
+use dsa::collections::persistent_treap::PersistentTreap;
+
+fn main() {
+    let v0 = PersistentTreap::new();
+    let v1 = v0.insert(0, 10);
+    let v2 = v1.insert(1, 20);
+    let v3 = v2.insert(2, 30);
+    println!("v3: {:?}", v3.to_vec());
+
+    let v4 = v3.erase(1);
+    println!("v4 (after erasing index 1 from v3): {:?}", v4.to_vec());
+    println!("v3 is still intact: {:?}", v3.to_vec());
+
+    let v5 = v4.insert(0, 99);
+    println!("v5: {:?}", v5.to_vec());
+    println!("v0 was always empty: {:?}", v0.to_vec());
+    println!("v3 len: {}, v5 len: {}", v3.len(), v5.len());
+}
@@ -0,0 +1,17 @@
+use dsa::graph::push_relabel::PushRelabel;
+
+fn main() {
+    // A small dense network: source 0, sink 4.
+    let mut pr = PushRelabel::new(5);
+    pr.add_edge(0, 1, 10);
+    pr.add_edge(0, 2, 10);
+    pr.add_edge(1, 2, 2);
+    pr.add_edge(1, 3, 4);
+    pr.add_edge(1, 4, 8);
+    pr.add_edge(2, 4, 9);
+    pr.add_edge(3, 4, 10);
+    pr.add_edge(2, 3, 6);
+
+    let flow = pr.max_flow(0, 4);
+    println!("Max flow (push-relabel): {}", flow);
+}
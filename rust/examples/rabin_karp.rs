@@ -0,0 +1,10 @@
+use dsa::string::rabin_karp::rabin_karp;
+
+fn main() {
+    let text = "ABCCDDAEFG";
+    let pattern = "CDD";
+
+    for i in rabin_karp(text, pattern) {
+        println!("Pattern found at index {}", i);
+    }
+}
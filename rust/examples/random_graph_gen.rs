@@ -0,0 +1,12 @@
+use dsa::graph::random_graph_gen::{barabasi_albert, erdos_renyi, random_tree};
+
+fn main() {
+    let g1 = erdos_renyi(8, 0.3, 1);
+    println!("Erdos-Renyi G(8, 0.3): {} edges -> {:?}", g1.len(), g1);
+
+    let tree = random_tree(8, 1);
+    println!("random tree on 8 vertices: {:?}", tree);
+
+    let ba = barabasi_albert(10, 2, 1);
+    println!("Barabasi-Albert(10, m=2): {} edges -> {:?}", ba.len(), ba);
+}
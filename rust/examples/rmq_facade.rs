@@ -0,0 +1,149 @@
+// A "shoot-out" of range-minimum-query strategies plus a facade that picks
+// one automatically based on how the caller intends to use it: a sparse
+// table for static arrays with many queries, a segment tree when updates
+// are needed, and plain linear scan for tiny or rarely-queried arrays.
+
+/// O(n log n) build, O(1) query. Best when the array never changes and
+/// there are many queries.
+struct SparseTableRmq {
+    table: Vec<Vec<i64>>,
+    log: Vec<usize>,
+}
+
+impl SparseTableRmq {
+    fn new(a: &[i64]) -> Self {
+        let n = a.len();
+        let mut log = vec![0usize; n + 1];
+        for i in 2..=n {
+            log[i] = log[i / 2] + 1;
+        }
+        let k = log[n] + 1;
+        let mut table = vec![a.to_vec(); k];
+        for level in 1..k {
+            let half = 1 << (level - 1);
+            for i in 0..=(n - (1 << level)) {
+                table[level][i] = table[level - 1][i].min(table[level - 1][i + half]);
+            }
+        }
+        SparseTableRmq { table, log }
+    }
+
+    fn query(&self, l: usize, r: usize) -> i64 {
+        let level = self.log[r - l + 1];
+        let half = 1usize << level;
+        self.table[level][l].min(self.table[level][r + 1 - half])
+    }
+}
+
+/// O(n) build, O(log n) query and O(log n) point update. Best when the
+/// array is mutated between queries.
+struct SegTreeRmq {
+    n: usize,
+    tree: Vec<i64>,
+}
+
+impl SegTreeRmq {
+    fn new(a: &[i64]) -> Self {
+        let n = a.len();
+        let mut tree = vec![i64::MAX; 2 * n];
+        tree[n..].copy_from_slice(a);
+        for i in (1..n).rev() {
+            tree[i] = tree[2 * i].min(tree[2 * i + 1]);
+        }
+        SegTreeRmq { n, tree }
+    }
+
+    fn update(&mut self, mut i: usize, value: i64) {
+        i += self.n;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i].min(self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Inclusive-exclusive query `[l, r)`.
+    fn query(&self, mut l: usize, mut r: usize) -> i64 {
+        let mut result = i64::MAX;
+        l += self.n;
+        r += self.n;
+        while l < r {
+            if l & 1 == 1 {
+                result = result.min(self.tree[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                result = result.min(self.tree[r]);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        result
+    }
+}
+
+/// Strategy used internally by [`Rmq`], chosen automatically at construction.
+enum Strategy {
+    Linear(Vec<i64>),
+    Sparse(SparseTableRmq),
+    Segment(SegTreeRmq),
+}
+
+/// Auto-selecting RMQ facade: picks the cheapest structure for the
+/// declared usage pattern so callers don't have to know the tradeoffs.
+struct Rmq {
+    strategy: Strategy,
+}
+
+impl Rmq {
+    const LINEAR_THRESHOLD: usize = 32;
+
+    /// Builds an RMQ for a static array expected to answer `expected_queries`
+    /// range-min queries and never be mutated.
+    fn for_static(a: &[i64], expected_queries: usize) -> Self {
+        let strategy = if a.len() <= Self::LINEAR_THRESHOLD && expected_queries < a.len() {
+            Strategy::Linear(a.to_vec())
+        } else {
+            Strategy::Sparse(SparseTableRmq::new(a))
+        };
+        Rmq { strategy }
+    }
+
+    /// Builds an RMQ for an array that will receive point updates.
+    fn for_mutable(a: &[i64]) -> Self {
+        Rmq { strategy: Strategy::Segment(SegTreeRmq::new(a)) }
+    }
+
+    /// Minimum over the inclusive range `[l, r]`.
+    fn query(&self, l: usize, r: usize) -> i64 {
+        match &self.strategy {
+            Strategy::Linear(a) => a[l..=r].iter().copied().min().unwrap(),
+            Strategy::Sparse(s) => s.query(l, r),
+            Strategy::Segment(s) => s.query(l, r + 1),
+        }
+    }
+
+    /// Point update; only valid (and only efficient) when built with
+    /// [`Rmq::for_mutable`]. Panics otherwise, since a static structure
+    /// cannot be updated cheaply.
+    fn update(&mut self, i: usize, value: i64) {
+        match &mut self.strategy {
+            Strategy::Segment(s) => s.update(i, value),
+            _ => panic!("Rmq::update requires a structure built with Rmq::for_mutable"),
+        }
+    }
+}
+
+fn main() {
+    let a = vec![5, 2, 8, 1, 9, 3, 7, 4, 6];
+
+    let static_rmq = Rmq::for_static(&a, 1000);
+    println!("min[1, 4] = {}", static_rmq.query(1, 4));
+    println!("min[0, 8] = {}", static_rmq.query(0, 8));
+
+    let mut mutable_rmq = Rmq::for_mutable(&a);
+    println!("min[3, 6] = {}", mutable_rmq.query(3, 6));
+    mutable_rmq.update(4, 0);
+    println!("after update, min[3, 6] = {}", mutable_rmq.query(3, 6));
+}
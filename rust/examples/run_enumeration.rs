@@ -0,0 +1,16 @@
+use dsa::string::runs::find_runs;
+
+fn main() {
+    for text in ["aabaabaab", "banana", "mississippi", "abcde"] {
+        let runs = find_runs(text);
+        println!("runs in \"{}\":", text);
+        for run in &runs {
+            println!(
+                "  {:?} (period {}) = \"{}\"",
+                run.start..run.end,
+                run.period,
+                &text[run.start..run.end]
+            );
+        }
+    }
+}
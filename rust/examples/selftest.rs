@@ -0,0 +1,55 @@
+// `dsa selftest`: this repo's example binaries double as self-checking
+// demos (most `assert!`/`assert_eq!` their own output in `main`), but
+// there's no `[[bin]]` wrapper to hang a `dsa` subcommand off of — every
+// runnable thing here lives under `examples/` and is invoked via `cargo
+// run --example <name>` (see `graph_cli.rs`, `huffman_compress.rs`).
+// `cargo run --example selftest` is this repo's equivalent of that
+// subcommand: it runs every other example in turn and reports how many
+// panicked, so a user with a local copy of just `rust/` can validate the
+// whole collection with one command instead of invoking each by hand.
+//
+// A few examples are interactive CLIs that expect arguments or terminal
+// input rather than being self-contained demos; those are skipped rather
+// than reported as failures; see `SKIP` below.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Examples that are CLI tools expecting arguments or stdin input, not
+/// self-checking demos, so running them bare (as every other example is)
+/// would fail, or hang, for a reason unrelated to correctness.
+const SKIP: &[&str] = &["graph_cli", "huffman_compress", "Stack", "selftest"];
+
+fn main() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let mut names: Vec<String> = std::fs::read_dir(&examples_dir)
+        .expect("failed to read the examples directory")
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.path().file_stem().and_then(|s| s.to_str()).map(str::to_owned))
+        .filter(|name| !SKIP.contains(&name.as_str()))
+        .collect();
+    names.sort();
+
+    let mut passed = 0;
+    let mut failed = Vec::new();
+    for name in &names {
+        let status = Command::new("cargo")
+            .args(["run", "--quiet", "--example", name])
+            .status()
+            .unwrap_or_else(|e| panic!("failed to launch example {name}: {e}"));
+        if status.success() {
+            println!("PASS {name}");
+            passed += 1;
+        } else {
+            println!("FAIL {name}");
+            failed.push(name.clone());
+        }
+    }
+
+    println!();
+    println!("{passed}/{} examples passed ({} skipped: {})", names.len(), SKIP.len(), SKIP.join(", "));
+    if !failed.is_empty() {
+        println!("failed: {}", failed.join(", "));
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,116 @@
+// Global sequence alignment with affine gap penalties (Gotoh's algorithm):
+// opening a gap costs `gap_open`, extending it costs `gap_extend` per
+// symbol, which scores more realistically than a flat per-gap penalty.
+
+const NEG_INF: i64 = i64::MIN / 2;
+
+/// Aligns `a` against `b`, returning the best score and one optimal
+/// alignment as a pair of strings padded with `-` for gaps.
+fn align(
+    a: &[u8],
+    b: &[u8],
+    match_score: i64,
+    mismatch: i64,
+    gap_open: i64,
+    gap_extend: i64,
+) -> (i64, String, String) {
+    let n = a.len();
+    let m = b.len();
+
+    // m_mat[i][j]: best score ending with a[i-1] aligned to b[j-1].
+    // x_mat[i][j]: best score ending with a gap in `b` (a[i-1] aligned to '-').
+    // y_mat[i][j]: best score ending with a gap in `a` (b[j-1] aligned to '-').
+    let mut m_mat = vec![vec![NEG_INF; m + 1]; n + 1];
+    let mut x_mat = vec![vec![NEG_INF; m + 1]; n + 1];
+    let mut y_mat = vec![vec![NEG_INF; m + 1]; n + 1];
+
+    m_mat[0][0] = 0;
+    for (i, row) in x_mat.iter_mut().enumerate().skip(1) {
+        row[0] = gap_open + gap_extend * i as i64;
+    }
+    for (j, cell) in y_mat[0].iter_mut().enumerate().skip(1) {
+        *cell = gap_open + gap_extend * j as i64;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let sub = if a[i - 1] == b[j - 1] { match_score } else { mismatch };
+            m_mat[i][j] = sub + m_mat[i - 1][j - 1].max(x_mat[i - 1][j - 1]).max(y_mat[i - 1][j - 1]);
+            x_mat[i][j] = (m_mat[i - 1][j] + gap_open + gap_extend)
+                .max(x_mat[i - 1][j] + gap_extend);
+            y_mat[i][j] = (m_mat[i][j - 1] + gap_open + gap_extend)
+                .max(y_mat[i][j - 1] + gap_extend);
+        }
+    }
+
+    let best = m_mat[n][m].max(x_mat[n][m]).max(y_mat[n][m]);
+
+    // Traceback: track which matrix we're currently in.
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        M,
+        X,
+        Y,
+    }
+    let mut state = if best == m_mat[n][m] {
+        State::M
+    } else if best == x_mat[n][m] {
+        State::X
+    } else {
+        State::Y
+    };
+
+    let mut aligned_a = Vec::new();
+    let mut aligned_b = Vec::new();
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        match state {
+            State::M => {
+                aligned_a.push(a[i - 1]);
+                aligned_b.push(b[j - 1]);
+                let sub = if a[i - 1] == b[j - 1] { match_score } else { mismatch };
+                let prev = m_mat[i][j] - sub;
+                i -= 1;
+                j -= 1;
+                state = if prev == m_mat[i][j] {
+                    State::M
+                } else if prev == x_mat[i][j] {
+                    State::X
+                } else {
+                    State::Y
+                };
+            }
+            State::X => {
+                aligned_a.push(a[i - 1]);
+                aligned_b.push(b'-');
+                let from_m = x_mat[i][j] == m_mat[i - 1][j] + gap_open + gap_extend;
+                i -= 1;
+                state = if from_m { State::M } else { State::X };
+            }
+            State::Y => {
+                aligned_a.push(b'-');
+                aligned_b.push(b[j - 1]);
+                let from_m = y_mat[i][j] == m_mat[i][j - 1] + gap_open + gap_extend;
+                j -= 1;
+                state = if from_m { State::M } else { State::Y };
+            }
+        }
+    }
+
+    aligned_a.reverse();
+    aligned_b.reverse();
+    (
+        best,
+        String::from_utf8(aligned_a).unwrap(),
+        String::from_utf8(aligned_b).unwrap(),
+    )
+}
+
+fn main() {
+    let a = b"GATTACA";
+    let b = b"GCATGCA";
+    let (score, aligned_a, aligned_b) = align(a, b, 2, -1, -3, -1);
+    println!("score: {}", score);
+    println!("{}", aligned_a);
+    println!("{}", aligned_b);
+}
@@ -0,0 +1,39 @@
+use dsa::graph::shortest_path::{BellmanFordSp, DijkstraSp, FloydWarshallSp, ShortestPath};
+use dsa::graph::types::{Edge, WeightedEdge};
+
+/// Runs any `ShortestPath` implementation from `source` and prints its
+/// distances, demonstrating that call sites don't need to know which
+/// algorithm backs them.
+fn report(name: &str, algo: &dyn ShortestPath, source: usize) {
+    match algo.shortest_paths(source) {
+        Some(dist) => {
+            for (v, d) in dist.iter().enumerate() {
+                if d.is_infinite() {
+                    println!("{name}: {source} -> {v}: unreachable");
+                } else {
+                    println!("{name}: {source} -> {v}: {d}");
+                }
+            }
+        }
+        None => println!("{name}: negative weight cycle detected"),
+    }
+}
+
+fn main() {
+    let n = 4;
+    let weighted_edges = vec![
+        WeightedEdge { from: 0, to: 1, weight: 4 },
+        WeightedEdge { from: 0, to: 3, weight: 15 },
+        WeightedEdge { from: 1, to: 2, weight: 3 },
+        WeightedEdge { from: 2, to: 3, weight: 5 },
+    ];
+
+    let mut adj_list = vec![Vec::new(); n];
+    for edge in &weighted_edges {
+        adj_list[edge.from].push(Edge { to: edge.to, weight: edge.weight });
+    }
+
+    report("dijkstra", &DijkstraSp { graph: &adj_list }, 0);
+    report("bellman-ford", &BellmanFordSp { vertices: n, edges: &weighted_edges }, 0);
+    report("floyd-warshall", &FloydWarshallSp { vertices: n, edges: &weighted_edges }, 0);
+}
@@ -0,0 +1,8 @@
+use dsa::streaming::sliding_window_median::sliding_window_medians;
+
+fn main() {
+    let a = [1, 3, -1, -3, 5, 3, 6, 7];
+    let k = 3;
+    println!("array: {:?}", a);
+    println!("sliding window medians (k={}): {:?}", k, sliding_window_medians(&a, k));
+}
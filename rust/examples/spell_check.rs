@@ -0,0 +1,25 @@
+use dsa::collections::bk_tree::BkTree;
+use dsa::string::myers_levenshtein::edit_distance_dp;
+
+fn main() {
+    let dictionary = [
+        "apple", "apply", "apples", "maple", "ample", "banana", "bandana", "orange", "grape", "grapefruit",
+    ];
+
+    let mut tree = BkTree::new();
+    for word in dictionary {
+        tree.insert(word.as_bytes());
+    }
+
+    for misspelled in ["aple", "bananna", "graep"] {
+        let suggestions = tree.find_within(misspelled.as_bytes(), 2);
+        println!("{misspelled}: {suggestions:?}");
+
+        // The tree must agree with checking every dictionary word by hand.
+        for word in dictionary {
+            let distance = edit_distance_dp(word.as_bytes(), misspelled.as_bytes());
+            let in_suggestions = suggestions.iter().any(|(w, d)| w == word.as_bytes() && *d == distance);
+            assert_eq!(distance <= 2, in_suggestions);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use dsa::graph::stoer_wagner::minimum_cut;
+
+fn main() {
+    // A graph with an obvious min cut of weight 4 (split {0,1,2} | {3,4,5}).
+    let n = 6;
+    let mut adj = vec![vec![0i64; n]; n];
+    let edges = [(0, 1, 2), (0, 2, 3), (1, 2, 2), (2, 3, 2), (2, 4, 2), (3, 4, 3), (3, 5, 1), (4, 5, 1)];
+    for &(u, v, w) in &edges {
+        adj[u][v] += w;
+        adj[v][u] += w;
+    }
+
+    println!("global min cut weight: {}", minimum_cut(&adj));
+}
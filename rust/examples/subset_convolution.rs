@@ -0,0 +1,17 @@
+use dsa::dp::subset_convolution::{count_k_independent_partitions, subset_convolution};
+
+fn main() {
+    let a = vec![1, 2, 3, 4];
+    let b = vec![5, 6, 7, 8];
+    println!("subset convolution: {:?}", subset_convolution(&a, &b));
+
+    // A 4-cycle: 0-1-2-3-0.
+    let adj = [0b0110u32, 0b0101, 0b1010, 0b1001];
+    for k in 1..=2 {
+        println!(
+            "ways to split the 4-cycle into {} independent sets: {}",
+            k,
+            count_k_independent_partitions(&adj, k)
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use dsa::string::suffix_array::SubstringComparator;
+
+fn main() {
+    let text = "banana";
+    let cmp = SubstringComparator::new(text);
+
+    // All substrings as (start, len) pairs.
+    let mut substrings: Vec<(usize, usize)> = Vec::new();
+    for start in 0..text.len() {
+        for len in 1..=(text.len() - start) {
+            substrings.push((start, len));
+        }
+    }
+
+    substrings.sort_by(|&(s1, l1), &(s2, l2)| cmp.compare(s1, l1, s2, l2));
+    substrings.dedup_by(|&mut (s1, l1), &mut (s2, l2)| {
+        let common = cmp.lcp(s1, l1, s2, l2);
+        common == l1 && common == l2
+    });
+
+    println!("distinct substrings of \"{}\", lexicographically sorted:", text);
+    for (start, len) in &substrings {
+        println!("{}", &text[*start..*start + *len]);
+    }
+
+    let lcp = cmp.lcp(1, 3, 3, 3); // "ana" (from index 1) vs "ana" (from index 3)
+    println!("lcp(\"ana\"@1, \"ana\"@3) = {}", lcp);
+}
@@ -0,0 +1,21 @@
+use dsa::tree::tree_multiset_hash::MultisetHasher;
+
+fn main() {
+    let mut hasher = MultisetHasher::new();
+    let a = vec![3u64, 1, 4, 1, 5];
+    let b = vec![1u64, 5, 4, 1, 3];
+    let c = vec![3u64, 1, 4, 1, 6];
+    println!("hash(a) == hash(b): {}", hasher.multiset_hash(&a) == hasher.multiset_hash(&b));
+    println!("hash(a) == hash(c): {}", hasher.multiset_hash(&a) == hasher.multiset_hash(&c));
+
+    // Two isomorphic rooted trees with differently-ordered children.
+    let adj_a = vec![vec![1, 2], vec![0], vec![0, 3], vec![2]];
+    let labels_a = vec![0u64, 0, 0, 0];
+    let adj_b = vec![vec![1, 2], vec![0, 3], vec![0], vec![1]];
+    let labels_b = vec![0u64, 0, 0, 0];
+
+    println!(
+        "tree_hash(a) == tree_hash(b): {}",
+        hasher.tree_hash(&adj_a, &labels_a, 0) == hasher.tree_hash(&adj_b, &labels_b, 0)
+    );
+}
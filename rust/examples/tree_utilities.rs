@@ -0,0 +1,19 @@
+use dsa::tree::utilities::{center, centroids, diameter};
+
+fn main() {
+    // A path-like tree with a branch: 0-1-2-3-4, plus 2-5.
+    let adj = vec![
+        vec![1],
+        vec![0, 2],
+        vec![1, 3, 5],
+        vec![2, 4],
+        vec![3],
+        vec![2],
+    ];
+
+    let (len, path) = diameter(&adj);
+    println!("diameter length: {}", len);
+    println!("diameter path: {:?}", path);
+    println!("center: {:?}", center(&adj));
+    println!("centroid(s): {:?}", centroids(&adj));
+}
@@ -0,0 +1,26 @@
+use dsa::math::weighted_sampling::{AliasSampler, DynamicWeightedSampler};
+
+fn main() {
+    let weights = [1.0, 4.0, 2.0, 3.0];
+
+    let mut alias = AliasSampler::new(&weights, 42);
+    let mut counts = [0u32; 4];
+    for _ in 0..10_000 {
+        counts[alias.sample()] += 1;
+    }
+    println!("alias-method sample counts (weights {:?}): {:?}", weights, counts);
+
+    let mut dynamic = DynamicWeightedSampler::new(&weights, 42);
+    let mut counts2 = [0u32; 4];
+    for _ in 0..10_000 {
+        counts2[dynamic.sample()] += 1;
+    }
+    println!("dynamic sampler counts (before reweight): {:?}", counts2);
+
+    dynamic.set_weight(0, 20.0); // heavily favor index 0
+    let mut counts3 = [0u32; 4];
+    for _ in 0..10_000 {
+        counts3[dynamic.sample()] += 1;
+    }
+    println!("dynamic sampler counts (after reweighting index 0): {:?}", counts3);
+}
@@ -0,0 +1,33 @@
+#![no_main]
+
+use dsa::graph::dijkstra::dijkstra_with_path;
+use dsa::graph::types::{AdjList, Edge};
+use libfuzzer_sys::fuzz_target;
+
+/// Decodes an arbitrary byte string into a small adjacency list plus a
+/// start/end pair, all bounded so the fuzzer explores shapes (including
+/// disconnected vertices and zero-weight cycles) instead of just sizes.
+fn decode(data: &[u8]) -> Option<(AdjList, usize, usize)> {
+    let mut bytes = data.iter().copied();
+    let mut next = move || bytes.next();
+
+    let vertex_count = (next()? as usize % 16) + 1;
+    let mut graph: AdjList = vec![Vec::new(); vertex_count];
+    for adj in graph.iter_mut() {
+        let edge_count = next()? as usize % 4;
+        for _ in 0..edge_count {
+            let to = next()? as usize % vertex_count;
+            let weight = next()? as i64 % 8;
+            adj.push(Edge { to, weight });
+        }
+    }
+    let start = next()? as usize % vertex_count;
+    let end = next()? as usize % vertex_count;
+    Some((graph, start, end))
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((graph, start, end)) = decode(data) {
+        let _ = dijkstra_with_path(&graph, start, end);
+    }
+});
@@ -0,0 +1,71 @@
+//! `PyO3` bindings exposing `Graph`, the shortest-path solvers, and
+//! `introsort` to Python, so the benchmarks under `python/` can call the
+//! Rust implementations directly instead of re-running the pure-Python
+//! versions. A separate crate (like `fuzz/`) so the main `dsa` crate stays
+//! buildable for `#![no_std]` targets without pulling in `pyo3`.
+
+use ::dsa::graph::dijkstra::dijkstra_with_path;
+use ::dsa::graph::floyd_warshall::floyd_warshall;
+use ::dsa::graph::types::{AdjList, Edge, WeightedEdge};
+use ::dsa::sorting::introsort::introsort;
+use pyo3::prelude::*;
+
+/// A graph built incrementally from Python, then handed to whichever
+/// shortest-path algorithm the caller wants to run.
+#[pyclass]
+pub struct Graph {
+    vertices: usize,
+    edges: Vec<WeightedEdge>,
+}
+
+#[pymethods]
+impl Graph {
+    #[new]
+    fn new(vertices: usize) -> Self {
+        Graph { vertices, edges: Vec::new() }
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: i64) {
+        self.edges.push(WeightedEdge { from, to, weight });
+    }
+
+    /// Shortest distance and path from `start` to `end`, or `None` if
+    /// `end` is unreachable.
+    fn dijkstra_path(&self, start: usize, end: usize) -> Option<(i64, Vec<usize>)> {
+        dijkstra_with_path(&self.adjacency_list(), start, end)
+    }
+
+    /// All-pairs distances as `dist[u][v]`, with `None` in place of
+    /// `f64::INFINITY` for unreachable pairs (Python has no float
+    /// infinity literal that round-trips cleanly through every caller).
+    fn floyd_warshall(&self) -> Vec<Vec<Option<f64>>> {
+        floyd_warshall(self.vertices, &self.edges)
+            .into_iter()
+            .map(|row| row.into_iter().map(|d| if d.is_infinite() { None } else { Some(d) }).collect())
+            .collect()
+    }
+}
+
+impl Graph {
+    fn adjacency_list(&self) -> AdjList {
+        let mut adj: AdjList = vec![Vec::new(); self.vertices];
+        for edge in &self.edges {
+            adj[edge.from].push(Edge { to: edge.to, weight: edge.weight });
+        }
+        adj
+    }
+}
+
+/// Sorts a list of integers with [`introsort`].
+#[pyfunction]
+fn introsort_ints(mut values: Vec<i64>) -> Vec<i64> {
+    introsort(&mut values);
+    values
+}
+
+#[pymodule]
+fn dsa(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Graph>()?;
+    m.add_function(wrap_pyfunction!(introsort_ints, m)?)?;
+    Ok(())
+}
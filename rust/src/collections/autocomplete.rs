@@ -0,0 +1,199 @@
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// One trie node: byte-keyed children plus a cached `top_k`, the
+/// highest-weight terms reachable through this node (itself included, if
+/// it's a term's end), already merged and capped so a [`query`](Autocomplete::query)
+/// is just reading this list straight off.
+struct TrieNode {
+    children: BTreeMap<u8, u32>,
+    terminal: Option<usize>,
+    top_k: Vec<(i64, usize)>,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        TrieNode { children: BTreeMap::new(), terminal: None, top_k: Vec::new() }
+    }
+}
+
+/// One ranked source a [`merge_top_k`] heap-merge pulls from: either a
+/// node's own terminal weight, or the next not-yet-consumed entry of one
+/// child's already-sorted `top_k` list.
+#[derive(Clone, Copy)]
+struct Candidate {
+    weight: i64,
+    term: usize,
+    child: Option<(usize, usize)>, // (child index into `children_lists`, position within its list)
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.weight == other.weight && self.term == other.term
+    }
+}
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Highest weight first; ties broken by the lower term id, so
+        // results are deterministic regardless of insertion order.
+        self.weight.cmp(&other.weight).then_with(|| other.term.cmp(&self.term))
+    }
+}
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Merges a node's own terminal entry (if any) with its children's
+/// already-ranked `top_k` lists via a k-way heap merge, keeping only the
+/// `k` highest-weight results — the same idea as merging `k` sorted runs,
+/// just with "run" meaning "one child's cached ranking".
+fn merge_top_k(own: Option<(i64, usize)>, children_lists: &[&Vec<(i64, usize)>], k: usize) -> Vec<(i64, usize)> {
+    let mut heap = BinaryHeap::new();
+    if let Some((weight, term)) = own {
+        heap.push(Candidate { weight, term, child: None });
+    }
+    for (child_index, list) in children_lists.iter().enumerate() {
+        if let Some(&(weight, term)) = list.first() {
+            heap.push(Candidate { weight, term, child: Some((child_index, 0)) });
+        }
+    }
+
+    let mut result = Vec::with_capacity(k);
+    while result.len() < k {
+        let Some(top) = heap.pop() else { break };
+        result.push((top.weight, top.term));
+        if let Some((child_index, position)) = top.child {
+            if let Some(&(weight, term)) = children_lists[child_index].get(position + 1) {
+                heap.push(Candidate { weight, term, child: Some((child_index, position + 1)) });
+            }
+        }
+    }
+    result
+}
+
+/// Trie-based autocomplete: every node caches the `k` highest-weight
+/// completions reachable beneath it, so [`query`](Self::query) is O(k)
+/// instead of walking every term under the prefix at lookup time. Paying
+/// for that cache is O(depth * k log k) per [`insert`](Self::insert) or
+/// [`update_weight`](Self::update_weight), re-merging every node from the
+/// affected leaf back up to the root.
+pub struct Autocomplete {
+    k: usize,
+    arena: Vec<TrieNode>,
+    terms: Vec<Vec<u8>>,
+    weights: Vec<i64>,
+    /// Root-to-leaf node path per term id, so a weight change only needs
+    /// to re-merge the nodes that term actually passes through.
+    paths: Vec<Vec<u32>>,
+}
+
+impl Autocomplete {
+    /// Ranks completions by the `k` highest weights per prefix.
+    pub fn new(k: usize) -> Self {
+        Autocomplete { k, arena: vec![TrieNode::new()], terms: Vec::new(), weights: Vec::new(), paths: Vec::new() }
+    }
+
+    /// Inserts `term` with `weight`, returning its term id (for later
+    /// [`update_weight`](Self::update_weight) calls). Each term should be
+    /// inserted once; re-inserting the same bytes replaces which term id
+    /// owns that trie position rather than adding a second entry.
+    pub fn insert(&mut self, term: &[u8], weight: i64) -> usize {
+        let mut node = 0u32;
+        let mut path = vec![0u32];
+        for &byte in term {
+            node = match self.arena[node as usize].children.get(&byte) {
+                Some(&next) => next,
+                None => {
+                    self.arena.push(TrieNode::new());
+                    let new_index = (self.arena.len() - 1) as u32;
+                    self.arena[node as usize].children.insert(byte, new_index);
+                    new_index
+                }
+            };
+            path.push(node);
+        }
+
+        let term_id = self.terms.len();
+        self.terms.push(term.to_vec());
+        self.weights.push(weight);
+        self.paths.push(path.clone());
+        self.arena[node as usize].terminal = Some(term_id);
+        self.refresh_path(&path);
+        term_id
+    }
+
+    /// Updates a previously inserted term's weight, re-ranking every
+    /// node on its path.
+    pub fn update_weight(&mut self, term_id: usize, new_weight: i64) {
+        self.weights[term_id] = new_weight;
+        let path = self.paths[term_id].clone();
+        self.refresh_path(&path);
+    }
+
+    /// The `k` highest-weight terms starting with `prefix`, highest
+    /// weight first (ties broken by insertion order), or empty if no
+    /// term has this prefix.
+    pub fn query(&self, prefix: &[u8]) -> Vec<(Vec<u8>, i64)> {
+        let mut node = 0u32;
+        for &byte in prefix {
+            match self.arena[node as usize].children.get(&byte) {
+                Some(&next) => node = next,
+                None => return Vec::new(),
+            }
+        }
+        self.arena[node as usize].top_k.iter().map(|&(weight, term)| (self.terms[term].clone(), weight)).collect()
+    }
+
+    /// Re-merges every node on `path`, leaf first so each node's own
+    /// refresh only ever reads already-up-to-date children.
+    fn refresh_path(&mut self, path: &[u32]) {
+        for &node_index in path.iter().rev() {
+            let own = self.arena[node_index as usize].terminal.map(|term| (self.weights[term], term));
+            let children_lists: Vec<&Vec<(i64, usize)>> =
+                self.arena[node_index as usize].children.values().map(|&child| &self.arena[child as usize].top_k).collect();
+            let merged = merge_top_k(own, &children_lists, self.k);
+            self.arena[node_index as usize].top_k = merged;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_completions_by_weight_descending() {
+        let mut ac = Autocomplete::new(2);
+        ac.insert(b"cat", 5);
+        ac.insert(b"car", 9);
+        ac.insert(b"cart", 3);
+        ac.insert(b"dog", 7);
+
+        let results = ac.query(b"ca");
+        assert_eq!(results, vec![(b"car".to_vec(), 9), (b"cat".to_vec(), 5)]);
+    }
+
+    #[test]
+    fn unknown_prefix_returns_nothing() {
+        let mut ac = Autocomplete::new(3);
+        ac.insert(b"hello", 1);
+        assert!(ac.query(b"world").is_empty());
+    }
+
+    #[test]
+    fn weight_update_changes_the_ranking() {
+        let mut ac = Autocomplete::new(1);
+        let cat = ac.insert(b"cat", 1);
+        ac.insert(b"car", 2);
+        assert_eq!(ac.query(b"ca"), vec![(b"car".to_vec(), 2)]);
+
+        ac.update_weight(cat, 100);
+        assert_eq!(ac.query(b"ca"), vec![(b"cat".to_vec(), 100)]);
+    }
+}
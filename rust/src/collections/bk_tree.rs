@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+use crate::string::myers_levenshtein::edit_distance_dp;
+
+/// One entry in the tree: its word, and the children reachable from it,
+/// each tagged with its exact edit distance from this node.
+struct Node {
+    word: Vec<u8>,
+    children: Vec<(usize, u32)>,
+}
+
+/// A Burkhard-Keller tree over Levenshtein distance: a metric tree that
+/// answers "every word within distance `d`" without comparing against
+/// every entry, by pruning whole subtrees via the triangle inequality
+/// (`|dist(query, child) - dist(query, node)| <= d` must hold for `child`
+/// to possibly be within `d` of `query`, since `dist(query, node)` and
+/// the node-to-child edge distance are both known exactly).
+///
+/// Arena-indexed (`u32`, like [`super::segment_tree::SegmentTree`]'s
+/// siblings) rather than boxed, since children are discovered one at a
+/// time as words are inserted and a `Vec` of them per node is simplest.
+pub struct BkTree {
+    nodes: Vec<Node>,
+    root: Option<u32>,
+}
+
+impl BkTree {
+    pub fn new() -> Self {
+        BkTree { nodes: Vec::new(), root: None }
+    }
+
+    /// Inserts `word`. A no-op if an identical word (distance 0) is
+    /// already present.
+    pub fn insert(&mut self, word: &[u8]) {
+        let Some(root) = self.root else {
+            self.root = Some(self.push_node(word));
+            return;
+        };
+
+        let mut current = root;
+        loop {
+            let distance = edit_distance_dp(&self.nodes[current as usize].word, word);
+            if distance == 0 {
+                return;
+            }
+            match self.nodes[current as usize].children.iter().find(|&&(d, _)| d == distance) {
+                Some(&(_, child)) => current = child,
+                None => {
+                    let new_node = self.push_node(word);
+                    self.nodes[current as usize].children.push((distance, new_node));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn push_node(&mut self, word: &[u8]) -> u32 {
+        self.nodes.push(Node { word: word.to_vec(), children: Vec::new() });
+        (self.nodes.len() - 1) as u32
+    }
+
+    /// Every stored word within `max_distance` edits of `query`, paired
+    /// with its exact distance.
+    pub fn find_within(&self, query: &[u8], max_distance: usize) -> Vec<(Vec<u8>, usize)> {
+        let mut matches = Vec::new();
+        if let Some(root) = self.root {
+            self.find_within_node(root, query, max_distance, &mut matches);
+        }
+        matches
+    }
+
+    fn find_within_node(&self, node: u32, query: &[u8], max_distance: usize, matches: &mut Vec<(Vec<u8>, usize)>) {
+        let node = &self.nodes[node as usize];
+        let distance = edit_distance_dp(&node.word, query);
+        if distance <= max_distance {
+            matches.push((node.word.clone(), distance));
+        }
+        for &(edge_distance, child) in &node.children {
+            if edge_distance.abs_diff(distance) <= max_distance {
+                self.find_within_node(child, query, max_distance, matches);
+            }
+        }
+    }
+}
+
+impl Default for BkTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary() -> BkTree {
+        let mut tree = BkTree::new();
+        for word in ["book", "books", "cake", "boo", "cook", "cape", "cart"] {
+            tree.insert(word.as_bytes());
+        }
+        tree
+    }
+
+    #[test]
+    fn finds_every_word_within_distance_matching_a_linear_scan() {
+        let tree = dictionary();
+        let words = ["book", "books", "cake", "boo", "cook", "cape", "cart"];
+
+        for query in ["book", "bok", "cape", "zzzz"] {
+            for max_distance in 0..=3 {
+                let mut from_tree = tree.find_within(query.as_bytes(), max_distance);
+                from_tree.sort();
+
+                let mut from_scan: Vec<(Vec<u8>, usize)> = words
+                    .iter()
+                    .map(|w| (w.as_bytes().to_vec(), edit_distance_dp(w.as_bytes(), query.as_bytes())))
+                    .filter(|&(_, d)| d <= max_distance)
+                    .collect();
+                from_scan.sort();
+
+                assert_eq!(from_tree, from_scan, "query={query:?} max_distance={max_distance}");
+            }
+        }
+    }
+
+    #[test]
+    fn an_exact_match_has_distance_zero() {
+        let tree = dictionary();
+        let matches = tree.find_within(b"cake", 0);
+        assert_eq!(matches, vec![(b"cake".to_vec(), 0)]);
+    }
+
+    #[test]
+    fn reinserting_an_existing_word_does_not_duplicate_it() {
+        let mut tree = dictionary();
+        tree.insert(b"book");
+        assert_eq!(tree.find_within(b"book", 0).len(), 1);
+    }
+
+    #[test]
+    fn an_empty_tree_finds_nothing() {
+        let tree = BkTree::new();
+        assert!(tree.find_within(b"anything", 5).is_empty());
+    }
+}
@@ -0,0 +1,458 @@
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+
+/// One arena slot. Unlike the binary trees in this module ([`Treap`],
+/// [`AvlTree`], [`RedBlackTree`]), a node here holds up to `2 * min_degree -
+/// 1` keys at once (plus one more child than it has keys, for internal
+/// nodes) — the wide, shallow shape that makes a B-tree "disk-friendly":
+/// each node is sized to fill one page, so a lookup touches O(log_t n)
+/// pages instead of O(log_2 n).
+///
+/// [`Treap`]: crate::collections::treap::Treap
+/// [`AvlTree`]: crate::collections::avl_tree::AvlTree
+/// [`RedBlackTree`]: crate::collections::red_black_tree::RedBlackTree
+struct Node<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    /// Empty for a leaf; otherwise always `keys.len() + 1` entries,
+    /// indices into the same arena.
+    children: Vec<u32>,
+    leaf: bool,
+}
+
+/// A B-tree of configurable order: every non-root node holds between
+/// `min_degree - 1` and `2 * min_degree - 1` keys, kept sorted, with
+/// `insert`/`remove` proactively splitting/merging nodes on the way down
+/// so every node visited during a mutation already has room to give up
+/// or accept a key. A higher `min_degree` means wider, shallower nodes —
+/// the parameter a real disk-backed B-tree would tune to its page size.
+pub struct BTree<K: Ord, V> {
+    arena: Vec<Node<K, V>>,
+    root: u32,
+    min_degree: usize,
+    len: usize,
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    /// `min_degree` (the textbook `t`) must be at least 2: every node
+    /// other than the root then holds between `min_degree - 1` and
+    /// `2 * min_degree - 1` keys.
+    pub fn new(min_degree: usize) -> Self {
+        assert!(min_degree >= 2, "B-tree minimum degree must be at least 2");
+        BTree { arena: Vec::new(), root: NULL, min_degree, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut node = self.root;
+        while node != NULL {
+            let n = &self.arena[node as usize];
+            let pos = n.keys.partition_point(|k| k < key);
+            if pos < n.keys.len() && n.keys[pos] == *key {
+                return Some(&n.values[pos]);
+            }
+            if n.leaf {
+                return None;
+            }
+            node = n.children[pos];
+        }
+        None
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts `key` -> `value`, returning the previous value if `key`
+    /// was already present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.root == NULL {
+            self.root = self.push_node(true);
+        }
+        if self.arena[self.root as usize].keys.len() == self.max_keys() {
+            let new_root = self.push_node(false);
+            self.arena[new_root as usize].children.push(self.root);
+            self.split_child(new_root, 0);
+            self.root = new_root;
+        }
+        let old = self.insert_non_full(self.root, key, value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+        old
+    }
+
+    /// Removes `key`, returning its value if present.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if self.root == NULL {
+            return None;
+        }
+        let removed = self.remove_from(self.root, key);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        if self.arena[self.root as usize].keys.is_empty() {
+            self.root =
+                if self.arena[self.root as usize].leaf { NULL } else { self.arena[self.root as usize].children[0] };
+        }
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+        removed
+    }
+
+    /// The key-value pairs in the half-open key range `[lo, hi)`,
+    /// ascending.
+    pub fn range(&self, lo: &K, hi: &K) -> Vec<(&K, &V)> {
+        let mut result = Vec::new();
+        self.range_from(self.root, lo, hi, &mut result);
+        result
+    }
+
+    fn range_from<'a>(&'a self, node: u32, lo: &K, hi: &K, result: &mut Vec<(&'a K, &'a V)>) {
+        if node == NULL {
+            return;
+        }
+        let n = &self.arena[node as usize];
+        for i in 0..n.keys.len() {
+            if !n.leaf && n.keys[i] > *lo {
+                self.range_from(n.children[i], lo, hi, result);
+            }
+            if n.keys[i] >= *lo && n.keys[i] < *hi {
+                result.push((&n.keys[i], &n.values[i]));
+            }
+            if n.keys[i] >= *hi {
+                return;
+            }
+        }
+        if !n.leaf {
+            self.range_from(*n.children.last().unwrap(), lo, hi, result);
+        }
+    }
+
+    fn max_keys(&self) -> usize {
+        2 * self.min_degree - 1
+    }
+
+    fn key_count(&self, node: u32) -> usize {
+        self.arena[node as usize].keys.len()
+    }
+
+    fn push_node(&mut self, leaf: bool) -> u32 {
+        self.arena.push(Node { keys: Vec::new(), values: Vec::new(), children: Vec::new(), leaf });
+        (self.arena.len() - 1) as u32
+    }
+
+    /// Splits the full node `parent.children[index]` in two around its
+    /// median key, which moves up into `parent` at `index`.
+    fn split_child(&mut self, parent: u32, index: usize) {
+        let t = self.min_degree;
+        let child = self.arena[parent as usize].children[index];
+        let leaf = self.arena[child as usize].leaf;
+
+        let right_keys = self.arena[child as usize].keys.split_off(t);
+        let median_key = self.arena[child as usize].keys.pop().unwrap();
+        let right_values = self.arena[child as usize].values.split_off(t);
+        let median_value = self.arena[child as usize].values.pop().unwrap();
+        let right_children = if leaf { Vec::new() } else { self.arena[child as usize].children.split_off(t) };
+
+        let new_node = self.push_node(leaf);
+        self.arena[new_node as usize].keys = right_keys;
+        self.arena[new_node as usize].values = right_values;
+        self.arena[new_node as usize].children = right_children;
+
+        self.arena[parent as usize].keys.insert(index, median_key);
+        self.arena[parent as usize].values.insert(index, median_value);
+        self.arena[parent as usize].children.insert(index + 1, new_node);
+    }
+
+    /// Inserts into a subtree rooted at a node that is guaranteed not to
+    /// be full (the caller splits full children before descending into
+    /// them, so this never needs to split `node` itself).
+    fn insert_non_full(&mut self, node: u32, key: K, value: V) -> Option<V> {
+        let pos = self.arena[node as usize].keys.partition_point(|k| *k < key);
+        if pos < self.arena[node as usize].keys.len() && self.arena[node as usize].keys[pos] == key {
+            return Some(core::mem::replace(&mut self.arena[node as usize].values[pos], value));
+        }
+        if self.arena[node as usize].leaf {
+            self.arena[node as usize].keys.insert(pos, key);
+            self.arena[node as usize].values.insert(pos, value);
+            return None;
+        }
+        let mut child = self.arena[node as usize].children[pos];
+        if self.key_count(child) == self.max_keys() {
+            self.split_child(node, pos);
+            match key.cmp(&self.arena[node as usize].keys[pos]) {
+                core::cmp::Ordering::Equal => {
+                    return Some(core::mem::replace(&mut self.arena[node as usize].values[pos], value));
+                }
+                core::cmp::Ordering::Greater => child = self.arena[node as usize].children[pos + 1],
+                core::cmp::Ordering::Less => child = self.arena[node as usize].children[pos],
+            }
+        }
+        self.insert_non_full(child, key, value)
+    }
+
+    fn remove_from(&mut self, node: u32, key: &K) -> Option<V> {
+        let t = self.min_degree;
+        let keys_len = self.arena[node as usize].keys.len();
+        let pos = self.arena[node as usize].keys.partition_point(|k| k < key);
+        let found = pos < keys_len && self.arena[node as usize].keys[pos] == *key;
+
+        if self.arena[node as usize].leaf {
+            return if found {
+                self.arena[node as usize].keys.remove(pos);
+                Some(self.arena[node as usize].values.remove(pos))
+            } else {
+                None
+            };
+        }
+
+        if found {
+            let left_child = self.arena[node as usize].children[pos];
+            let right_child = self.arena[node as usize].children[pos + 1];
+            if self.key_count(left_child) >= t {
+                let (pred_key, pred_value) = self.remove_max(left_child);
+                self.arena[node as usize].keys[pos] = pred_key;
+                Some(core::mem::replace(&mut self.arena[node as usize].values[pos], pred_value))
+            } else if self.key_count(right_child) >= t {
+                let (succ_key, succ_value) = self.remove_min(right_child);
+                self.arena[node as usize].keys[pos] = succ_key;
+                Some(core::mem::replace(&mut self.arena[node as usize].values[pos], succ_value))
+            } else {
+                self.merge_children(node, pos);
+                self.remove_from(node, key)
+            }
+        } else {
+            let children_before = self.arena[node as usize].children.len();
+            let child = self.arena[node as usize].children[pos];
+            if self.key_count(child) < t {
+                self.fill_child(node, pos);
+            }
+            let children_after = self.arena[node as usize].children.len();
+            let new_pos = if pos > 0 && children_after < children_before { pos - 1 } else { pos };
+            let child = self.arena[node as usize].children[new_pos];
+            self.remove_from(child, key)
+        }
+    }
+
+    /// Removes and returns the largest key-value pair in `node`'s subtree.
+    fn remove_max(&mut self, node: u32) -> (K, V) {
+        if self.arena[node as usize].leaf {
+            let key = self.arena[node as usize].keys.pop().unwrap();
+            let value = self.arena[node as usize].values.pop().unwrap();
+            return (key, value);
+        }
+        let t = self.min_degree;
+        let last = self.arena[node as usize].children.len() - 1;
+        if self.key_count(self.arena[node as usize].children[last]) < t {
+            self.fill_child(node, last);
+        }
+        let last = self.arena[node as usize].children.len() - 1;
+        let child = self.arena[node as usize].children[last];
+        self.remove_max(child)
+    }
+
+    /// Removes and returns the smallest key-value pair in `node`'s subtree.
+    fn remove_min(&mut self, node: u32) -> (K, V) {
+        if self.arena[node as usize].leaf {
+            let key = self.arena[node as usize].keys.remove(0);
+            let value = self.arena[node as usize].values.remove(0);
+            return (key, value);
+        }
+        let t = self.min_degree;
+        if self.key_count(self.arena[node as usize].children[0]) < t {
+            self.fill_child(node, 0);
+        }
+        let child = self.arena[node as usize].children[0];
+        self.remove_min(child)
+    }
+
+    /// Ensures `parent.children[index]` holds at least `min_degree`
+    /// keys, by borrowing one from a sibling that can spare it, or
+    /// merging with a sibling otherwise.
+    fn fill_child(&mut self, parent: u32, index: usize) {
+        let t = self.min_degree;
+        let has_left_sibling = index > 0;
+        let has_right_sibling = index + 1 < self.arena[parent as usize].children.len();
+
+        if has_left_sibling && self.key_count(self.arena[parent as usize].children[index - 1]) >= t {
+            self.borrow_from_left(parent, index);
+        } else if has_right_sibling && self.key_count(self.arena[parent as usize].children[index + 1]) >= t {
+            self.borrow_from_right(parent, index);
+        } else if has_left_sibling {
+            self.merge_children(parent, index - 1);
+        } else {
+            self.merge_children(parent, index);
+        }
+    }
+
+    fn borrow_from_left(&mut self, parent: u32, index: usize) {
+        let child = self.arena[parent as usize].children[index];
+        let left_sibling = self.arena[parent as usize].children[index - 1];
+
+        let sibling_key = self.arena[left_sibling as usize].keys.pop().unwrap();
+        let sibling_value = self.arena[left_sibling as usize].values.pop().unwrap();
+        let parent_key = core::mem::replace(&mut self.arena[parent as usize].keys[index - 1], sibling_key);
+        let parent_value = core::mem::replace(&mut self.arena[parent as usize].values[index - 1], sibling_value);
+        self.arena[child as usize].keys.insert(0, parent_key);
+        self.arena[child as usize].values.insert(0, parent_value);
+
+        if !self.arena[left_sibling as usize].leaf {
+            let moved_child = self.arena[left_sibling as usize].children.pop().unwrap();
+            self.arena[child as usize].children.insert(0, moved_child);
+        }
+    }
+
+    fn borrow_from_right(&mut self, parent: u32, index: usize) {
+        let child = self.arena[parent as usize].children[index];
+        let right_sibling = self.arena[parent as usize].children[index + 1];
+
+        let sibling_key = self.arena[right_sibling as usize].keys.remove(0);
+        let sibling_value = self.arena[right_sibling as usize].values.remove(0);
+        let parent_key = core::mem::replace(&mut self.arena[parent as usize].keys[index], sibling_key);
+        let parent_value = core::mem::replace(&mut self.arena[parent as usize].values[index], sibling_value);
+        self.arena[child as usize].keys.push(parent_key);
+        self.arena[child as usize].values.push(parent_value);
+
+        if !self.arena[right_sibling as usize].leaf {
+            let moved_child = self.arena[right_sibling as usize].children.remove(0);
+            self.arena[child as usize].children.push(moved_child);
+        }
+    }
+
+    /// Merges `parent.children[index]`, `parent.keys[index]`, and
+    /// `parent.children[index + 1]` into a single node at `index`,
+    /// shrinking `parent` by one key and one child.
+    fn merge_children(&mut self, parent: u32, index: usize) {
+        let left = self.arena[parent as usize].children[index];
+        let right = self.arena[parent as usize].children.remove(index + 1);
+        let sep_key = self.arena[parent as usize].keys.remove(index);
+        let sep_value = self.arena[parent as usize].values.remove(index);
+
+        self.arena[left as usize].keys.push(sep_key);
+        self.arena[left as usize].values.push(sep_value);
+
+        let mut right_keys = core::mem::take(&mut self.arena[right as usize].keys);
+        let mut right_values = core::mem::take(&mut self.arena[right as usize].values);
+        let mut right_children = core::mem::take(&mut self.arena[right as usize].children);
+        self.arena[left as usize].keys.append(&mut right_keys);
+        self.arena[left as usize].values.append(&mut right_values);
+        self.arena[left as usize].children.append(&mut right_children);
+    }
+
+    /// Panics (in debug builds only) if any node's keys aren't sorted,
+    /// any non-root node falls outside `[min_degree - 1, 2 * min_degree -
+    /// 1]` keys, a node's child count doesn't match its key count, or the
+    /// leaves aren't all at the same depth.
+    #[cfg(debug_assertions)]
+    fn assert_invariants(&self) {
+        if self.root == NULL {
+            debug_assert_eq!(self.len, 0, "B-tree invariant violated: empty root but nonzero len");
+            return;
+        }
+        self.check_node(self.root, true);
+    }
+
+    #[cfg(debug_assertions)]
+    fn check_node(&self, node: u32, is_root: bool) -> usize {
+        let n = &self.arena[node as usize];
+        debug_assert_eq!(n.keys.len(), n.values.len(), "B-tree invariant violated: keys/values length mismatch");
+        for i in 1..n.keys.len() {
+            debug_assert!(n.keys[i - 1] < n.keys[i], "B-tree invariant violated: keys not sorted");
+        }
+        if !is_root {
+            debug_assert!(n.keys.len() >= self.min_degree - 1, "B-tree invariant violated: node underflowed");
+        }
+        debug_assert!(n.keys.len() <= self.max_keys(), "B-tree invariant violated: node overflowed");
+
+        if n.leaf {
+            debug_assert!(n.children.is_empty(), "B-tree invariant violated: leaf has children");
+            1
+        } else {
+            debug_assert_eq!(
+                n.children.len(),
+                n.keys.len() + 1,
+                "B-tree invariant violated: child count doesn't match key count"
+            );
+            let children = n.children.clone();
+            let depths: Vec<usize> = children.iter().map(|&c| self.check_node(c, false)).collect();
+            let first = depths[0];
+            debug_assert!(depths.iter().all(|&d| d == first), "B-tree invariant violated: leaves at uneven depth");
+            first + 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_get_and_overwrite() {
+        let mut tree = BTree::new(2);
+        assert_eq!(tree.insert(5, "five"), None);
+        assert_eq!(tree.insert(3, "three"), None);
+        assert_eq!(tree.insert(5, "FIVE"), Some("five"));
+        assert_eq!(tree.get(&5), Some(&"FIVE"));
+        assert_eq!(tree.get(&3), Some(&"three"));
+        assert_eq!(tree.get(&100), None);
+        assert_eq!(tree.len(), 2);
+    }
+
+    #[test]
+    fn splits_and_merges_across_many_inserts_and_removes() {
+        let mut tree = BTree::new(2);
+        for i in 0..200 {
+            tree.insert(i, i * 10);
+        }
+        assert_eq!(tree.len(), 200);
+        for i in 0..200 {
+            assert_eq!(tree.get(&i), Some(&(i * 10)));
+        }
+
+        for i in 0..150 {
+            assert_eq!(tree.remove(&i), Some(i * 10));
+        }
+        assert_eq!(tree.len(), 50);
+        for i in 0..150 {
+            assert_eq!(tree.get(&i), None);
+        }
+        for i in 150..200 {
+            assert_eq!(tree.get(&i), Some(&(i * 10)));
+        }
+        assert_eq!(tree.remove(&10_000), None);
+    }
+
+    #[test]
+    fn range_returns_half_open_interval_in_order() {
+        let mut tree = BTree::new(3);
+        for i in 0..50 {
+            tree.insert(i, i);
+        }
+        let scanned: Vec<i32> = tree.range(&10, &20).into_iter().map(|(&k, _)| k).collect();
+        assert_eq!(scanned, (10..20).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn empties_back_to_an_empty_tree() {
+        let mut tree = BTree::new(2);
+        for i in 0..30 {
+            tree.insert(i, i);
+        }
+        for i in 0..30 {
+            assert_eq!(tree.remove(&i), Some(i));
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.get(&0), None);
+    }
+}
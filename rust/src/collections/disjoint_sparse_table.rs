@@ -0,0 +1,103 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::segment_tree::Monoid;
+
+/// Static (build-once, no updates) range query structure answering any
+/// [`Monoid`] combine in O(1), for arbitrary associative operations —
+/// including non-idempotent ones like sum or modular product, where a
+/// classic sparse table's overlapping power-of-two ranges would double
+/// count.
+///
+/// Works by recursively splitting the array in half `log2(n)` times (as
+/// a [`SegmentTree`] implicitly does), and for every half of every split,
+/// precomputing the combine from the split point outward to each index —
+/// so any query range `[l, r)` is covered by exactly the one split level
+/// whose boundary falls strictly between `l` and `r - 1`, stitched
+/// together from two precomputed prefix/suffix combines with a single
+/// extra `combine` call and no overlap.
+///
+/// [`SegmentTree`]: crate::collections::segment_tree::SegmentTree
+pub struct DisjointSparseTable<T: Monoid> {
+    values: Vec<T>,
+    // `table[level][i]`, for `i` left of its block's midpoint, holds
+    // `combine(values[i..mid))` accumulated right-to-left; for `i` at or
+    // right of the midpoint, it holds `combine(values[mid..=i])`
+    // accumulated left-to-right.
+    table: Vec<Vec<T>>,
+}
+
+impl<T: Monoid> DisjointSparseTable<T> {
+    pub fn build(values: &[T]) -> Self {
+        let n = values.len();
+        let levels = if n <= 1 { 1 } else { (usize::BITS - (n - 1).leading_zeros()) as usize };
+        let mut table = vec![values.to_vec(); levels];
+
+        // `level` is the bit position at which two indices first differ
+        // (matching `query`'s level choice below), so the blocks it
+        // splits in half are `1 << (level + 1)` wide.
+        #[allow(clippy::needless_range_loop)] // `level` indexes `table`, `block_size`, and splits derived from both
+        for level in 0..levels {
+            let block_size = 1usize << (level + 1);
+            let mut start = 0;
+            while start < n {
+                let mid = (start + block_size / 2).min(n);
+                let end = (start + block_size).min(n);
+                if mid >= end {
+                    start += block_size;
+                    continue;
+                }
+
+                table[level][mid - 1] = values[mid - 1].clone();
+                for i in (start..mid - 1).rev() {
+                    table[level][i] = values[i].combine(&table[level][i + 1]);
+                }
+
+                table[level][mid] = values[mid].clone();
+                for i in mid + 1..end {
+                    table[level][i] = table[level][i - 1].combine(&values[i]);
+                }
+
+                start += block_size;
+            }
+        }
+
+        DisjointSparseTable { values: values.to_vec(), table }
+    }
+
+    /// Combines the half-open range `[l, r)` in O(1).
+    pub fn query(&self, l: usize, r: usize) -> T {
+        let last = r - 1;
+        if l == last {
+            return self.values[l].clone();
+        }
+        let level = (usize::BITS - 1 - (l ^ last).leading_zeros()) as usize;
+        self.table[level][l].combine(&self.table[level][last])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::segment_tree::Sum;
+
+    #[test]
+    fn sums_match_a_naive_scan() {
+        let values: Vec<Sum> = [3i64, 1, 4, 1, 5, 9, 2, 6, -3].into_iter().map(Sum).collect();
+        let table = DisjointSparseTable::build(&values);
+
+        for l in 0..values.len() {
+            for r in l + 1..=values.len() {
+                let expected: i64 = values[l..r].iter().map(|s| s.0).sum();
+                assert_eq!(table.query(l, r).0, expected, "l={l} r={r}");
+            }
+        }
+    }
+
+    #[test]
+    fn single_element_ranges_return_that_element() {
+        let values: Vec<Sum> = [7i64].into_iter().map(Sum).collect();
+        let table = DisjointSparseTable::build(&values);
+        assert_eq!(table.query(0, 1).0, 7);
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Disjoint-set union (union-find) over `0..n`, with path compression and
+/// union by size for amortized O(log n) `find`/`union`.
+pub struct Dsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl Dsu {
+    pub fn new(n: usize) -> Self {
+        Dsu { parent: (0..n).collect(), size: vec![1; n], components: n }
+    }
+
+    /// The representative of `x`'s set, compressing the path to it.
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they
+    /// were already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        if self.size[ra] < self.size[rb] {
+            core::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Alias for [`same_set`](Self::same_set) under the name callers
+    /// asking "are `a` and `b` connected" tend to reach for.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.same_set(a, b)
+    }
+
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// The number of disjoint sets remaining (starts at `n`, drops by one
+    /// per successful [`union`](Self::union)).
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+}
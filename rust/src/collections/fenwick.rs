@@ -0,0 +1,86 @@
+use core::ops::{Add, Sub};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// 1-indexed Fenwick tree (Binary Indexed Tree): point update and prefix
+/// sum both in O(log n), using O(1) extra space per update instead of a
+/// [`SegmentTree`]'s O(log n) combine chain — the usual trade for giving
+/// up range queries over anything but a sum-like, invertible operation.
+///
+/// [`SegmentTree`]: crate::collections::segment_tree::SegmentTree
+pub struct Fenwick<T> {
+    // `tree[0]` is unused padding; real indices are 1..=n.
+    tree: Vec<T>,
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T> + PartialOrd> Fenwick<T> {
+    pub fn new(n: usize) -> Self {
+        Fenwick { tree: vec![T::default(); n + 1] }
+    }
+
+    pub fn build(values: &[T]) -> Self {
+        let mut fenwick = Fenwick::new(values.len());
+        for (i, &v) in values.iter().enumerate() {
+            fenwick.add(i, v);
+        }
+        fenwick
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to the 0-indexed position `i`.
+    pub fn add(&mut self, i: usize, delta: T) {
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the half-open prefix `[0, i)`.
+    pub fn prefix_sum(&self, i: usize) -> T {
+        let mut i = i;
+        let mut sum = T::default();
+        while i > 0 {
+            sum = sum + self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Sum of the half-open range `[l, r)`.
+    pub fn range_sum(&self, l: usize, r: usize) -> T {
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+
+    /// The largest prefix length `p` with `prefix_sum(p) <= target`,
+    /// i.e. the first position whose prefix sum *exceeds* `target` is
+    /// `p + 1`. Only meaningful when every element is non-negative, the
+    /// same assumption the classic Fenwick "find" trick relies on to
+    /// binary-lift over powers of two instead of binary-searching with
+    /// repeated `prefix_sum` calls.
+    pub fn lower_bound(&self, target: T) -> usize {
+        let n = self.len();
+        let mut pos = 0;
+        let mut remaining = target;
+        let mut pow = 1;
+        while pow * 2 <= n {
+            pow *= 2;
+        }
+        while pow > 0 {
+            if pos + pow <= n && self.tree[pos + pow] <= remaining {
+                pos += pow;
+                remaining = remaining - self.tree[pos];
+            }
+            pow /= 2;
+        }
+        pos
+    }
+}
@@ -0,0 +1,57 @@
+use core::ops::{Add, Sub};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// 2D Fenwick tree: point update and rectangle-sum query both in
+/// O(log rows * log cols), the grid-counting counterpart to [`Fenwick`] —
+/// each 1D update/prefix-sum walk over rows is nested with one over
+/// columns instead of touching a single cell.
+///
+/// [`Fenwick`]: crate::collections::fenwick::Fenwick
+pub struct Fenwick2D<T> {
+    rows: usize,
+    cols: usize,
+    // 1-indexed on both axes; row/col 0 are unused padding.
+    tree: Vec<Vec<T>>,
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> Fenwick2D<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Fenwick2D { rows, cols, tree: vec![vec![T::default(); cols + 1]; rows + 1] }
+    }
+
+    /// Adds `delta` to the 0-indexed cell `(r, c)`.
+    pub fn add(&mut self, r: usize, c: usize, delta: T) {
+        let mut i = r + 1;
+        while i <= self.rows {
+            let mut j = c + 1;
+            while j <= self.cols {
+                self.tree[i][j] = self.tree[i][j] + delta;
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the rectangle `[0, r) x [0, c)`.
+    pub fn prefix_sum(&self, r: usize, c: usize) -> T {
+        let mut sum = T::default();
+        let mut i = r;
+        while i > 0 {
+            let mut j = c;
+            while j > 0 {
+                sum = sum + self.tree[i][j];
+                j -= j & j.wrapping_neg();
+            }
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Sum of the rectangle `[r1, r2) x [c1, c2)`, via inclusion-exclusion
+    /// over four prefix sums.
+    pub fn rect_sum(&self, r1: usize, r2: usize, c1: usize, c2: usize) -> T {
+        self.prefix_sum(r2, c2) - self.prefix_sum(r1, c2) - self.prefix_sum(r2, c1) + self.prefix_sum(r1, c1)
+    }
+}
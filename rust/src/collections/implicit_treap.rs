@@ -0,0 +1,268 @@
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+
+/// One arena slot, addressed purely by subtree `size` rather than by a
+/// key — this is the "implicit" half of the treap, as opposed to
+/// [`Treap`]'s key-ordered one. `sum` is the cached combined value of
+/// the whole subtree, and `reversed` is a lazily-pushed flag: flipping
+/// it swaps `left`/`right` and re-queues the flip on both children
+/// instead of eagerly rebuilding the subtree, so a [`reverse_range`]
+/// over the whole array stays O(log n).
+///
+/// [`Treap`]: crate::collections::treap::Treap
+/// [`reverse_range`]: ImplicitTreap::reverse_range
+struct Node {
+    value: i64,
+    sum: i64,
+    priority: u64,
+    left: u32,
+    right: u32,
+    size: u32,
+    reversed: bool,
+}
+
+/// A treap addressed by position instead of key (a "rope"-like
+/// balanced array): [`split`](Self::split_at)/merge partition by
+/// subtree size rather than by comparing keys, which is what lets
+/// [`insert`](Self::insert)/[`erase`](Self::erase) work at an arbitrary
+/// index and [`reverse_range`] flip an arbitrary slice, both in
+/// expected O(log n). See [`Treap`] for the key-ordered sibling this
+/// shares its arena/split/merge shape with.
+///
+/// [`Treap`]: crate::collections::treap::Treap
+pub struct ImplicitTreap {
+    arena: Vec<Node>,
+    root: u32,
+    rng: u64,
+}
+
+impl ImplicitTreap {
+    pub fn new() -> Self {
+        Self::with_seed(0x9E37_79B9_7F4A_7C15)
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        ImplicitTreap { arena: Vec::new(), root: NULL, rng: seed | 1 }
+    }
+
+    /// Builds a treap holding `values` in order, via repeated `insert`.
+    pub fn build(values: &[i64]) -> Self {
+        let mut treap = Self::new();
+        for (i, &v) in values.iter().enumerate() {
+            treap.insert(i, v);
+        }
+        treap
+    }
+
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts `value` so it becomes element `index`, shifting everything
+    /// from `index` onward one slot later.
+    pub fn insert(&mut self, index: usize, value: i64) {
+        let (left, right) = self.split_at(self.root, index);
+        let priority = self.next_priority();
+        let node = self.push_node(value, priority);
+        let merged = self.merge(left, node);
+        self.root = self.merge(merged, right);
+    }
+
+    /// Removes and returns the element at `index`.
+    pub fn erase(&mut self, index: usize) -> i64 {
+        let (left, rest) = self.split_at(self.root, index);
+        let (mid, right) = self.split_at(rest, 1);
+        let value = self.arena[mid as usize].value;
+        self.root = self.merge(left, right);
+        value
+    }
+
+    /// The element at `index`.
+    pub fn get(&mut self, index: usize) -> i64 {
+        self.get_from(self.root, index)
+    }
+
+    /// Reverses the half-open range `[l, r)`.
+    pub fn reverse_range(&mut self, l: usize, r: usize) {
+        let (left, rest) = self.split_at(self.root, l);
+        let (mid, right) = self.split_at(rest, r - l);
+        if mid != NULL {
+            self.toggle_reversed(mid);
+        }
+        let merged = self.merge(left, mid);
+        self.root = self.merge(merged, right);
+    }
+
+    /// The sum over the half-open range `[l, r)`.
+    pub fn range_sum(&mut self, l: usize, r: usize) -> i64 {
+        let (left, rest) = self.split_at(self.root, l);
+        let (mid, right) = self.split_at(rest, r - l);
+        let sum = self.sum(mid);
+        let merged = self.merge(left, mid);
+        self.root = self.merge(merged, right);
+        sum
+    }
+
+    /// Collects the elements in order, for inspection/testing.
+    pub fn to_vec(&mut self) -> Vec<i64> {
+        let mut out = Vec::with_capacity(self.len());
+        self.collect(self.root, &mut out);
+        out
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        // xorshift64* — same generator `Treap` uses, for the same reason:
+        // fast, no external dependency, and good enough to balance a
+        // randomized BST.
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn push_node(&mut self, value: i64, priority: u64) -> u32 {
+        self.arena.push(Node { value, sum: value, priority, left: NULL, right: NULL, size: 1, reversed: false });
+        (self.arena.len() - 1) as u32
+    }
+
+    fn size(&self, node: u32) -> usize {
+        if node == NULL {
+            0
+        } else {
+            self.arena[node as usize].size as usize
+        }
+    }
+
+    fn sum(&self, node: u32) -> i64 {
+        if node == NULL {
+            0
+        } else {
+            self.arena[node as usize].sum
+        }
+    }
+
+    fn update(&mut self, node: u32) {
+        if node == NULL {
+            return;
+        }
+        let (left, right, value) = {
+            let n = &self.arena[node as usize];
+            (n.left, n.right, n.value)
+        };
+        self.arena[node as usize].size = 1 + self.size(left) as u32 + self.size(right) as u32;
+        self.arena[node as usize].sum = self.sum(left) + value + self.sum(right);
+    }
+
+    fn toggle_reversed(&mut self, node: u32) {
+        self.arena[node as usize].reversed ^= true;
+    }
+
+    /// Pushes a pending reversal down one level, so descending into
+    /// `node`'s children sees the up-to-date left/right order.
+    fn push_down(&mut self, node: u32) {
+        if node == NULL || !self.arena[node as usize].reversed {
+            return;
+        }
+        self.arena[node as usize].reversed = false;
+        let (left, right) = {
+            let n = &self.arena[node as usize];
+            (n.left, n.right)
+        };
+        self.arena[node as usize].left = right;
+        self.arena[node as usize].right = left;
+        if left != NULL {
+            self.toggle_reversed(left);
+        }
+        if right != NULL {
+            self.toggle_reversed(right);
+        }
+    }
+
+    fn get_from(&mut self, node: u32, index: usize) -> i64 {
+        self.push_down(node);
+        let left = self.arena[node as usize].left;
+        let left_size = self.size(left);
+        if index < left_size {
+            self.get_from(left, index)
+        } else if index == left_size {
+            self.arena[node as usize].value
+        } else {
+            let right = self.arena[node as usize].right;
+            self.get_from(right, index - left_size - 1)
+        }
+    }
+
+    fn collect(&mut self, node: u32, out: &mut Vec<i64>) {
+        if node == NULL {
+            return;
+        }
+        self.push_down(node);
+        let (left, right, value) = {
+            let n = &self.arena[node as usize];
+            (n.left, n.right, n.value)
+        };
+        self.collect(left, out);
+        out.push(value);
+        self.collect(right, out);
+    }
+
+    /// Splits `node`'s subtree into its first `count` elements and
+    /// everything after, preserving order.
+    fn split_at(&mut self, node: u32, count: usize) -> (u32, u32) {
+        if node == NULL {
+            return (NULL, NULL);
+        }
+        self.push_down(node);
+        let left = self.arena[node as usize].left;
+        let left_size = self.size(left);
+        if count <= left_size {
+            let (left_left, left_right) = self.split_at(left, count);
+            self.arena[node as usize].left = left_right;
+            self.update(node);
+            (left_left, node)
+        } else {
+            let right = self.arena[node as usize].right;
+            let (right_left, right_right) = self.split_at(right, count - left_size - 1);
+            self.arena[node as usize].right = right_left;
+            self.update(node);
+            (node, right_right)
+        }
+    }
+
+    fn merge(&mut self, a: u32, b: u32) -> u32 {
+        if a == NULL {
+            return b;
+        }
+        if b == NULL {
+            return a;
+        }
+        self.push_down(a);
+        self.push_down(b);
+        if self.arena[a as usize].priority > self.arena[b as usize].priority {
+            let right = self.arena[a as usize].right;
+            let merged = self.merge(right, b);
+            self.arena[a as usize].right = merged;
+            self.update(a);
+            a
+        } else {
+            let left = self.arena[b as usize].left;
+            let merged = self.merge(a, left);
+            self.arena[b as usize].left = merged;
+            self.update(b);
+            b
+        }
+    }
+}
+
+impl Default for ImplicitTreap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
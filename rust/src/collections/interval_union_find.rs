@@ -0,0 +1,79 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Interval union-find ("paint the segments"): a DSU where [`find`] always
+/// jumps to the next *unpainted* slot at or after `i`. Painting a range
+/// `[l, r]` unions every slot in it to `r + 1`, so re-painting an
+/// already-covered range is cheap and the whole structure amortizes to
+/// O(n + q) with path compression — a classic trick for "first free slot"
+/// and "paint interval, query if painted" problems.
+pub struct IntervalUnionFind {
+    parent: Vec<usize>, // parent[i] == i means slot i is unpainted
+    painted_with: Vec<Option<u32>>,
+}
+
+impl IntervalUnionFind {
+    pub fn new(n: usize) -> Self {
+        IntervalUnionFind {
+            parent: (0..=n).collect(), // sentinel slot n acts as "off the end"
+            painted_with: vec![None; n + 1],
+        }
+    }
+
+    pub fn find(&mut self, i: usize) -> usize {
+        if self.parent[i] != i {
+            self.parent[i] = self.find(self.parent[i]);
+        }
+        self.parent[i]
+    }
+
+    /// Paints every currently-unpainted slot in `[l, r]` with `color`,
+    /// returning how many slots were newly painted.
+    pub fn paint(&mut self, l: usize, r: usize, color: u32) -> usize {
+        let mut painted = 0;
+        let mut i = self.find(l);
+        while i <= r {
+            self.painted_with[i] = Some(color);
+            painted += 1;
+            self.parent[i] = i + 1;
+            i = self.find(i + 1);
+        }
+        painted
+    }
+
+    pub fn color_of(&mut self, i: usize) -> Option<u32> {
+        self.painted_with[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repainting_an_already_covered_range_paints_nothing_new() {
+        let mut canvas = IntervalUnionFind::new(10);
+        assert_eq!(canvas.paint(2, 5, 1), 4);
+        assert_eq!(canvas.paint(2, 5, 2), 0);
+        for i in 2..=5 {
+            assert_eq!(canvas.color_of(i), Some(1));
+        }
+    }
+
+    #[test]
+    fn overlapping_paints_only_cover_the_unpainted_remainder() {
+        let mut canvas = IntervalUnionFind::new(10);
+        assert_eq!(canvas.paint(2, 5, 1), 4);
+        assert_eq!(canvas.paint(4, 8, 2), 3); // 4, 5 already painted; 6, 7, 8 are new
+        assert_eq!(canvas.color_of(4), Some(1));
+        assert_eq!(canvas.color_of(6), Some(2));
+    }
+
+    #[test]
+    fn unpainted_slots_report_no_color() {
+        let mut canvas = IntervalUnionFind::new(5);
+        assert_eq!(canvas.color_of(3), None);
+        canvas.paint(3, 3, 7);
+        assert_eq!(canvas.color_of(3), Some(7));
+    }
+}
@@ -0,0 +1,90 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Static (build-once) merge sort tree: each node holds the sorted values
+/// of its range, built the same way a merge sort would merge two sorted
+/// halves, so answering "how many elements `<= x`" in a range is a
+/// binary search per node touched instead of a linear scan.
+///
+/// Uses the same iterative `2n`-array layout as [`SegmentTree`], so node
+/// `i`'s children are `2i`/`2i + 1` and leaves start at index `n` — just
+/// storing a sorted `Vec<i64>` per node instead of a single combined
+/// value, since "sorted merge of both halves" isn't expressible as a
+/// [`Monoid`] combine.
+///
+/// [`SegmentTree`]: crate::collections::segment_tree::SegmentTree
+/// [`Monoid`]: crate::collections::segment_tree::Monoid
+pub struct MergeSortTree {
+    n: usize,
+    tree: Vec<Vec<i64>>,
+}
+
+impl MergeSortTree {
+    pub fn build(values: &[i64]) -> Self {
+        let n = values.len();
+        let mut tree: Vec<Vec<i64>> = vec![Vec::new(); 2 * n];
+        for (i, &v) in values.iter().enumerate() {
+            tree[n + i] = vec![v];
+        }
+        for i in (1..n).rev() {
+            tree[i] = merge(&tree[2 * i], &tree[2 * i + 1]);
+        }
+        MergeSortTree { n, tree }
+    }
+
+    /// Count of elements `<= x` within the half-open range `[l, r)`, in
+    /// O(log^2 n): O(log n) nodes decomposed, O(log n) binary search each.
+    pub fn count_le(&self, l: usize, r: usize, x: i64) -> usize {
+        let (mut l, mut r) = (l + self.n, r + self.n);
+        let mut count = 0;
+        while l < r {
+            if l % 2 == 1 {
+                count += self.tree[l].partition_point(|&v| v <= x);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                count += self.tree[r].partition_point(|&v| v <= x);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        count
+    }
+
+    /// The `k`-th smallest element (0-indexed) within `[l, r)`: binary
+    /// searches over the values actually present (the root's sorted merge
+    /// of everything) for the smallest `x` with more than `k` elements
+    /// `<= x`, via [`count_le`](Self::count_le).
+    pub fn kth_smallest(&self, l: usize, r: usize, k: usize) -> i64 {
+        let candidates = &self.tree[1];
+        let mut lo = 0;
+        let mut hi = candidates.len() - 1;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.count_le(l, r, candidates[mid]) > k {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        candidates[lo]
+    }
+}
+
+fn merge(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] <= b[j] {
+            merged.push(a[i]);
+            i += 1;
+        } else {
+            merged.push(b[j]);
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&a[i..]);
+    merged.extend_from_slice(&b[j..]);
+    merged
+}
@@ -0,0 +1,31 @@
+//! Core data structures with no dependency beyond `core`/`alloc`, so they
+//! stay available in the `#![no_std]` build (see the crate root docs).
+
+pub mod autocomplete;
+pub mod avl_tree;
+pub mod bk_tree;
+pub mod btree;
+pub mod disjoint_sparse_table;
+pub mod dsu;
+pub mod fenwick;
+pub mod fenwick_2d;
+pub mod implicit_treap;
+pub mod interval_union_find;
+pub mod merge_sort_tree;
+pub mod ordered_multimap;
+pub mod persistent_segment_tree;
+pub mod persistent_stack_queue;
+pub mod persistent_treap;
+pub mod radix_trie;
+pub mod range_fenwick;
+pub mod red_black_tree;
+pub mod rollback_dsu;
+pub mod segment_tree;
+pub mod segment_tree_2d;
+pub mod skip_list;
+pub mod sqrt_decomposition;
+pub mod treap;
+pub mod trie;
+pub mod wavelet_tree;
+pub mod weighted_dsu;
+pub mod xor_trie;
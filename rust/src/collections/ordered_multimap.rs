@@ -0,0 +1,202 @@
+use alloc::vec::Vec;
+
+use super::fenwick::Fenwick;
+use super::treap::Treap;
+
+/// Which backend an [`OrderedMultiMap`] stores its keys in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// A randomized balanced BST ([`Treap`]): accepts any key at
+    /// `insert` time, no upfront universe needed.
+    Treap,
+    /// A [`Fenwick`] tree over a fixed, coordinate-compressed universe of
+    /// keys: cheaper per operation, but every key ever inserted must be
+    /// in the universe given at construction.
+    CompressedFenwick,
+}
+
+enum Storage<T: Ord> {
+    Treap(Treap<T>),
+    CompressedFenwick { universe: Vec<T>, counts: Fenwick<i64> },
+}
+
+/// An ordered multiset (duplicate keys allowed) exposing order-statistics
+/// — `rank`, `select`, range counting — over whichever [`Backend`] was
+/// chosen at construction, so callers can swap backends to benchmark
+/// them without touching any call site.
+pub struct OrderedMultiMap<T: Ord> {
+    storage: Storage<T>,
+}
+
+impl<T: Ord + Clone> OrderedMultiMap<T> {
+    /// An empty map backed by a [`Treap`], accepting any key.
+    pub fn new() -> Self {
+        OrderedMultiMap { storage: Storage::Treap(Treap::new()) }
+    }
+
+    /// An empty map backed by a [`Fenwick`] tree coordinate-compressed
+    /// over `universe`; every key ever inserted must be one of these.
+    /// `universe` need not be sorted or deduplicated.
+    pub fn with_compressed_universe(universe: &[T]) -> Self {
+        let mut universe = universe.to_vec();
+        universe.sort();
+        universe.dedup();
+        let counts = Fenwick::new(universe.len());
+        OrderedMultiMap { storage: Storage::CompressedFenwick { universe, counts } }
+    }
+
+    pub fn backend(&self) -> Backend {
+        match &self.storage {
+            Storage::Treap(_) => Backend::Treap,
+            Storage::CompressedFenwick { .. } => Backend::CompressedFenwick,
+        }
+    }
+
+    /// Inserts one occurrence of `key`. Panics on the
+    /// [`Backend::CompressedFenwick`] backend if `key` isn't in the
+    /// universe given at construction.
+    pub fn insert(&mut self, key: T) {
+        match &mut self.storage {
+            Storage::Treap(treap) => treap.insert(key),
+            Storage::CompressedFenwick { universe, counts } => {
+                let index = universe.binary_search(&key).expect("key outside the compressed universe");
+                counts.add(index, 1);
+            }
+        }
+    }
+
+    /// Removes one occurrence of `key`. Returns `false` if it wasn't
+    /// present.
+    pub fn erase(&mut self, key: &T) -> bool {
+        match &mut self.storage {
+            Storage::Treap(treap) => treap.erase(key),
+            Storage::CompressedFenwick { universe, counts } => {
+                let Ok(index) = universe.binary_search(key) else { return false };
+                if counts.range_sum(index, index + 1) == 0 {
+                    return false;
+                }
+                counts.add(index, -1);
+                true
+            }
+        }
+    }
+
+    /// Count of keys strictly less than `key`.
+    pub fn rank(&self, key: &T) -> usize {
+        match &self.storage {
+            Storage::Treap(treap) => treap.rank(key),
+            Storage::CompressedFenwick { universe, counts } => {
+                let index = universe.partition_point(|k| k < key);
+                counts.prefix_sum(index) as usize
+            }
+        }
+    }
+
+    /// The `k`-th smallest key (0-indexed), or `None` if fewer than
+    /// `k + 1` keys are present.
+    pub fn select(&self, k: usize) -> Option<T> {
+        match &self.storage {
+            Storage::Treap(treap) => treap.select(k).cloned(),
+            Storage::CompressedFenwick { universe, counts } => {
+                if k >= counts.prefix_sum(universe.len()) as usize {
+                    return None;
+                }
+                Some(universe[counts.lower_bound(k as i64)].clone())
+            }
+        }
+    }
+
+    /// Count of keys in the half-open range `[lo, hi)`.
+    pub fn range_count(&self, lo: &T, hi: &T) -> usize {
+        self.rank(hi) - self.rank(lo)
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Treap(treap) => treap.len(),
+            Storage::CompressedFenwick { universe, counts } => counts.prefix_sum(universe.len()) as usize,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Ord + Clone> Default for OrderedMultiMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exercise(mut map: OrderedMultiMap<i64>) {
+        for &v in &[5, 3, 8, 3, 1, 9, 3] {
+            map.insert(v);
+        }
+        assert_eq!(map.len(), 7);
+        assert_eq!(map.rank(&3), 1); // only `1` is strictly less than 3
+        assert_eq!(map.select(0), Some(1));
+        assert_eq!(map.select(1), Some(3));
+        assert_eq!(map.select(6), Some(9));
+        assert_eq!(map.select(7), None);
+        assert_eq!(map.range_count(&3, &9), 5); // 3, 3, 3, 5, 8
+
+        assert!(map.erase(&3));
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.range_count(&3, &9), 4); // 3, 3, 5, 8
+        assert!(!map.erase(&42));
+    }
+
+    #[test]
+    fn treap_backend_matches_expected_order_statistics() {
+        exercise(OrderedMultiMap::new());
+    }
+
+    #[test]
+    fn compressed_fenwick_backend_matches_expected_order_statistics() {
+        exercise(OrderedMultiMap::with_compressed_universe(&[1, 3, 5, 8, 9]));
+    }
+
+    #[test]
+    fn both_backends_agree_on_a_longer_random_sequence() {
+        let universe: Vec<i64> = (0..30).collect();
+        let mut treap = OrderedMultiMap::new();
+        let mut fenwick = OrderedMultiMap::with_compressed_universe(&universe);
+
+        let mut state = 0x1234_5678_9abc_def1_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 30) as i64
+        };
+
+        for _ in 0..200 {
+            let key = next();
+            if key % 2 == 0 {
+                treap.insert(key);
+                fenwick.insert(key);
+            } else {
+                treap.erase(&key);
+                fenwick.erase(&key);
+            }
+            assert_eq!(treap.len(), fenwick.len());
+            assert_eq!(treap.rank(&key), fenwick.rank(&key));
+            if !treap.is_empty() {
+                assert_eq!(treap.select(0), fenwick.select(0));
+            }
+        }
+    }
+
+    #[test]
+    fn erase_on_treap_reports_whether_key_was_present() {
+        let mut map: OrderedMultiMap<i64> = OrderedMultiMap::new();
+        map.insert(10);
+        assert!(map.erase(&10));
+        assert!(!map.erase(&10));
+    }
+}
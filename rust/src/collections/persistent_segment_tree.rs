@@ -0,0 +1,144 @@
+use alloc::vec::Vec;
+
+use crate::collections::segment_tree::{Monoid, Sum};
+
+const NULL: u32 = u32::MAX;
+
+#[derive(Clone)]
+struct Node<T> {
+    left: u32,
+    right: u32,
+    value: T,
+}
+
+/// Persistent (versioned) segment tree over any [`Monoid`]: every
+/// [`update`](Self::update) returns a *new* root instead of mutating the
+/// tree in place, while every node an update doesn't touch is shared with
+/// the version it was built from. Nodes live in one arena `Vec` addressed
+/// by index, so sharing a node across versions is just copying a
+/// `usize`/`u32` — no `Rc` refcounting, and the whole tree is one
+/// contiguous allocation.
+pub struct PersistentSegmentTree<T: Monoid> {
+    size: usize,
+    arena: Vec<Node<T>>,
+}
+
+impl<T: Monoid> PersistentSegmentTree<T> {
+    /// Builds an empty tree over the index domain `[0, size)` and returns
+    /// it along with the root of version 0 (every position holding
+    /// [`Monoid::identity`]).
+    pub fn new(size: usize) -> (Self, usize) {
+        assert!(size > 0, "persistent segment tree needs a non-empty domain");
+        let mut tree = PersistentSegmentTree { size, arena: Vec::new() };
+        let root = tree.build_empty(0, size);
+        (tree, root)
+    }
+
+    fn alloc(&mut self, left: u32, right: u32, value: T) -> usize {
+        self.arena.push(Node { left, right, value });
+        self.arena.len() - 1
+    }
+
+    fn build_empty(&mut self, lo: usize, hi: usize) -> usize {
+        if hi - lo == 1 {
+            return self.alloc(NULL, NULL, T::identity());
+        }
+        let mid = lo + (hi - lo) / 2;
+        let left = self.build_empty(lo, mid) as u32;
+        let right = self.build_empty(mid, hi) as u32;
+        let value = self.arena[left as usize].value.combine(&self.arena[right as usize].value);
+        self.alloc(left, right, value)
+    }
+
+    /// Combines `index`'s current value (as of `root`) with `delta`, and
+    /// returns the root of the resulting new version.
+    pub fn update(&mut self, root: usize, index: usize, delta: &T) -> usize {
+        self.update_range(root, 0, self.size, index, delta)
+    }
+
+    fn update_range(&mut self, node: usize, lo: usize, hi: usize, index: usize, delta: &T) -> usize {
+        if hi - lo == 1 {
+            let value = self.arena[node].value.combine(delta);
+            return self.alloc(NULL, NULL, value);
+        }
+        let mid = lo + (hi - lo) / 2;
+        let (left, right) = (self.arena[node].left, self.arena[node].right);
+        let (new_left, new_right) = if index < mid {
+            (self.update_range(left as usize, lo, mid, index, delta) as u32, right)
+        } else {
+            (left, self.update_range(right as usize, mid, hi, index, delta) as u32)
+        };
+        let value = self.arena[new_left as usize].value.combine(&self.arena[new_right as usize].value);
+        self.alloc(new_left, new_right, value)
+    }
+
+    /// Combines the half-open range `[l, r)` as of `root`.
+    pub fn query(&self, root: usize, l: usize, r: usize) -> T {
+        self.query_range(root, 0, self.size, l, r)
+    }
+
+    fn query_range(&self, node: usize, lo: usize, hi: usize, l: usize, r: usize) -> T {
+        if r <= lo || hi <= l {
+            return T::identity();
+        }
+        if l <= lo && hi <= r {
+            return self.arena[node].value.clone();
+        }
+        let mid = lo + (hi - lo) / 2;
+        let left = self.query_range(self.arena[node].left as usize, lo, mid, l, r);
+        let right = self.query_range(self.arena[node].right as usize, mid, hi, l, r);
+        left.combine(&right)
+    }
+}
+
+impl PersistentSegmentTree<Sum> {
+    /// The `k`-th smallest (0-indexed) position inserted strictly between
+    /// versions `before` and `after` (i.e. the elements whose update
+    /// calls happened after `before`'s version but by `after`'s),
+    /// found by walking both versions in lockstep: at each node, the
+    /// number of matching elements in the left child is the *difference*
+    /// between the two versions' left-child counts, since every node
+    /// `after` doesn't share with `before` is exactly the nodes rebuilt
+    /// along the path of updates in between.
+    pub fn kth_smallest(&self, before: usize, after: usize, mut k: usize) -> usize {
+        let (mut lo, mut hi) = (0, self.size);
+        let (mut before, mut after) = (before, after);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            let left_before = self.arena[before].left as usize;
+            let left_after = self.arena[after].left as usize;
+            let left_count = (self.arena[left_after].value.0 - self.arena[left_before].value.0) as usize;
+            if k < left_count {
+                before = left_before;
+                after = left_after;
+                hi = mid;
+            } else {
+                k -= left_count;
+                before = self.arena[before].right as usize;
+                after = self.arena[after].right as usize;
+                lo = mid;
+            }
+        }
+        lo
+    }
+}
+
+/// Coordinate-compresses `values` and builds one persistent-tree version
+/// per prefix: `roots[i]` counts `values[0..i]` by compressed rank. The
+/// `k`-th smallest original value in `values[l..r]` is then
+/// `sorted_values[tree.kth_smallest(roots[l], roots[r + 1], k)]`.
+pub fn build_prefix_roots(values: &[i64]) -> (PersistentSegmentTree<Sum>, Vec<usize>, Vec<i64>) {
+    let mut sorted_values: Vec<i64> = values.to_vec();
+    sorted_values.sort_unstable();
+    sorted_values.dedup();
+
+    let (mut tree, empty_root) = PersistentSegmentTree::new(sorted_values.len());
+    let mut roots = Vec::with_capacity(values.len() + 1);
+    roots.push(empty_root);
+    for &v in values {
+        let rank = sorted_values.partition_point(|&x| x < v);
+        let root = tree.update(*roots.last().expect("roots always has at least the empty version"), rank, &Sum(1));
+        roots.push(root);
+    }
+    (tree, roots, sorted_values)
+}
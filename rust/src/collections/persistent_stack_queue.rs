@@ -0,0 +1,179 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+/// An immutable singly-linked stack: pushing returns a new handle that
+/// shares structure with the original, so old versions stay valid.
+#[derive(Clone)]
+pub struct PersistentStack<T> {
+    head: Option<Rc<StackNode<T>>>,
+}
+
+struct StackNode<T> {
+    value: T,
+    next: Option<Rc<StackNode<T>>>,
+}
+
+impl<T: Clone> PersistentStack<T> {
+    pub fn new() -> Self {
+        PersistentStack { head: None }
+    }
+
+    pub fn push(&self, value: T) -> Self {
+        PersistentStack {
+            head: Some(Rc::new(StackNode { value, next: self.head.clone() })),
+        }
+    }
+
+    pub fn pop(&self) -> Option<(T, Self)> {
+        self.head.as_ref().map(|node| (node.value.clone(), PersistentStack { head: node.next.clone() }))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+}
+
+impl<T: Clone> Default for PersistentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Amortized O(1) queue built from two `Vec`-backed stacks: pushes go to
+/// `front`, pops drain from `back` (refilled by reversing `front` only
+/// when `back` runs dry). A single dequeue can cost O(n) on that refill.
+pub struct AmortizedQueue<T> {
+    front: Vec<T>, // newest on top
+    back: Vec<T>,  // oldest on top
+}
+
+impl<T> AmortizedQueue<T> {
+    pub fn new() -> Self {
+        AmortizedQueue { front: Vec::new(), back: Vec::new() }
+    }
+
+    pub fn enqueue(&mut self, value: T) {
+        self.front.push(value);
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.back.is_empty() {
+            while let Some(v) = self.front.pop() {
+                self.back.push(v);
+            }
+        }
+        self.back.pop()
+    }
+}
+
+impl<T> Default for AmortizedQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A persistent queue built from two persistent stacks: enqueues push onto
+/// `back`, dequeues pop from `front` (refilled by reversing all of `back`
+/// into `front` once it runs dry). Amortized O(1) per operation, same as
+/// [`AmortizedQueue`] — but since both stacks are persistent, every
+/// `BankersQueue` snapshot produced along the way stays valid, where
+/// `AmortizedQueue`'s in-place `Vec`s would have been mutated out from
+/// under it.
+pub struct BankersQueue<T: Clone> {
+    front: PersistentStack<T>,
+    back: PersistentStack<T>, // reversed tail, newest pushed on top
+}
+
+impl<T: Clone> BankersQueue<T> {
+    pub fn new() -> Self {
+        BankersQueue { front: PersistentStack::new(), back: PersistentStack::new() }
+    }
+
+    /// Once `front` runs dry, reverses all of `back` into it in one pass.
+    fn refill(&mut self) {
+        if self.front.is_empty() {
+            let mut reversed = PersistentStack::new();
+            let mut rest = self.back.clone();
+            while let Some((v, r)) = rest.pop() {
+                reversed = reversed.push(v);
+                rest = r;
+            }
+            self.front = reversed;
+            self.back = PersistentStack::new();
+        }
+    }
+
+    pub fn enqueue(&mut self, value: T) {
+        self.back = self.back.push(value);
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.refill();
+        let (v, rest) = self.front.pop()?;
+        self.front = rest;
+        Some(v)
+    }
+}
+
+impl<T: Clone> Default for BankersQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushing_to_a_persistent_stack_leaves_earlier_handles_untouched() {
+        let s0: PersistentStack<i32> = PersistentStack::new();
+        let s1 = s0.push(1);
+        let s2 = s1.push(2);
+        let s3 = s2.push(3);
+        assert_eq!(s3.pop().map(|(v, _)| v), Some(3));
+        assert_eq!(s1.pop().map(|(v, _)| v), Some(1));
+        assert!(s0.is_empty());
+    }
+
+    #[test]
+    fn amortized_queue_dequeues_in_fifo_order() {
+        let mut q = AmortizedQueue::new();
+        for i in 1..=5 {
+            q.enqueue(i);
+        }
+        let mut drained = Vec::new();
+        while let Some(v) = q.dequeue() {
+            drained.push(v);
+        }
+        assert_eq!(drained, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn bankers_queue_dequeues_in_fifo_order() {
+        let mut q = BankersQueue::new();
+        for i in 1..=5 {
+            q.enqueue(i);
+        }
+        let mut drained = Vec::new();
+        while let Some(v) = q.dequeue() {
+            drained.push(v);
+        }
+        assert_eq!(drained, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn bankers_queue_matches_amortized_queue_under_interleaved_operations() {
+        let mut bankers = BankersQueue::new();
+        let mut amortized = AmortizedQueue::new();
+        let ops = [1, 2, -1, 3, 4, -1, -1, 5, 6, -1, -1, -1, -1];
+        for &op in &ops {
+            if op > 0 {
+                bankers.enqueue(op);
+                amortized.enqueue(op);
+            } else {
+                assert_eq!(bankers.dequeue(), amortized.dequeue());
+            }
+        }
+    }
+}
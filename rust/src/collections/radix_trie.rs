@@ -0,0 +1,215 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::collections::trie::Trie;
+
+/// One arena slot: `label` is the compressed edge coming in from the
+/// parent (possibly several bytes long, unlike [`Trie`]'s one-byte-per-
+/// node edges), and `children` is keyed by the first byte of each
+/// child's label, which is always distinct among siblings.
+struct Node {
+    label: Vec<u8>,
+    children: BTreeMap<u8, u32>,
+    terminal: bool,
+}
+
+impl Node {
+    fn new(label: Vec<u8>, terminal: bool) -> Self {
+        Node { label, children: BTreeMap::new(), terminal }
+    }
+}
+
+/// A path-compressed trie (a.k.a. radix tree / Patricia trie) over byte
+/// strings: runs of nodes with a single child are collapsed into one
+/// edge labelled with the whole run, so the tree has exactly one node
+/// per branch point or word end rather than one per byte. That's the
+/// shape IP routing tables use for longest-prefix-match lookups, which
+/// is why [`longest_prefix_match`](Self::longest_prefix_match) is the
+/// headline query here rather than [`Trie`]'s `count_prefix`.
+pub struct RadixTrie {
+    arena: Vec<Node>,
+}
+
+impl RadixTrie {
+    pub fn new() -> Self {
+        RadixTrie { arena: alloc::vec![Node::new(Vec::new(), false)] }
+    }
+
+    pub fn insert(&mut self, word: &[u8]) {
+        self.insert_from(0, word);
+    }
+
+    pub fn contains(&self, word: &[u8]) -> bool {
+        let mut node = 0u32;
+        let mut consumed = 0usize;
+        loop {
+            if consumed == word.len() {
+                return self.arena[node as usize].terminal;
+            }
+            let Some(&child) = self.arena[node as usize].children.get(&word[consumed]) else {
+                return false;
+            };
+            let label = &self.arena[child as usize].label;
+            if !word[consumed..].starts_with(label.as_slice()) {
+                return false;
+            }
+            consumed += label.len();
+            node = child;
+        }
+    }
+
+    /// The longest inserted word that is a prefix of `word`, or `None`
+    /// if no inserted word is a prefix of it — the "which routing-table
+    /// entry matches this address" query.
+    pub fn longest_prefix_match(&self, word: &[u8]) -> Option<Vec<u8>> {
+        let mut node = 0u32;
+        let mut consumed = 0usize;
+        let mut best = if self.arena[0].terminal { Some(0) } else { None };
+        loop {
+            if consumed == word.len() {
+                break;
+            }
+            let Some(&child) = self.arena[node as usize].children.get(&word[consumed]) else {
+                break;
+            };
+            let label = &self.arena[child as usize].label;
+            if !word[consumed..].starts_with(label.as_slice()) {
+                break;
+            }
+            consumed += label.len();
+            node = child;
+            if self.arena[node as usize].terminal {
+                best = Some(consumed);
+            }
+        }
+        best.map(|len| word[..len].to_vec())
+    }
+
+    fn insert_from(&mut self, node: u32, remaining: &[u8]) {
+        if remaining.is_empty() {
+            self.arena[node as usize].terminal = true;
+            return;
+        }
+
+        let byte = remaining[0];
+        match self.arena[node as usize].children.get(&byte).copied() {
+            None => {
+                self.arena.push(Node::new(remaining.to_vec(), true));
+                let new_index = (self.arena.len() - 1) as u32;
+                self.arena[node as usize].children.insert(byte, new_index);
+            }
+            Some(child) => {
+                let common = common_prefix_len(&self.arena[child as usize].label, remaining);
+                if common == self.arena[child as usize].label.len() {
+                    self.insert_from(child, &remaining[common..]);
+                } else {
+                    self.split_child(child, common);
+                    if common == remaining.len() {
+                        self.arena[child as usize].terminal = true;
+                    } else {
+                        self.insert_from(child, &remaining[common..]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Splits `child`'s edge after its first `common` bytes, demoting
+    /// the rest of its old label (and its own children) onto a fresh
+    /// node beneath it, so `child` can grow a second branch at the
+    /// split point.
+    fn split_child(&mut self, child: u32, common: usize) {
+        let old_label = core::mem::take(&mut self.arena[child as usize].label);
+        let old_children = core::mem::take(&mut self.arena[child as usize].children);
+        let old_terminal = self.arena[child as usize].terminal;
+
+        self.arena[child as usize].label = old_label[..common].to_vec();
+        self.arena[child as usize].terminal = false;
+
+        let mut tail_node = Node::new(old_label[common..].to_vec(), old_terminal);
+        tail_node.children = old_children;
+        self.arena.push(tail_node);
+        let tail_index = (self.arena.len() - 1) as u32;
+        self.arena[child as usize].children.insert(old_label[common], tail_index);
+    }
+}
+
+impl Default for RadixTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&Trie> for RadixTrie {
+    /// Rebuilds every word held by a plain [`Trie`] into a freshly
+    /// compressed radix trie, via its public `words_with_prefix`
+    /// query rather than reaching into its arena directly.
+    fn from(trie: &Trie) -> Self {
+        let mut radix = RadixTrie::new();
+        for word in trie.words_with_prefix(&[]) {
+            radix.insert(&word);
+        }
+        radix
+    }
+}
+
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut trie = RadixTrie::new();
+        for word in [&b"romane"[..], b"romanus", b"romulus", b"rubens", b"ruber", b"rubicon", b"rubicundus"] {
+            trie.insert(word);
+        }
+        for word in [&b"romane"[..], b"romanus", b"romulus", b"rubens", b"ruber", b"rubicon", b"rubicundus"] {
+            assert!(trie.contains(word));
+        }
+        assert!(!trie.contains(b"roman"));
+        assert!(!trie.contains(b"rub"));
+        assert!(!trie.contains(b"rubicundussss"));
+    }
+
+    #[test]
+    fn splitting_a_shared_edge_keeps_both_words_reachable() {
+        let mut trie = RadixTrie::new();
+        trie.insert(b"test");
+        trie.insert(b"team");
+        trie.insert(b"toast");
+        assert!(trie.contains(b"test"));
+        assert!(trie.contains(b"team"));
+        assert!(trie.contains(b"toast"));
+        assert!(!trie.contains(b"te"));
+        assert!(!trie.contains(b"to"));
+    }
+
+    #[test]
+    fn longest_prefix_match_finds_the_most_specific_entry() {
+        let mut trie = RadixTrie::new();
+        for word in [&b"10.0"[..], b"10.0.0", b"10.0.0.1"] {
+            trie.insert(word);
+        }
+        assert_eq!(trie.longest_prefix_match(b"10.0.0.1"), Some(b"10.0.0.1".to_vec()));
+        assert_eq!(trie.longest_prefix_match(b"10.0.0.255"), Some(b"10.0.0".to_vec()));
+        assert_eq!(trie.longest_prefix_match(b"10.0.5"), Some(b"10.0".to_vec()));
+        assert_eq!(trie.longest_prefix_match(b"192.168.0.1"), None);
+    }
+
+    #[test]
+    fn from_trie_preserves_every_word() {
+        let mut plain = Trie::new();
+        for word in [&b"car"[..], b"cart", b"care", b"cat", b"dog"] {
+            plain.insert(word);
+        }
+        let radix = RadixTrie::from(&plain);
+        for word in [&b"car"[..], b"cart", b"care", b"cat", b"dog"] {
+            assert!(radix.contains(word));
+        }
+        assert!(!radix.contains(b"ca"));
+    }
+}
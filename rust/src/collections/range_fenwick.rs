@@ -0,0 +1,63 @@
+use super::fenwick::Fenwick;
+
+/// The "dual BIT" trick: two plain [`Fenwick`] trees combined so that
+/// *both* range updates and range queries run in O(log n), the usual
+/// reason to reach for a lazily-propagated segment tree, but without
+/// needing one when the update is just "add a constant to a range".
+///
+/// Derivation: adding `delta` to every element in `[l, r)` is encoded as
+/// `bit1.add(l, delta)`, `bit1.add(r, -delta)` (so `bit1`'s prefix sum at
+/// `i` is `delta` while `i` is inside the range, `0` once `i` passes it)
+/// together with the matching `bit2.add(l, delta * l)`,
+/// `bit2.add(r, -delta * r)` correction, so that the prefix sum of the
+/// *original* array works out to `bit1.prefix_sum(i) * i -
+/// bit2.prefix_sum(i)`.
+pub struct RangeFenwick {
+    bit1: Fenwick<i64>,
+    bit2: Fenwick<i64>,
+}
+
+impl RangeFenwick {
+    pub fn new(n: usize) -> Self {
+        RangeFenwick { bit1: Fenwick::new(n), bit2: Fenwick::new(n) }
+    }
+
+    pub fn build(values: &[i64]) -> Self {
+        let mut tree = RangeFenwick::new(values.len());
+        for (i, &v) in values.iter().enumerate() {
+            tree.range_add(i, i + 1, v);
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.bit1.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to every element in the half-open range `[l, r)`.
+    pub fn range_add(&mut self, l: usize, r: usize, delta: i64) {
+        self.bit1.add(l, delta);
+        self.bit1.add(r, -delta);
+        self.bit2.add(l, delta * l as i64);
+        self.bit2.add(r, -(delta * r as i64));
+    }
+
+    /// Sum of the half-open prefix `[0, i)`.
+    pub fn prefix_sum(&self, i: usize) -> i64 {
+        self.bit1.prefix_sum(i) * i as i64 - self.bit2.prefix_sum(i)
+    }
+
+    /// Sum of the half-open range `[l, r)`.
+    pub fn range_sum(&self, l: usize, r: usize) -> i64 {
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+
+    /// The current value at the single position `i`.
+    pub fn point_query(&self, i: usize) -> i64 {
+        self.range_sum(i, i + 1)
+    }
+}
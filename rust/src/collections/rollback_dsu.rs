@@ -0,0 +1,84 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// One successful [`RollbackDsu::union`], recorded so [`rollback_to`]
+/// can undo exactly it.
+///
+/// [`rollback_to`]: RollbackDsu::rollback_to
+struct UnionRecord {
+    child_root: usize,
+    bumped_rank_of: Option<usize>,
+}
+
+/// Union-by-rank DSU *without* path compression, so every merge is a
+/// single O(1) reversible edit instead of a whole compressed chain —
+/// the property an undo log needs to stay cheap. Built for offline
+/// algorithms like [`dynamic_connectivity`] that repeatedly union a
+/// batch of edges, answer queries, then need to roll back to exactly
+/// the state before that batch.
+///
+/// [`dynamic_connectivity`]: crate::graph::dynamic_connectivity
+pub struct RollbackDsu {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    history: Vec<UnionRecord>,
+}
+
+impl RollbackDsu {
+    pub fn new(n: usize) -> Self {
+        RollbackDsu { parent: (0..n).collect(), rank: vec![0; n], history: Vec::new() }
+    }
+
+    /// The representative of `x`'s set. No path compression, so this
+    /// never mutates — the DSU's only mutation is [`union`](Self::union),
+    /// which [`rollback_to`](Self::rollback_to) can always undo.
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    pub fn connected(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they
+    /// were already the same set (and records nothing, since there's
+    /// nothing to undo).
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        if self.rank[ra] < self.rank[rb] {
+            core::mem::swap(&mut ra, &mut rb);
+        }
+        let bumped_rank_of = if self.rank[ra] == self.rank[rb] {
+            self.rank[ra] += 1;
+            Some(ra)
+        } else {
+            None
+        };
+        self.parent[rb] = ra;
+        self.history.push(UnionRecord { child_root: rb, bumped_rank_of });
+        true
+    }
+
+    /// A checkpoint for a later [`rollback_to`](Self::rollback_to).
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes every union since `snapshot`, restoring exactly the state
+    /// [`snapshot`](Self::snapshot) was taken in.
+    pub fn rollback_to(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            let record = self.history.pop().expect("loop condition guarantees history is non-empty");
+            self.parent[record.child_root] = record.child_root;
+            if let Some(root) = record.bumped_rank_of {
+                self.rank[root] -= 1;
+            }
+        }
+    }
+}
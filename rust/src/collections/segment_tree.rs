@@ -0,0 +1,100 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An associative operation with an identity element, e.g. sum, min, max,
+/// or gcd — whatever a [`SegmentTree`] should combine ranges with.
+pub trait Monoid: Clone {
+    fn identity() -> Self;
+    fn combine(&self, other: &Self) -> Self;
+}
+
+/// Iterative (bottom-up, `2n`-array) segment tree over any [`Monoid`]:
+/// point updates and range queries both in O(log n), with none of the
+/// pointer-chasing of a recursive top-down tree.
+pub struct SegmentTree<T: Monoid> {
+    n: usize,
+    tree: Vec<T>,
+}
+
+impl<T: Monoid> SegmentTree<T> {
+    pub fn build(values: &[T]) -> Self {
+        let n = values.len();
+        let mut tree = vec![T::identity(); 2 * n];
+        tree[n..].clone_from_slice(values);
+        for i in (1..n).rev() {
+            tree[i] = tree[2 * i].combine(&tree[2 * i + 1]);
+        }
+        SegmentTree { n, tree }
+    }
+
+    pub fn update(&mut self, index: usize, value: T) {
+        let mut i = index + self.n;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i].combine(&self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Combines the half-open range `[l, r)`.
+    pub fn query(&self, l: usize, r: usize) -> T {
+        let (mut l, mut r) = (l + self.n, r + self.n);
+        let mut from_left = T::identity();
+        let mut from_right = T::identity();
+        while l < r {
+            if l % 2 == 1 {
+                from_left = from_left.combine(&self.tree[l]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                from_right = self.tree[r].combine(&from_right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        from_left.combine(&from_right)
+    }
+}
+
+/// Combines by sum, identity `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sum(pub i64);
+
+impl Monoid for Sum {
+    fn identity() -> Self {
+        Sum(0)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+/// Combines by minimum, identity `i64::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Min(pub i64);
+
+impl Monoid for Min {
+    fn identity() -> Self {
+        Min(i64::MAX)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        Min(self.0.min(other.0))
+    }
+}
+
+/// Combines by maximum, identity `i64::MIN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Max(pub i64);
+
+impl Monoid for Max {
+    fn identity() -> Self {
+        Max(i64::MIN)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        Max(self.0.max(other.0))
+    }
+}
@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+
+use crate::collections::segment_tree::{Monoid, SegmentTree};
+
+/// A "segment tree of segment trees": an outer 1D segment tree over rows
+/// whose every node holds a full 1D [`SegmentTree`] over columns,
+/// combining that node's row range element-wise. Point updates and
+/// rectangle queries are both O(log rows * log cols), touching one column
+/// tree per outer node on the query/update path.
+pub struct SegmentTree2D<T: Monoid> {
+    rows: usize,
+    tree: Vec<SegmentTree<T>>,
+}
+
+impl<T: Monoid> SegmentTree2D<T> {
+    pub fn build(grid: &[Vec<T>]) -> Self {
+        let rows = grid.len();
+        let mut tree: Vec<Option<SegmentTree<T>>> = (0..2 * rows).map(|_| None).collect();
+        tree[0] = Some(SegmentTree::build(&[])); // index 0 is never addressed by the 1-indexed layout below.
+        for (i, row) in grid.iter().enumerate() {
+            tree[rows + i] = Some(SegmentTree::build(row));
+        }
+        for i in (1..rows).rev() {
+            let cols = grid[0].len();
+            let merged: Vec<T> = (0..cols)
+                .map(|c| tree[2 * i].as_ref().unwrap().query(c, c + 1).combine(&tree[2 * i + 1].as_ref().unwrap().query(c, c + 1)))
+                .collect();
+            tree[i] = Some(SegmentTree::build(&merged));
+        }
+        SegmentTree2D { rows, tree: tree.into_iter().map(|node| node.expect("every node built above")).collect() }
+    }
+
+    pub fn update(&mut self, r: usize, c: usize, value: T) {
+        let mut i = r + self.rows;
+        self.tree[i].update(c, value);
+        while i > 1 {
+            i /= 2;
+            let combined = self.tree[2 * i].query(c, c + 1).combine(&self.tree[2 * i + 1].query(c, c + 1));
+            self.tree[i].update(c, combined);
+        }
+    }
+
+    /// Combines the rectangle `rows [r1, r2) x cols [c1, c2)`.
+    pub fn query(&self, r1: usize, r2: usize, c1: usize, c2: usize) -> T {
+        let (mut l, mut r) = (r1 + self.rows, r2 + self.rows);
+        let mut from_left = T::identity();
+        let mut from_right = T::identity();
+        while l < r {
+            if l % 2 == 1 {
+                from_left = from_left.combine(&self.tree[l].query(c1, c2));
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                from_right = self.tree[r].query(c1, c2).combine(&from_right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        from_left.combine(&from_right)
+    }
+}
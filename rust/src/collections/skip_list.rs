@@ -0,0 +1,376 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+const MAX_LEVEL: usize = 32;
+
+/// One arena slot. `forward[i]`/`span[i]` are only defined for
+/// `i < forward.len()` — that length is the node's randomly chosen
+/// "height", how many levels of the list it participates in. `span[i]`
+/// is the number of nodes skipped (including the forward target itself)
+/// by following `forward[i]`, which is what makes [`SkipList::rank`] and
+/// [`SkipList::select`] O(log n) instead of a level-0 linear walk.
+struct Node<T> {
+    value: T,
+    forward: Vec<u32>,
+    span: Vec<u32>,
+}
+
+/// A probabilistically balanced ordered multiset: each inserted node is
+/// linked into a random number of singly-linked levels (geometric
+/// distribution, `p = 1/4`), so the top levels act like an express lane
+/// over the full list and a search skips most of it. Expected O(log n)
+/// search/insert/erase without any explicit rebalancing, at the cost of
+/// O(log n) *expected* rather than *worst-case* — an unlucky run of coin
+/// flips can (rarely) degrade towards a plain linked list.
+///
+/// The head of the list is a sentinel that never holds a value, tracked
+/// separately from the arena (`head_forward`/`head_span`) so `Node<T>`
+/// never needs an `Option<T>` just for the head slot.
+pub struct SkipList<T: Ord> {
+    arena: Vec<Node<T>>,
+    head_forward: Vec<u32>,
+    head_span: Vec<u32>,
+    level: usize,
+    len: usize,
+    rng: u64,
+}
+
+impl<T: Ord> SkipList<T> {
+    pub fn new() -> Self {
+        Self::with_seed(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Builds an empty skip list with a fixed RNG seed, for reproducible
+    /// shapes in tests — the randomness only needs to avoid adversarial
+    /// worst cases, not be unpredictable.
+    pub fn with_seed(seed: u64) -> Self {
+        SkipList {
+            arena: Vec::new(),
+            head_forward: vec![NULL; MAX_LEVEL],
+            head_span: vec![0; MAX_LEVEL],
+            level: 1,
+            len: 0,
+            rng: seed | 1,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node = NULL;
+        for i in (0..self.level).rev() {
+            loop {
+                let next = self.forward_at(node, i);
+                if next != NULL && self.arena[next as usize].value < *value {
+                    node = next;
+                } else {
+                    break;
+                }
+            }
+        }
+        let candidate = self.forward_at(node, 0);
+        candidate != NULL && self.arena[candidate as usize].value == *value
+    }
+
+    pub fn insert(&mut self, value: T) {
+        let mut update = [NULL; MAX_LEVEL];
+        let mut rank = [0u32; MAX_LEVEL];
+        let mut node = NULL;
+        let mut traveled = 0u32;
+        for i in (0..self.level).rev() {
+            loop {
+                let next = self.forward_at(node, i);
+                if next != NULL && self.arena[next as usize].value < value {
+                    traveled += self.span_at(node, i);
+                    node = next;
+                } else {
+                    break;
+                }
+            }
+            update[i] = node;
+            rank[i] = traveled;
+        }
+
+        let new_level = self.next_random_level();
+        if new_level > self.level {
+            for head_span in &mut self.head_span[self.level..new_level] {
+                *head_span = self.len as u32;
+            }
+            self.level = new_level;
+        }
+
+        let new_index = self.push_node(value, new_level);
+
+        for i in 0..new_level {
+            let old_span = self.span_at(update[i], i);
+            let next = self.forward_at(update[i], i);
+            self.set_forward_at(new_index, i, next);
+            self.set_forward_at(update[i], i, new_index);
+            self.set_span_at(new_index, i, old_span - (rank[0] - rank[i]));
+            self.set_span_at(update[i], i, rank[0] - rank[i] + 1);
+        }
+        for (i, &predecessor) in update.iter().enumerate().take(self.level).skip(new_level) {
+            let s = self.span_at(predecessor, i);
+            self.set_span_at(predecessor, i, s + 1);
+        }
+
+        self.len += 1;
+    }
+
+    /// Removes one occurrence of `value`. Returns `false` if it wasn't
+    /// present.
+    pub fn erase(&mut self, value: &T) -> bool {
+        let mut update = [NULL; MAX_LEVEL];
+        let mut node = NULL;
+        for i in (0..self.level).rev() {
+            loop {
+                let next = self.forward_at(node, i);
+                if next != NULL && self.arena[next as usize].value < *value {
+                    node = next;
+                } else {
+                    break;
+                }
+            }
+            update[i] = node;
+        }
+
+        let target = self.forward_at(node, 0);
+        if target == NULL || self.arena[target as usize].value != *value {
+            return false;
+        }
+
+        for (i, &predecessor) in update.iter().enumerate().take(self.level) {
+            if self.forward_at(predecessor, i) == target {
+                let combined = self.span_at(predecessor, i) + self.span_at(target, i) - 1;
+                self.set_span_at(predecessor, i, combined);
+                let target_next = self.forward_at(target, i);
+                self.set_forward_at(predecessor, i, target_next);
+            } else {
+                let s = self.span_at(predecessor, i);
+                self.set_span_at(predecessor, i, s - 1);
+            }
+        }
+
+        while self.level > 1 && self.head_forward[self.level - 1] == NULL {
+            self.level -= 1;
+        }
+
+        self.len -= 1;
+        true
+    }
+
+    /// Count of values strictly less than `value`.
+    pub fn rank(&self, value: &T) -> usize {
+        let mut node = NULL;
+        let mut traveled = 0u32;
+        for i in (0..self.level).rev() {
+            loop {
+                let next = self.forward_at(node, i);
+                if next != NULL && self.arena[next as usize].value < *value {
+                    traveled += self.span_at(node, i);
+                    node = next;
+                } else {
+                    break;
+                }
+            }
+        }
+        traveled as usize
+    }
+
+    /// The `k`-th smallest value (0-indexed), or `None` if fewer than
+    /// `k + 1` values are present.
+    pub fn select(&self, k: usize) -> Option<&T> {
+        let target_rank = (k + 1) as u32;
+        let mut node = NULL;
+        let mut traveled = 0u32;
+        for i in (0..self.level).rev() {
+            loop {
+                let next = self.forward_at(node, i);
+                let next_span = self.span_at(node, i);
+                if next != NULL && traveled + next_span <= target_rank {
+                    traveled += next_span;
+                    node = next;
+                } else {
+                    break;
+                }
+            }
+            if traveled == target_rank {
+                break;
+            }
+        }
+        if traveled == target_rank && node != NULL {
+            Some(&self.arena[node as usize].value)
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { arena: &self.arena, next: self.head_forward[0] }
+    }
+
+    fn next_random_level(&mut self) -> usize {
+        // xorshift64*, the same generator Treap uses, read two bits at a
+        // time as a coin-flip stream: level p = 1/4 per extra level.
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        let mut bits = self.rng.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        let mut level = 1;
+        while level < MAX_LEVEL && bits & 0b11 == 0 {
+            level += 1;
+            bits >>= 2;
+        }
+        level
+    }
+
+    fn push_node(&mut self, value: T, level: usize) -> u32 {
+        self.arena.push(Node { value, forward: vec![NULL; level], span: vec![0; level] });
+        (self.arena.len() - 1) as u32
+    }
+
+    fn forward_at(&self, node: u32, level: usize) -> u32 {
+        if node == NULL {
+            self.head_forward[level]
+        } else {
+            self.arena[node as usize].forward[level]
+        }
+    }
+
+    fn set_forward_at(&mut self, node: u32, level: usize, target: u32) {
+        if node == NULL {
+            self.head_forward[level] = target;
+        } else {
+            self.arena[node as usize].forward[level] = target;
+        }
+    }
+
+    fn span_at(&self, node: u32, level: usize) -> u32 {
+        if node == NULL {
+            self.head_span[level]
+        } else {
+            self.arena[node as usize].span[level]
+        }
+    }
+
+    fn set_span_at(&mut self, node: u32, level: usize, span: u32) {
+        if node == NULL {
+            self.head_span[level] = span;
+        } else {
+            self.arena[node as usize].span[level] = span;
+        }
+    }
+}
+
+impl<T: Ord> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ascending in-order iterator: just walks the level-0 chain, which is
+/// always a fully sorted singly-linked list.
+pub struct Iter<'a, T> {
+    arena: &'a [Node<T>],
+    next: u32,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.next == NULL {
+            return None;
+        }
+        let node = &self.arena[self.next as usize];
+        self.next = node.forward[0];
+        Some(&node.value)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a SkipList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut list = SkipList::with_seed(1);
+        for v in [5, 3, 8, 1, 9, 3] {
+            list.insert(v);
+        }
+        assert_eq!(list.len(), 6);
+        assert!(list.contains(&3));
+        assert!(list.contains(&9));
+        assert!(!list.contains(&100));
+    }
+
+    #[test]
+    fn iterates_in_ascending_order() {
+        let mut list = SkipList::with_seed(7);
+        for v in [5, 3, 8, 1, 9, 3] {
+            list.insert(v);
+        }
+        let collected: Vec<i32> = list.iter().copied().collect();
+        assert_eq!(collected, vec![1, 3, 3, 5, 8, 9]);
+    }
+
+    #[test]
+    fn erase_removes_one_occurrence() {
+        let mut list = SkipList::with_seed(42);
+        for v in [5, 3, 8, 3] {
+            list.insert(v);
+        }
+        assert!(list.erase(&3));
+        assert_eq!(list.len(), 3);
+        assert!(list.contains(&3));
+        assert!(list.erase(&3));
+        assert!(!list.contains(&3));
+        assert!(!list.erase(&3));
+    }
+
+    #[test]
+    fn rank_and_select_agree_with_sorted_order() {
+        let mut list = SkipList::with_seed(123);
+        let mut values = vec![5, 3, 8, 1, 9, 3, 7, 2];
+        for &v in &values {
+            list.insert(v);
+        }
+        values.sort_unstable();
+        for (k, &v) in values.iter().enumerate() {
+            assert_eq!(list.rank(&v), values.iter().position(|&x| x == v).unwrap());
+            assert_eq!(list.select(k), Some(&values[k]));
+        }
+        assert_eq!(list.select(values.len()), None);
+    }
+
+    #[test]
+    fn stays_consistent_through_many_inserts_and_erases() {
+        let mut list = SkipList::with_seed(99);
+        for v in 0..1000 {
+            list.insert(v);
+        }
+        for v in (0..1000).step_by(2) {
+            assert!(list.erase(&v));
+        }
+        assert_eq!(list.len(), 500);
+        let collected: Vec<i32> = list.iter().copied().collect();
+        let expected: Vec<i32> = (0..1000).step_by(2).map(|v| v + 1).collect();
+        assert_eq!(collected, expected);
+    }
+}
@@ -0,0 +1,154 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::segment_tree::Monoid;
+
+/// Newton's method integer square root, rounded up to at least 1 — the
+/// block size every structure in this file buckets elements by. Kept
+/// integer-only (no `f64::sqrt`) so this module stays available in the
+/// `#![no_std]` build.
+fn block_size_for(n: usize) -> usize {
+    if n == 0 {
+        return 1;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x.max(1)
+}
+
+/// Sqrt decomposition over any [`Monoid`]: the array split into
+/// `O(sqrt n)` blocks of `O(sqrt n)` elements each, with one combined
+/// aggregate cached per block. A point [`update`](Self::update)
+/// recomputes its one block from scratch; a [`query`](Self::query)
+/// combines whole blocks via their cached aggregate and touches partial
+/// blocks at their edges element by element — both O(sqrt n), the
+/// tradeoff for a much simpler implementation than a [`SegmentTree`]'s
+/// O(log n).
+///
+/// [`block_of`](Self::block_of) exposes the same block assignment Mo's
+/// algorithm sorts offline range queries by, so this doubles as the
+/// bucketing primitive a Mo's-algorithm implementation would build on.
+///
+/// [`SegmentTree`]: crate::collections::segment_tree::SegmentTree
+pub struct SqrtDecomposition<T: Monoid> {
+    values: Vec<T>,
+    block_size: usize,
+    block_aggregate: Vec<T>,
+}
+
+impl<T: Monoid> SqrtDecomposition<T> {
+    pub fn build(values: &[T]) -> Self {
+        let block_size = block_size_for(values.len());
+        let num_blocks = values.len().div_ceil(block_size).max(1);
+        let mut block_aggregate = vec![T::identity(); num_blocks];
+        for (i, v) in values.iter().enumerate() {
+            let b = i / block_size;
+            block_aggregate[b] = block_aggregate[b].combine(v);
+        }
+        SqrtDecomposition { values: values.to_vec(), block_size, block_aggregate }
+    }
+
+    pub fn update(&mut self, index: usize, value: T) {
+        self.values[index] = value;
+        let b = index / self.block_size;
+        let start = b * self.block_size;
+        let end = (start + self.block_size).min(self.values.len());
+        let mut aggregate = T::identity();
+        for v in &self.values[start..end] {
+            aggregate = aggregate.combine(v);
+        }
+        self.block_aggregate[b] = aggregate;
+    }
+
+    /// Combines the half-open range `[l, r)`.
+    pub fn query(&self, l: usize, r: usize) -> T {
+        let mut result = T::identity();
+        let mut i = l;
+        while i < r {
+            let block_start = (i / self.block_size) * self.block_size;
+            let block_end = (block_start + self.block_size).min(self.values.len());
+            if i == block_start && block_end <= r {
+                result = result.combine(&self.block_aggregate[i / self.block_size]);
+                i = block_end;
+            } else {
+                result = result.combine(&self.values[i]);
+                i += 1;
+            }
+        }
+        result
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// The block `index` falls in.
+    pub fn block_of(&self, index: usize) -> usize {
+        index / self.block_size
+    }
+}
+
+/// Sqrt decomposition specialized for range-add, range-sum: unlike
+/// [`SqrtDecomposition`]'s point updates, adding to a whole range is
+/// also O(sqrt n) here, by stashing a pending per-block delta
+/// (`block_lazy_add`) instead of touching every element a full block
+/// covers.
+pub struct SqrtRangeAdd {
+    values: Vec<i64>,
+    block_size: usize,
+    block_sum: Vec<i64>,
+    block_lazy_add: Vec<i64>,
+}
+
+impl SqrtRangeAdd {
+    pub fn build(values: &[i64]) -> Self {
+        let block_size = block_size_for(values.len());
+        let num_blocks = values.len().div_ceil(block_size).max(1);
+        let mut block_sum = vec![0i64; num_blocks];
+        for (i, &v) in values.iter().enumerate() {
+            block_sum[i / block_size] += v;
+        }
+        SqrtRangeAdd { values: values.to_vec(), block_size, block_sum, block_lazy_add: vec![0; num_blocks] }
+    }
+
+    /// Adds `delta` to every element in the half-open range `[l, r)`.
+    pub fn range_add(&mut self, l: usize, r: usize, delta: i64) {
+        let mut i = l;
+        while i < r {
+            let b = i / self.block_size;
+            let block_start = b * self.block_size;
+            let block_end = (block_start + self.block_size).min(self.values.len());
+            if i == block_start && block_end <= r {
+                self.block_lazy_add[b] += delta;
+                i = block_end;
+            } else {
+                self.values[i] += delta;
+                self.block_sum[b] += delta;
+                i += 1;
+            }
+        }
+    }
+
+    /// The sum over the half-open range `[l, r)`.
+    pub fn range_sum(&self, l: usize, r: usize) -> i64 {
+        let mut sum = 0;
+        let mut i = l;
+        while i < r {
+            let b = i / self.block_size;
+            let block_start = b * self.block_size;
+            let block_end = (block_start + self.block_size).min(self.values.len());
+            if i == block_start && block_end <= r {
+                sum += self.block_sum[b] + self.block_lazy_add[b] * (block_end - block_start) as i64;
+                i = block_end;
+            } else {
+                sum += self.values[i] + self.block_lazy_add[b];
+                i += 1;
+            }
+        }
+        sum
+    }
+}
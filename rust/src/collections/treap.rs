@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+
+/// One arena slot: `left`/`right` are indices into the same arena (or
+/// [`NULL`] for no child), the same "avoid `Rc`/`Box` pointer-chasing"
+/// trick [`PersistentSegmentTree`] uses.
+///
+/// [`PersistentSegmentTree`]: crate::collections::persistent_segment_tree::PersistentSegmentTree
+struct Node<T> {
+    key: T,
+    priority: u64,
+    left: u32,
+    right: u32,
+    size: u32,
+}
+
+/// A randomized balanced binary search tree (treap): every node gets a
+/// random priority, and merge/split maintain heap order on priority
+/// alongside BST order on `key`, which keeps the tree's expected depth
+/// O(log n) without any explicit rebalancing. Duplicate keys are kept
+/// (this is a multiset), ordered arbitrarily among themselves.
+pub struct Treap<T: Ord> {
+    arena: Vec<Node<T>>,
+    root: u32,
+    rng: u64,
+}
+
+impl<T: Ord> Treap<T> {
+    pub fn new() -> Self {
+        Self::with_seed(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Builds an empty treap with a fixed RNG seed, for reproducible
+    /// shapes in tests — the randomness only needs to avoid adversarial
+    /// worst cases, not be unpredictable.
+    pub fn with_seed(seed: u64) -> Self {
+        Treap { arena: Vec::new(), root: NULL, rng: seed | 1 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn insert(&mut self, key: T) {
+        let (left, right) = self.split_le(self.root, &key);
+        let priority = self.next_priority();
+        let new_node = self.arena.len() as u32;
+        self.arena.push(Node { key, priority, left: NULL, right: NULL, size: 1 });
+        let merged_left = self.merge(left, new_node);
+        self.root = self.merge(merged_left, right);
+    }
+
+    /// Removes one occurrence of `key`. Returns `false` if it wasn't
+    /// present.
+    pub fn erase(&mut self, key: &T) -> bool {
+        let (less, ge) = self.split_lt(self.root, key);
+        let (eq, greater) = self.split_le(ge, key);
+        if eq == NULL {
+            self.root = self.merge(less, greater);
+            return false;
+        }
+        let remaining_eq = self.merge(self.arena[eq as usize].left, self.arena[eq as usize].right);
+        let merged = self.merge(less, remaining_eq);
+        self.root = self.merge(merged, greater);
+        true
+    }
+
+    /// Count of keys strictly less than `key`.
+    pub fn rank(&self, key: &T) -> usize {
+        self.rank_from(self.root, key)
+    }
+
+    /// The `k`-th smallest key (0-indexed), or `None` if fewer than
+    /// `k + 1` keys are present.
+    pub fn select(&self, k: usize) -> Option<&T> {
+        if k >= self.len() {
+            return None;
+        }
+        let mut node = self.root;
+        let mut k = k;
+        loop {
+            let left_size = self.size(self.arena[node as usize].left);
+            if k < left_size {
+                node = self.arena[node as usize].left;
+            } else if k == left_size {
+                return Some(&self.arena[node as usize].key);
+            } else {
+                k -= left_size + 1;
+                node = self.arena[node as usize].right;
+            }
+        }
+    }
+
+    /// Count of keys in the half-open range `[lo, hi)`.
+    pub fn range_count(&self, lo: &T, hi: &T) -> usize {
+        self.rank(hi) - self.rank(lo)
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        // xorshift64*: good enough to avoid adversarial BST shapes,
+        // which is all a treap's priorities need to do.
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    fn size(&self, node: u32) -> usize {
+        if node == NULL {
+            0
+        } else {
+            self.arena[node as usize].size as usize
+        }
+    }
+
+    fn update(&mut self, node: u32) {
+        if node != NULL {
+            let (l, r) = (self.arena[node as usize].left, self.arena[node as usize].right);
+            self.arena[node as usize].size = 1 + self.size(l) as u32 + self.size(r) as u32;
+        }
+    }
+
+    fn merge(&mut self, a: u32, b: u32) -> u32 {
+        if a == NULL {
+            return b;
+        }
+        if b == NULL {
+            return a;
+        }
+        if self.arena[a as usize].priority > self.arena[b as usize].priority {
+            let right = self.arena[a as usize].right;
+            let merged = self.merge(right, b);
+            self.arena[a as usize].right = merged;
+            self.update(a);
+            a
+        } else {
+            let left = self.arena[b as usize].left;
+            let merged = self.merge(a, left);
+            self.arena[b as usize].left = merged;
+            self.update(b);
+            b
+        }
+    }
+
+    /// Splits into `(keys <= x, keys > x)`, preserving relative order.
+    fn split_le(&mut self, node: u32, key: &T) -> (u32, u32) {
+        if node == NULL {
+            return (NULL, NULL);
+        }
+        if self.arena[node as usize].key <= *key {
+            let right = self.arena[node as usize].right;
+            let (right_le, right_gt) = self.split_le(right, key);
+            self.arena[node as usize].right = right_le;
+            self.update(node);
+            (node, right_gt)
+        } else {
+            let left = self.arena[node as usize].left;
+            let (left_le, left_gt) = self.split_le(left, key);
+            self.arena[node as usize].left = left_gt;
+            self.update(node);
+            (left_le, node)
+        }
+    }
+
+    /// Splits into `(keys < x, keys >= x)`, preserving relative order.
+    fn split_lt(&mut self, node: u32, key: &T) -> (u32, u32) {
+        if node == NULL {
+            return (NULL, NULL);
+        }
+        if self.arena[node as usize].key < *key {
+            let right = self.arena[node as usize].right;
+            let (right_lt, right_ge) = self.split_lt(right, key);
+            self.arena[node as usize].right = right_lt;
+            self.update(node);
+            (node, right_ge)
+        } else {
+            let left = self.arena[node as usize].left;
+            let (left_lt, left_ge) = self.split_lt(left, key);
+            self.arena[node as usize].left = left_ge;
+            self.update(node);
+            (left_lt, node)
+        }
+    }
+
+    fn rank_from(&self, node: u32, key: &T) -> usize {
+        if node == NULL {
+            return 0;
+        }
+        let n = &self.arena[node as usize];
+        if n.key < *key {
+            1 + self.size(n.left) + self.rank_from(n.right, key)
+        } else {
+            self.rank_from(n.left, key)
+        }
+    }
+}
+
+impl<T: Ord> Default for Treap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// One trie node. `passing` counts how many inserted words pass through
+/// (or end at) this node and `terminal_count` how many end exactly here —
+/// tracking both as plain counts, rather than a single "is this node the
+/// end of a word" flag, is what lets [`Trie::erase`] decrement its way
+/// back to an accurate trie without leaving behind tombstone markers for
+/// words that are no longer present.
+struct TrieNode {
+    children: BTreeMap<u8, u32>,
+    passing: u32,
+    terminal_count: u32,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        TrieNode { children: BTreeMap::new(), passing: 0, terminal_count: 0 }
+    }
+}
+
+/// A byte-string trie supporting counted prefix queries and duplicate-
+/// aware deletion. Root is `arena[0]`.
+pub struct Trie {
+    arena: Vec<TrieNode>,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Trie { arena: vec![TrieNode::new()] }
+    }
+
+    /// Inserts `word`, incrementing every node on its path. Inserting
+    /// the same word again is allowed and tracked as a second
+    /// occurrence (`count_prefix`/`contains` reflect the running total).
+    pub fn insert(&mut self, word: &[u8]) {
+        let mut node = 0u32;
+        self.arena[0].passing += 1;
+        for &byte in word {
+            node = match self.arena[node as usize].children.get(&byte) {
+                Some(&next) => next,
+                None => {
+                    self.arena.push(TrieNode::new());
+                    let new_index = (self.arena.len() - 1) as u32;
+                    self.arena[node as usize].children.insert(byte, new_index);
+                    new_index
+                }
+            };
+            self.arena[node as usize].passing += 1;
+        }
+        self.arena[node as usize].terminal_count += 1;
+    }
+
+    /// Removes one occurrence of `word`. Returns `false` if it wasn't
+    /// present (leaving the trie untouched).
+    pub fn erase(&mut self, word: &[u8]) -> bool {
+        let Some(path) = self.path_to(word) else { return false };
+        let end = *path.last().unwrap();
+        if self.arena[end as usize].terminal_count == 0 {
+            return false;
+        }
+        self.arena[end as usize].terminal_count -= 1;
+        for node in path {
+            self.arena[node as usize].passing -= 1;
+        }
+        true
+    }
+
+    pub fn contains(&self, word: &[u8]) -> bool {
+        match self.path_to(word) {
+            Some(path) => self.arena[*path.last().unwrap() as usize].terminal_count > 0,
+            None => false,
+        }
+    }
+
+    /// Count of inserted words (counting duplicates) that start with
+    /// `prefix`. `0` if no word has this prefix.
+    pub fn count_prefix(&self, prefix: &[u8]) -> usize {
+        match self.find(prefix) {
+            Some(node) => self.arena[node as usize].passing as usize,
+            None => 0,
+        }
+    }
+
+    /// All distinct words starting with `prefix`, in ascending byte
+    /// order (a consequence of `children` being a `BTreeMap`).
+    pub fn words_with_prefix(&self, prefix: &[u8]) -> Vec<Vec<u8>> {
+        let Some(node) = self.find(prefix) else { return Vec::new() };
+        let mut results = Vec::new();
+        let mut buffer = prefix.to_vec();
+        self.collect_words(node, &mut buffer, &mut results);
+        results
+    }
+
+    /// The longest byte string that is a prefix of every inserted word,
+    /// or empty if the trie is empty or has no shared prefix.
+    pub fn longest_common_prefix(&self) -> Vec<u8> {
+        let mut prefix = Vec::new();
+        let mut node = 0u32;
+        loop {
+            if self.arena[node as usize].terminal_count > 0 || self.arena[node as usize].children.len() != 1 {
+                break;
+            }
+            let (&byte, &next) = self.arena[node as usize].children.iter().next().unwrap();
+            prefix.push(byte);
+            node = next;
+        }
+        prefix
+    }
+
+    fn find(&self, prefix: &[u8]) -> Option<u32> {
+        let mut node = 0u32;
+        for &byte in prefix {
+            node = *self.arena[node as usize].children.get(&byte)?;
+        }
+        Some(node)
+    }
+
+    fn path_to(&self, word: &[u8]) -> Option<Vec<u32>> {
+        let mut node = 0u32;
+        let mut path = vec![0u32];
+        for &byte in word {
+            node = *self.arena[node as usize].children.get(&byte)?;
+            path.push(node);
+        }
+        Some(path)
+    }
+
+    fn collect_words(&self, node: u32, buffer: &mut Vec<u8>, results: &mut Vec<Vec<u8>>) {
+        if self.arena[node as usize].terminal_count > 0 {
+            results.push(buffer.clone());
+        }
+        for (&byte, &child) in &self.arena[node as usize].children {
+            buffer.push(byte);
+            self.collect_words(child, buffer, results);
+            buffer.pop();
+        }
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut trie = Trie::new();
+        trie.insert(b"cat");
+        trie.insert(b"car");
+        trie.insert(b"cart");
+        assert!(trie.contains(b"cat"));
+        assert!(trie.contains(b"cart"));
+        assert!(!trie.contains(b"ca"));
+        assert!(!trie.contains(b"dog"));
+    }
+
+    #[test]
+    fn erase_removes_one_occurrence_without_tombstones() {
+        let mut trie = Trie::new();
+        trie.insert(b"cat");
+        trie.insert(b"cat");
+        trie.insert(b"car");
+
+        assert!(trie.erase(b"cat"));
+        assert!(trie.contains(b"cat"));
+        assert_eq!(trie.count_prefix(b"ca"), 2);
+
+        assert!(trie.erase(b"cat"));
+        assert!(!trie.contains(b"cat"));
+        assert!(trie.contains(b"car"));
+        assert_eq!(trie.count_prefix(b"ca"), 1);
+
+        assert!(!trie.erase(b"cat"));
+        assert!(!trie.erase(b"dog"));
+    }
+
+    #[test]
+    fn count_prefix_counts_duplicates() {
+        let mut trie = Trie::new();
+        for word in [&b"car"[..], b"cart", b"cart", b"care", b"dog"] {
+            trie.insert(word);
+        }
+        assert_eq!(trie.count_prefix(b"car"), 4);
+        assert_eq!(trie.count_prefix(b"ca"), 4);
+        assert_eq!(trie.count_prefix(b"d"), 1);
+        assert_eq!(trie.count_prefix(b"z"), 0);
+    }
+
+    #[test]
+    fn words_with_prefix_lists_every_distinct_word_in_order() {
+        let mut trie = Trie::new();
+        for word in [&b"car"[..], b"cart", b"care", b"cat", b"dog"] {
+            trie.insert(word);
+        }
+        assert_eq!(
+            trie.words_with_prefix(b"ca"),
+            vec![b"car".to_vec(), b"care".to_vec(), b"cart".to_vec(), b"cat".to_vec()]
+        );
+        assert!(trie.words_with_prefix(b"z").is_empty());
+    }
+
+    #[test]
+    fn longest_common_prefix_stops_at_the_first_branch_or_word_end() {
+        let mut trie = Trie::new();
+        assert_eq!(trie.longest_common_prefix(), Vec::<u8>::new());
+
+        trie.insert(b"flower");
+        assert_eq!(trie.longest_common_prefix(), b"flower".to_vec());
+
+        trie.insert(b"flow");
+        assert_eq!(trie.longest_common_prefix(), b"flow".to_vec());
+
+        trie.insert(b"flight");
+        assert_eq!(trie.longest_common_prefix(), b"fl".to_vec());
+    }
+}
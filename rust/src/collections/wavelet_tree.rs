@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+
+/// One value-range split of a [`WaveletTree`]. Unlike [`MergeSortTree`],
+/// a node here never stores the values that pass through it — only
+/// `left_count`, the prefix count of "went left" decisions — so
+/// descending a query still needs the original array's structure, but
+/// the tree itself stays close to succinct (one `u32` per element per
+/// level instead of one `i64`).
+///
+/// [`MergeSortTree`]: super::merge_sort_tree::MergeSortTree
+struct WaveletNode {
+    lo: i64,
+    hi: i64,
+    /// `left_count[i]` is the number of elements among this node's first
+    /// `i` positions that fell in the lower half (`<= mid`) and recursed
+    /// into `left`; the rest recursed into `right`.
+    left_count: Vec<u32>,
+    left: u32,
+    right: u32,
+}
+
+/// Static (build-once) wavelet tree: recursively splits the value range
+/// in half (not the index range, the way a [`SegmentTree`] does), so
+/// "how many elements `<= x`" and "`k`-th smallest" in an index range
+/// both resolve by following the same O(log(max - min)) root-to-leaf
+/// path, updating the query's `[l, r)` window at each level via
+/// `left_count`.
+///
+/// [`SegmentTree`]: super::segment_tree::SegmentTree
+pub struct WaveletTree {
+    nodes: Vec<WaveletNode>,
+    root: u32,
+}
+
+impl WaveletTree {
+    pub fn build(values: &[i64]) -> Self {
+        let mut tree = WaveletTree { nodes: Vec::new(), root: NULL };
+        if values.is_empty() {
+            return tree;
+        }
+        let lo = *values.iter().min().expect("checked non-empty above");
+        let hi = *values.iter().max().expect("checked non-empty above");
+        tree.root = tree.build_node(values, lo, hi);
+        tree
+    }
+
+    fn build_node(&mut self, values: &[i64], lo: i64, hi: i64) -> u32 {
+        let mut left_count = Vec::with_capacity(values.len() + 1);
+        left_count.push(0);
+
+        if lo == hi {
+            for _ in values {
+                left_count.push(*left_count.last().expect("just pushed 0 above"));
+            }
+            let index = self.nodes.len() as u32;
+            self.nodes.push(WaveletNode { lo, hi, left_count, left: NULL, right: NULL });
+            return index;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let mut left_values = Vec::new();
+        let mut right_values = Vec::new();
+        for &v in values {
+            let previous = *left_count.last().expect("just pushed 0 above");
+            if v <= mid {
+                left_values.push(v);
+                left_count.push(previous + 1);
+            } else {
+                right_values.push(v);
+                left_count.push(previous);
+            }
+        }
+
+        let left = if left_values.is_empty() { NULL } else { self.build_node(&left_values, lo, mid) };
+        let right = if right_values.is_empty() { NULL } else { self.build_node(&right_values, mid + 1, hi) };
+        let index = self.nodes.len() as u32;
+        self.nodes.push(WaveletNode { lo, hi, left_count, left, right });
+        index
+    }
+
+    /// Count of elements `<= x` within the half-open range `[l, r)`.
+    pub fn count_le(&self, l: usize, r: usize, x: i64) -> usize {
+        if self.root == NULL {
+            return 0;
+        }
+        self.count_le_node(self.root, l, r, x)
+    }
+
+    fn count_le_node(&self, node_index: u32, l: usize, r: usize, x: i64) -> usize {
+        if l >= r {
+            return 0;
+        }
+        let node = &self.nodes[node_index as usize];
+        if node.hi <= x {
+            return r - l;
+        }
+        if node.lo > x {
+            return 0;
+        }
+
+        let left_l = node.left_count[l] as usize;
+        let left_r = node.left_count[r] as usize;
+        let mut count = self.count_le_node(node.left, left_l, left_r, x);
+        count += self.count_le_node(node.right, l - left_l, r - left_r, x);
+        count
+    }
+
+    /// The `k`-th smallest element (0-indexed) within the half-open range
+    /// `[l, r)`: descends toward whichever half contains rank `k`,
+    /// translating the `[l, r)` window into that half's own index space
+    /// via `left_count` at every level.
+    pub fn kth_smallest(&self, l: usize, r: usize, k: usize) -> i64 {
+        self.kth_smallest_node(self.root, l, r, k)
+    }
+
+    fn kth_smallest_node(&self, node_index: u32, l: usize, r: usize, k: usize) -> i64 {
+        let node = &self.nodes[node_index as usize];
+        if node.lo == node.hi {
+            return node.lo;
+        }
+
+        let left_l = node.left_count[l] as usize;
+        let left_r = node.left_count[r] as usize;
+        let left_size = left_r - left_l;
+        if k < left_size {
+            self.kth_smallest_node(node.left, left_l, left_r, k)
+        } else {
+            self.kth_smallest_node(node.right, l - left_l, r - left_r, k - left_size)
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Disjoint-set union augmented with a *potential* per element: besides
+/// "are `u` and `v` in the same set", it tracks the relative weight
+/// `value[v] - value[u]` implied by every [`union`](Self::union) call,
+/// the structure behind difference-constraint and parity problems
+/// ("A owes B 3 more than C", "X and Y are in different groups" encoded
+/// as a weight of 1 mod 2) without ever materializing actual `value`s.
+///
+/// `potential[x]` is `value[x] - value[parent[x]]`; [`find`](Self::find)
+/// compresses paths the same way [`Dsu`] does, folding the potentials
+/// along the way so it ends up holding `value[x] - value[root]`
+/// directly.
+///
+/// [`Dsu`]: super::dsu::Dsu
+pub struct WeightedDsu {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    potential: Vec<i64>,
+}
+
+impl WeightedDsu {
+    pub fn new(n: usize) -> Self {
+        WeightedDsu { parent: (0..n).collect(), rank: vec![0; n], potential: vec![0; n] }
+    }
+
+    /// The representative of `x`'s set, and `value[x] - value[root]`
+    /// under whatever constraints have been [`union`](Self::union)-ed so
+    /// far.
+    pub fn find(&mut self, x: usize) -> (usize, i64) {
+        if self.parent[x] == x {
+            return (x, 0);
+        }
+        let (root, parent_potential) = self.find(self.parent[x]);
+        self.potential[x] += parent_potential;
+        self.parent[x] = root;
+        (root, self.potential[x])
+    }
+
+    /// Enforces `value[v] - value[u] == w`. Returns `false` (leaving the
+    /// structure unchanged) if `u` and `v` were already related by a
+    /// constraint that contradicts this one.
+    pub fn union(&mut self, u: usize, v: usize, w: i64) -> bool {
+        let (ru, pu) = self.find(u);
+        let (rv, pv) = self.find(v);
+        if ru == rv {
+            return pv - pu == w;
+        }
+
+        // value[rv] - value[ru] must end up at `w + pu - pv`, derived
+        // from `value[v] - value[u] = w` with `value[u] = value[ru] + pu`
+        // and `value[v] = value[rv] + pv`.
+        if self.rank[ru] < self.rank[rv] {
+            self.parent[ru] = rv;
+            self.potential[ru] = pv - pu - w;
+        } else {
+            self.parent[rv] = ru;
+            self.potential[rv] = w + pu - pv;
+            if self.rank[ru] == self.rank[rv] {
+                self.rank[ru] += 1;
+            }
+        }
+        true
+    }
+
+    pub fn connected(&mut self, u: usize, v: usize) -> bool {
+        self.find(u).0 == self.find(v).0
+    }
+
+    /// `value[v] - value[u]`, if `u` and `v` are related by some chain
+    /// of constraints, or `None` if they aren't (in which case their
+    /// relative weight is unconstrained).
+    pub fn diff(&mut self, u: usize, v: usize) -> Option<i64> {
+        let (ru, pu) = self.find(u);
+        let (rv, pv) = self.find(v);
+        if ru != rv {
+            return None;
+        }
+        Some(pv - pu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chained_constraints_imply_the_transitive_weight() {
+        let mut dsu = WeightedDsu::new(3);
+        assert!(dsu.union(0, 1, 3)); // value[1] = value[0] + 3
+        assert!(dsu.union(1, 2, 4)); // value[2] = value[1] + 4
+        assert_eq!(dsu.diff(0, 2), Some(7));
+    }
+
+    #[test]
+    fn a_contradictory_constraint_is_rejected() {
+        let mut dsu = WeightedDsu::new(3);
+        assert!(dsu.union(0, 1, 3));
+        assert!(dsu.union(1, 2, 4));
+        assert!(!dsu.union(0, 2, 8)); // implied weight is 7, not 8
+        assert_eq!(dsu.diff(0, 2), Some(7)); // left untouched
+    }
+
+    #[test]
+    fn a_repeated_consistent_constraint_is_accepted() {
+        let mut dsu = WeightedDsu::new(2);
+        assert!(dsu.union(0, 1, 5));
+        assert!(dsu.union(0, 1, 5));
+        assert!(dsu.union(1, 0, -5));
+    }
+
+    #[test]
+    fn unconnected_elements_have_no_defined_difference() {
+        let mut dsu = WeightedDsu::new(2);
+        assert_eq!(dsu.diff(0, 1), None);
+        assert!(!dsu.connected(0, 1));
+    }
+}
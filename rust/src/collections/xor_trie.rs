@@ -0,0 +1,232 @@
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+const BITS: u32 = 64;
+
+/// One arena slot: `children[b]` is the next node after bit `b` (or
+/// [`NULL`]), and `count` is how many inserted keys share this node's
+/// bit-prefix — tracked the same way [`Trie`](crate::collections::trie::Trie)
+/// tracks `passing`, so `erase` can decrement its way back to "not
+/// present" without leaving tombstones, and so [`count_less_than_xor`]
+/// can read off a whole subtree's size in O(1) instead of walking it.
+struct Node {
+    children: [u32; 2],
+    count: u32,
+}
+
+impl Node {
+    fn new() -> Self {
+        Node { children: [NULL, NULL], count: 0 }
+    }
+}
+
+/// A bitwise trie over 64-bit keys (callers working with `u32` keys can
+/// just widen with `as u64`), walked from the most significant bit
+/// down. Every key takes a full `BITS`-deep path, branching on one bit
+/// at a time, which is what makes [`max_xor_with`](Self::max_xor_with)
+/// and [`count_less_than_xor`](Self::count_less_than_xor) — the two
+/// queries behind "maximum XOR pair" and "count pairs with XOR less
+/// than k" problems — a single O(BITS) greedy walk each instead of an
+/// O(n) scan.
+pub struct XorTrie {
+    arena: Vec<Node>,
+    root: u32,
+}
+
+impl XorTrie {
+    pub fn new() -> Self {
+        XorTrie { arena: alloc::vec![Node::new()], root: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.count_at(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts `key`. Duplicates are kept (this is a multiset).
+    pub fn insert(&mut self, key: u64) {
+        let mut node = self.root;
+        self.arena[node as usize].count += 1;
+        for i in (0..BITS).rev() {
+            let bit = ((key >> i) & 1) as usize;
+            node = self.ensure_child(node, bit);
+            self.arena[node as usize].count += 1;
+        }
+    }
+
+    /// Removes one occurrence of `key`. Returns `false` if it wasn't
+    /// present.
+    pub fn erase(&mut self, key: u64) -> bool {
+        let Some(path) = self.path_to(key) else { return false };
+        if self.arena[*path.last().unwrap() as usize].count == 0 {
+            return false;
+        }
+        for node in path {
+            self.arena[node as usize].count -= 1;
+        }
+        true
+    }
+
+    pub fn contains(&self, key: u64) -> bool {
+        match self.path_to(key) {
+            Some(path) => self.arena[*path.last().unwrap() as usize].count > 0,
+            None => false,
+        }
+    }
+
+    /// The stored key that maximizes `key ^ x`, or `None` if empty.
+    /// Greedily prefers, at every bit, the child whose bit disagrees
+    /// with `x`'s — the classic "maximum XOR" trie walk.
+    pub fn max_xor_with(&self, x: u64) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut node = self.root;
+        let mut result = 0u64;
+        for i in (0..BITS).rev() {
+            let x_bit = ((x >> i) & 1) as usize;
+            let opposite = 1 - x_bit;
+            let opposite_child = self.arena[node as usize].children[opposite];
+            if opposite_child != NULL && self.arena[opposite_child as usize].count > 0 {
+                result |= 1u64 << i;
+                node = opposite_child;
+            } else {
+                node = self.arena[node as usize].children[x_bit];
+            }
+        }
+        Some(result)
+    }
+
+    /// Count of stored keys `y` (with multiplicity) such that
+    /// `x ^ y < k`. At each bit where `k` is `1`, every key agreeing
+    /// with `x` on that bit (so its XOR bit here is `0`, making the
+    /// running XOR value strictly smaller regardless of the remaining
+    /// bits) counts in full; the walk only has to keep descending
+    /// through keys that still tie `k`'s prefix exactly.
+    pub fn count_less_than_xor(&self, x: u64, k: u64) -> usize {
+        let mut node = self.root;
+        let mut total = 0usize;
+        for i in (0..BITS).rev() {
+            if node == NULL {
+                break;
+            }
+            let x_bit = ((x >> i) & 1) as usize;
+            let k_bit = (k >> i) & 1;
+            let same_bit_child = self.arena[node as usize].children[x_bit];
+            let diff_bit_child = self.arena[node as usize].children[1 - x_bit];
+            if k_bit == 1 {
+                total += self.count_at(same_bit_child);
+                node = diff_bit_child;
+            } else {
+                node = same_bit_child;
+            }
+        }
+        total
+    }
+
+    fn count_at(&self, node: u32) -> usize {
+        if node == NULL {
+            0
+        } else {
+            self.arena[node as usize].count as usize
+        }
+    }
+
+    fn ensure_child(&mut self, node: u32, bit: usize) -> u32 {
+        if self.arena[node as usize].children[bit] == NULL {
+            self.arena.push(Node::new());
+            let new_index = (self.arena.len() - 1) as u32;
+            self.arena[node as usize].children[bit] = new_index;
+        }
+        self.arena[node as usize].children[bit]
+    }
+
+    fn path_to(&self, key: u64) -> Option<Vec<u32>> {
+        let mut node = self.root;
+        let mut path = alloc::vec![node];
+        for i in (0..BITS).rev() {
+            let bit = ((key >> i) & 1) as usize;
+            node = self.arena[node as usize].children[bit];
+            if node == NULL {
+                return None;
+            }
+            path.push(node);
+        }
+        Some(path)
+    }
+}
+
+impl Default for XorTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut trie = XorTrie::new();
+        for key in [3u64, 10, 5, 25, 2, 8] {
+            trie.insert(key);
+        }
+        assert_eq!(trie.len(), 6);
+        for key in [3u64, 10, 5, 25, 2, 8] {
+            assert!(trie.contains(key));
+        }
+        assert!(!trie.contains(100));
+    }
+
+    #[test]
+    fn erase_removes_one_occurrence() {
+        let mut trie = XorTrie::new();
+        trie.insert(7);
+        trie.insert(7);
+        trie.insert(9);
+        assert!(trie.erase(7));
+        assert_eq!(trie.len(), 2);
+        assert!(trie.contains(7));
+        assert!(trie.erase(7));
+        assert!(!trie.contains(7));
+        assert!(!trie.erase(7));
+    }
+
+    #[test]
+    fn max_xor_with_matches_brute_force() {
+        let keys = [3u64, 10, 5, 25, 2, 8];
+        let mut trie = XorTrie::new();
+        for &key in &keys {
+            trie.insert(key);
+        }
+        for x in 0..32u64 {
+            let expected = keys.iter().map(|&y| x ^ y).max();
+            assert_eq!(trie.max_xor_with(x), expected);
+        }
+    }
+
+    #[test]
+    fn max_xor_with_is_none_when_empty() {
+        let trie = XorTrie::new();
+        assert_eq!(trie.max_xor_with(42), None);
+    }
+
+    #[test]
+    fn count_less_than_xor_matches_brute_force() {
+        let keys = [3u64, 10, 5, 25, 2, 8, 17, 0];
+        let mut trie = XorTrie::new();
+        for &key in &keys {
+            trie.insert(key);
+        }
+        for x in 0..32u64 {
+            for k in 0..32u64 {
+                let expected = keys.iter().filter(|&&y| (x ^ y) < k).count();
+                assert_eq!(trie.count_less_than_xor(x, k), expected);
+            }
+        }
+    }
+}
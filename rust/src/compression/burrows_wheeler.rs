@@ -0,0 +1,205 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::string::suffix_array::suffix_array;
+
+/// Burrows-Wheeler transform of `s`: the last column of the matrix of
+/// every rotation of `s` (plus a unique `0x00` terminator), sorted
+/// lexicographically. Computed via the suffix array of `s` with the
+/// terminator appended, rather than sorting rotations directly, since a
+/// string's sorted rotations (with a unique minimal terminator) and its
+/// sorted suffixes coincide.
+///
+/// `s` must not itself contain a `0x00` byte, since that's reserved as
+/// the terminator — the usual simplification that avoids the extra
+/// bookkeeping a sentinel-free BWT needs to stay invertible.
+pub fn bwt(s: &[u8]) -> Vec<u8> {
+    assert!(!s.contains(&0), "bwt: input must not contain a 0x00 byte (reserved as the terminator)");
+
+    let mut terminated = s.to_vec();
+    terminated.push(0);
+    let sa = suffix_array(&terminated);
+    let m = terminated.len();
+    sa.iter().map(|&suffix_start| terminated[(suffix_start + m - 1) % m]).collect()
+}
+
+/// Inverts [`bwt`], recovering the original string (the terminator is
+/// stripped from the result).
+///
+/// `l` is the last column of the sorted-rotations matrix; `f = sorted(l)`
+/// is its first column (same multiset of bytes, sorted). For row `r`,
+/// `f[r]` is the character at the rotation's start position and `l[r]` is
+/// the character immediately *before* it, circularly — so the row whose
+/// `f` entry is that same character instance is one step further back in
+/// the original string. That row is found via the standard LF-mapping,
+/// `next[r] = base[l[r]] + (occurrences of l[r] among l[..r])`, where
+/// `base[c]` counts bytes less than `c`. Starting at row 0 (whose `f`
+/// entry is the terminator, globally smallest) and chasing `next` for `m`
+/// steps therefore walks the original string *backwards*; reversing that
+/// and dropping the terminator (now trailing) recovers it forwards.
+pub fn inverse_bwt(l: &[u8]) -> Vec<u8> {
+    let m = l.len();
+    if m == 0 {
+        return Vec::new();
+    }
+
+    let mut counts = [0usize; 256];
+    for &byte in l {
+        counts[byte as usize] += 1;
+    }
+    let mut base = [0usize; 256];
+    let mut running = 0usize;
+    for (byte, &count) in counts.iter().enumerate() {
+        base[byte] = running;
+        running += count;
+    }
+
+    let mut seen = [0usize; 256];
+    let mut next = vec![0usize; m];
+    for (row, &byte) in l.iter().enumerate() {
+        next[row] = base[byte as usize] + seen[byte as usize];
+        seen[byte as usize] += 1;
+    }
+
+    let mut sorted_l = l.to_vec();
+    sorted_l.sort_unstable();
+
+    let mut row = 0;
+    let mut result = Vec::with_capacity(m);
+    for _ in 0..m {
+        result.push(sorted_l[row]);
+        row = next[row];
+    }
+    result.reverse();
+    result.pop();
+    result
+}
+
+/// Move-to-front encoding: replaces each byte with its current position
+/// in a 256-entry "recency list", then moves it to the front of that
+/// list. Turns the long runs of repeated bytes a BWT output tends to
+/// produce into long runs of zeros, which run-length encoding then
+/// shrinks further.
+pub fn move_to_front_encode(bytes: &[u8]) -> Vec<u8> {
+    let mut table: Vec<u8> = (0..=255).collect();
+    let mut out = Vec::with_capacity(bytes.len());
+    for &byte in bytes {
+        let pos = table.iter().position(|&b| b == byte).unwrap();
+        out.push(pos as u8);
+        table.remove(pos);
+        table.insert(0, byte);
+    }
+    out
+}
+
+/// Inverts [`move_to_front_encode`].
+pub fn move_to_front_decode(codes: &[u8]) -> Vec<u8> {
+    let mut table: Vec<u8> = (0..=255).collect();
+    let mut out = Vec::with_capacity(codes.len());
+    for &code in codes {
+        let byte = table.remove(code as usize);
+        out.push(byte);
+        table.insert(0, byte);
+    }
+    out
+}
+
+/// One run in a run-length encoding: `count` consecutive copies of
+/// `byte` (`count >= 1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub byte: u8,
+    pub count: u32,
+}
+
+/// Run-length encodes `bytes` into maximal runs of equal bytes.
+pub fn rle_encode(bytes: &[u8]) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    for &byte in bytes {
+        match runs.last_mut() {
+            Some(run) if run.byte == byte && run.count < u32::MAX => run.count += 1,
+            _ => runs.push(Run { byte, count: 1 }),
+        }
+    }
+    runs
+}
+
+/// Inverts [`rle_encode`].
+pub fn rle_decode(runs: &[Run]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(runs.iter().map(|r| r.count as usize).sum());
+    for run in runs {
+        bytes.extend(core::iter::repeat_n(run.byte, run.count as usize));
+    }
+    bytes
+}
+
+/// The full teachable compression chain: BWT groups similar bytes
+/// together, move-to-front turns that locality into runs of small
+/// numbers (mostly zero), and RLE collapses those runs. Requires `s` not
+/// contain a `0x00` byte, the same precondition as [`bwt`].
+pub fn compress(s: &[u8]) -> Vec<Run> {
+    rle_encode(&move_to_front_encode(&bwt(s)))
+}
+
+/// Inverts [`compress`].
+pub fn decompress(runs: &[Run]) -> Vec<u8> {
+    inverse_bwt(&move_to_front_decode(&rle_decode(runs)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bwt_matches_known_example() {
+        // The textbook "banana" example (using `^` in place of the usual
+        // `$` as the terminator, since ours is a literal 0x00 byte).
+        let transformed = bwt(b"banana");
+        assert_eq!(transformed, b"annb\0aa");
+    }
+
+    #[test]
+    fn inverse_bwt_round_trips_on_random_short_strings() {
+        let alphabet = b"abc";
+        for len in 0..8 {
+            for mask in 0..(3u32.pow(len)) {
+                let mut m = mask;
+                let s: Vec<u8> = (0..len)
+                    .map(|_| {
+                        let byte = alphabet[(m % 3) as usize];
+                        m /= 3;
+                        byte
+                    })
+                    .collect();
+                assert_eq!(inverse_bwt(&bwt(&s)), s, "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn move_to_front_round_trips() {
+        for bytes in [&b""[..], b"aaaaabbbbbccccc", b"abcabcabc", b"\0\x01\xff\x01\0"] {
+            assert_eq!(move_to_front_decode(&move_to_front_encode(bytes)), bytes);
+        }
+    }
+
+    #[test]
+    fn rle_round_trips_and_compresses_runs() {
+        let bytes = b"aaaaabbbccccccccd";
+        let runs = rle_encode(bytes);
+        assert!(runs.len() < bytes.len());
+        assert_eq!(rle_decode(&runs), bytes);
+    }
+
+    #[test]
+    fn full_pipeline_round_trips_on_repetitive_text() {
+        let s = b"the quick brown fox jumps over the lazy dog the quick brown fox";
+        assert_eq!(decompress(&compress(s)), s);
+    }
+
+    #[test]
+    #[should_panic(expected = "0x00 byte")]
+    fn bwt_rejects_input_containing_the_terminator_byte() {
+        bwt(b"has\0a null byte");
+    }
+}
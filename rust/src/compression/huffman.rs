@@ -0,0 +1,199 @@
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+// Builds the Huffman tree with the same min-heap merge as
+// `greedy::optimal_merge` (k = 2): repeatedly combine the two least
+// frequent nodes, which the exchange argument shows never costs more
+// than any other merge order.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Node {
+    Leaf(u8),
+    Branch(alloc::boxed::Box<Node>, alloc::boxed::Box<Node>),
+}
+
+/// A code table assigning each byte a bit length and a canonical code:
+/// among all codes of that length, ordered the same way as the bytes
+/// they encode were ordered (first by code length, then by byte value).
+/// Canonical codes need only be transmitted as a list of (byte, length)
+/// pairs — the decoder can reconstruct the actual bit patterns itself —
+/// which is why real formats (DEFLATE, JPEG) use them instead of
+/// shipping the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalCode {
+    pub length: u8,
+    pub code: u32,
+}
+
+/// Builds a canonical Huffman code table from byte frequencies.
+///
+/// Returns `None` for empty input. A single distinct byte is assigned
+/// code length 1 (there's nothing to distinguish it from, but a decoder
+/// still needs at least one bit per symbol to know how many it saw).
+pub fn build_canonical_codes(frequencies: &BTreeMap<u8, u64>) -> Option<BTreeMap<u8, CanonicalCode>> {
+    if frequencies.is_empty() {
+        return None;
+    }
+
+    let mut lengths = code_lengths(frequencies);
+    if lengths.len() == 1 {
+        let (&byte, _) = lengths.iter().next().unwrap();
+        lengths = BTreeMap::from([(byte, 1u8)]);
+    }
+
+    // Canonical assignment: sort by (length, byte), then walk assigning
+    // consecutive codes, left-shifting by one bit whenever length grows
+    // (padding the code with trailing zero bits for the new, longer
+    // symbols, which is what keeps the codes prefix-free).
+    let mut by_length: Vec<(u8, u8)> = lengths.iter().map(|(&byte, &len)| (len, byte)).collect();
+    by_length.sort_unstable();
+
+    let mut table = BTreeMap::new();
+    let mut code = 0u32;
+    let mut previous_length = by_length[0].0;
+    for (length, byte) in by_length {
+        code <<= length - previous_length;
+        table.insert(byte, CanonicalCode { length, code });
+        code += 1;
+        previous_length = length;
+    }
+    Some(table)
+}
+
+/// Runs Huffman's algorithm to find each byte's optimal code length,
+/// without committing to specific bit patterns yet (those come from
+/// [`build_canonical_codes`]'s canonical assignment instead).
+fn code_lengths(frequencies: &BTreeMap<u8, u64>) -> BTreeMap<u8, u8> {
+    let mut heap: BinaryHeap<Reverse<(u64, usize, Node)>> = frequencies
+        .iter()
+        .enumerate()
+        .map(|(order, (&byte, &freq))| Reverse((freq, order, Node::Leaf(byte))))
+        .collect();
+    let mut next_order = heap.len();
+
+    while heap.len() > 1 {
+        let Reverse((freq_a, _, a)) = heap.pop().unwrap();
+        let Reverse((freq_b, _, b)) = heap.pop().unwrap();
+        let merged = Node::Branch(alloc::boxed::Box::new(a), alloc::boxed::Box::new(b));
+        heap.push(Reverse((freq_a + freq_b, next_order, merged)));
+        next_order += 1;
+    }
+
+    let mut lengths = BTreeMap::new();
+    if let Some(Reverse((_, _, root))) = heap.pop() {
+        assign_lengths(&root, 0, &mut lengths);
+    }
+    lengths
+}
+
+fn assign_lengths(node: &Node, depth: u8, lengths: &mut BTreeMap<u8, u8>) {
+    match node {
+        Node::Leaf(byte) => {
+            lengths.insert(*byte, depth.max(1));
+        }
+        Node::Branch(left, right) => {
+            assign_lengths(left, depth + 1, lengths);
+            assign_lengths(right, depth + 1, lengths);
+        }
+    }
+}
+
+/// Encodes `bytes` against a canonical code table built by
+/// [`build_canonical_codes`] from its own frequencies, returning the bit
+/// stream (as one `bool` per bit, MSB first per symbol) and the table
+/// needed to decode it. Returns `None` for empty input.
+pub fn encode(bytes: &[u8]) -> Option<(Vec<bool>, BTreeMap<u8, CanonicalCode>)> {
+    let mut frequencies = BTreeMap::new();
+    for &byte in bytes {
+        *frequencies.entry(byte).or_insert(0u64) += 1;
+    }
+    let table = build_canonical_codes(&frequencies)?;
+
+    let mut bits = Vec::new();
+    for &byte in bytes {
+        let entry = &table[&byte];
+        for shift in (0..entry.length).rev() {
+            bits.push((entry.code >> shift) & 1 == 1);
+        }
+    }
+    Some((bits, table))
+}
+
+/// Inverts [`encode`], given the bit stream and the code table it was
+/// encoded with.
+pub fn decode(bits: &[bool], table: &BTreeMap<u8, CanonicalCode>) -> Vec<u8> {
+    let mut by_code: BTreeMap<(u8, u32), u8> = BTreeMap::new();
+    for (&byte, entry) in table {
+        by_code.insert((entry.length, entry.code), byte);
+    }
+
+    let mut out = Vec::new();
+    let mut length = 0u8;
+    let mut code = 0u32;
+    for &bit in bits {
+        length += 1;
+        code = (code << 1) | bit as u32;
+        if let Some(&byte) = by_code.get(&(length, code)) {
+            out.push(byte);
+            length = 0;
+            code = 0;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_arbitrary_byte_input() {
+        for bytes in [&b""[..], b"a", b"aaaa", b"abracadabra", b"\0\x01\xff\xff\x01\0the quick brown fox"] {
+            if bytes.is_empty() {
+                assert!(encode(bytes).is_none());
+                continue;
+            }
+            let (bits, table) = encode(bytes).unwrap();
+            assert_eq!(decode(&bits, &table), bytes);
+        }
+    }
+
+    #[test]
+    fn codes_are_shorter_for_more_frequent_bytes() {
+        let bytes = b"aaaaaaaaaabbbbbc";
+        let (_, table) = encode(bytes).unwrap();
+        assert!(table[&b'a'].length <= table[&b'b'].length);
+        assert!(table[&b'b'].length <= table[&b'c'].length);
+    }
+
+    #[test]
+    fn compresses_skewed_frequencies() {
+        let bytes = alloc::vec![b'a'; 100];
+        let (bits, _) = encode(&bytes).unwrap();
+        assert!(bits.len() < bytes.len() * 8);
+    }
+
+    #[test]
+    fn canonical_codes_are_prefix_free() {
+        let bytes = b"the quick brown fox jumps over the lazy dog";
+        let (_, table) = encode(bytes).unwrap();
+        let codes: Vec<&CanonicalCode> = table.values().collect();
+        for (i, a) in codes.iter().enumerate() {
+            for b in &codes[i + 1..] {
+                let shorter = a.length.min(b.length);
+                let a_prefix = a.code >> (a.length - shorter);
+                let b_prefix = b.code >> (b.length - shorter);
+                assert_ne!(a_prefix, b_prefix, "codes not prefix-free: {a:?} vs {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn single_distinct_byte_still_round_trips() {
+        let bytes = alloc::vec![b'z'; 5];
+        let (bits, table) = encode(&bytes).unwrap();
+        assert_eq!(table.len(), 1);
+        assert_eq!(bits.len(), bytes.len());
+        assert_eq!(decode(&bits, &table), bytes);
+    }
+}
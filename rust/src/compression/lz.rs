@@ -0,0 +1,182 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// One LZ77 token: either a literal byte, or a back-reference copying
+/// `length` bytes starting `distance` bytes before the current output
+/// position (`distance >= 1`, `length >= 1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lz77Token {
+    Literal(u8),
+    Match { distance: u32, length: u32 },
+}
+
+/// Compresses `bytes` with a sliding-window LZ77: at each position, finds
+/// the longest match against the preceding `window_size` bytes (a plain
+/// O(n * window_size) scan — good enough to demonstrate the algorithm;
+/// real codecs use a hash chain to find that match in O(1) amortized),
+/// and emits a back-reference when it beats emitting a literal.
+pub fn lz77_encode(bytes: &[u8], window_size: usize) -> Vec<Lz77Token> {
+    let n = bytes.len();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < n {
+        let window_start = pos.saturating_sub(window_size);
+        let mut best_len = 0;
+        let mut best_distance = 0;
+        for start in window_start..pos {
+            let mut len = 0;
+            while pos + len < n && bytes[start + len] == bytes[pos + len] {
+                len += 1;
+            }
+            if len > best_len {
+                best_len = len;
+                best_distance = pos - start;
+            }
+        }
+        if best_len >= 2 {
+            tokens.push(Lz77Token::Match { distance: best_distance as u32, length: best_len as u32 });
+            pos += best_len;
+        } else {
+            tokens.push(Lz77Token::Literal(bytes[pos]));
+            pos += 1;
+        }
+    }
+    tokens
+}
+
+/// Inverts [`lz77_encode`].
+pub fn lz77_decode(tokens: &[Lz77Token]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for &token in tokens {
+        match token {
+            Lz77Token::Literal(byte) => out.push(byte),
+            Lz77Token::Match { distance, length } => {
+                let start = out.len() - distance as usize;
+                for i in 0..length as usize {
+                    out.push(out[start + i]);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Compresses `bytes` with LZW: starts from a dictionary of the 256
+/// single-byte strings, and on each step emits the code for the longest
+/// dictionary entry matching the input so far, then adds "that entry plus
+/// the next byte" as a new dictionary entry. Codes are emitted as
+/// `u32`s; a real container format would pack them into a bit stream,
+/// which is a separate, orthogonal concern from the algorithm itself.
+pub fn lzw_encode(bytes: &[u8]) -> Vec<u32> {
+    let mut dictionary: BTreeMap<Vec<u8>, u32> = (0u32..256).map(|b| (alloc::vec![b as u8], b)).collect();
+    let mut next_code = 256u32;
+
+    let mut out = Vec::new();
+    let mut current: Vec<u8> = Vec::new();
+    for &byte in bytes {
+        let mut extended = current.clone();
+        extended.push(byte);
+        if dictionary.contains_key(&extended) {
+            current = extended;
+        } else {
+            out.push(dictionary[&current]);
+            dictionary.insert(extended, next_code);
+            next_code += 1;
+            current = alloc::vec![byte];
+        }
+    }
+    if !current.is_empty() {
+        out.push(dictionary[&current]);
+    }
+    out
+}
+
+/// Inverts [`lzw_encode`].
+pub fn lzw_decode(codes: &[u32]) -> Vec<u8> {
+    let mut dictionary: Vec<Vec<u8>> = (0u32..256).map(|b| alloc::vec![b as u8]).collect();
+
+    let mut out = Vec::new();
+    let mut previous: Option<Vec<u8>> = None;
+    for &code in codes {
+        let mut entry = if (code as usize) < dictionary.len() {
+            dictionary[code as usize].clone()
+        } else {
+            // Exactly the one code the decoder can see before it's in the
+            // dictionary yet: the encoder just registered it as
+            // `previous + first-byte-of-previous`.
+            let mut entry = previous.clone().unwrap();
+            entry.push(entry[0]);
+            entry
+        };
+        out.extend_from_slice(&entry);
+
+        if let Some(prev) = previous {
+            let mut new_entry = prev;
+            new_entry.push(entry[0]);
+            dictionary.push(new_entry);
+        }
+        previous = Some(core::mem::take(&mut entry));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lz77_round_trips_on_repetitive_text() {
+        let bytes = b"abababababab the quick brown fox the quick brown fox";
+        assert_eq!(lz77_decode(&lz77_encode(bytes, 32)), bytes);
+    }
+
+    #[test]
+    fn lz77_uses_matches_to_shrink_highly_repetitive_input() {
+        let bytes = alloc::vec![b'a'; 100];
+        let tokens = lz77_encode(&bytes, 64);
+        assert!(tokens.len() < bytes.len());
+        assert_eq!(lz77_decode(&tokens), bytes);
+    }
+
+    #[test]
+    fn lz77_round_trips_on_random_short_strings() {
+        let alphabet = b"ab";
+        for len in 0..12 {
+            for mask in 0..(1u32 << len) {
+                let s: Vec<u8> = (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                assert_eq!(lz77_decode(&lz77_encode(&s, 8)), s, "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn lzw_round_trips_on_repetitive_text() {
+        let bytes = b"TOBEORNOTTOBEORTOBEORNOT";
+        assert_eq!(lzw_decode(&lzw_encode(bytes)), bytes);
+    }
+
+    #[test]
+    fn lzw_round_trips_on_random_short_strings() {
+        let alphabet = b"abc";
+        for len in 0..8 {
+            for mask in 0..(3u32.pow(len)) {
+                let mut m = mask;
+                let s: Vec<u8> = (0..len)
+                    .map(|_| {
+                        let byte = alphabet[(m % 3) as usize];
+                        m /= 3;
+                        byte
+                    })
+                    .collect();
+                assert_eq!(lzw_decode(&lzw_encode(&s)), s, "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn lzw_shrinks_a_classic_repetitive_example() {
+        let bytes = alloc::vec![b'a'; 64];
+        let codes = lzw_encode(&bytes);
+        assert!(codes.len() < bytes.len());
+    }
+}
@@ -0,0 +1,5 @@
+//! Lossless compression algorithms.
+
+pub mod burrows_wheeler;
+pub mod huffman;
+pub mod lz;
@@ -0,0 +1,247 @@
+// 0/1, unbounded, and bounded knapsack, sharing the same `Item` type and
+// value-only DP row; the variants differ only in how they iterate
+// capacity (and, for bounded, how they preprocess items) around that
+// shared recurrence.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// One candidate item: `weight` capacity it consumes, `value` it's worth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub weight: u64,
+    pub value: u64,
+}
+
+/// Maximum achievable value packing `items` into `capacity`, taking each
+/// item at most once, plus the indices of the items chosen.
+///
+/// `dp[c]` is the best value achievable with capacity `c` using items
+/// considered so far; iterating capacity *downward* for each item (the
+/// standard 0/1 knapsack space optimization) ensures each item's own
+/// `dp[c - weight]` read is still from before this item was applied, so
+/// it's never taken twice. `choice[i][c]` separately records whether
+/// item `i` was taken at capacity `c`, needed to reconstruct which items
+/// were chosen (the single-row `dp` alone can't answer that after the
+/// fact).
+pub fn knapsack_01(items: &[Item], capacity: u64) -> (u64, Vec<usize>) {
+    let capacity = capacity as usize;
+    let mut dp = vec![0u64; capacity + 1];
+    let mut choice = vec![vec![false; capacity + 1]; items.len()];
+
+    for (i, item) in items.iter().enumerate() {
+        let weight = item.weight as usize;
+        for c in (weight..=capacity).rev() {
+            let candidate = dp[c - weight] + item.value;
+            if candidate > dp[c] {
+                dp[c] = candidate;
+                choice[i][c] = true;
+            }
+        }
+    }
+
+    let mut chosen = Vec::new();
+    let mut c = capacity;
+    for i in (0..items.len()).rev() {
+        if choice[i][c] {
+            chosen.push(i);
+            c -= items[i].weight as usize;
+        }
+    }
+    chosen.reverse();
+    (dp[capacity], chosen)
+}
+
+/// Same as [`knapsack_01`], but only the best achievable value — no item
+/// list is reconstructed, so the `choice` table (the dominant memory cost
+/// above) is never allocated.
+pub fn knapsack_01_value_only(items: &[Item], capacity: u64) -> u64 {
+    let capacity = capacity as usize;
+    let mut dp = vec![0u64; capacity + 1];
+    for item in items {
+        let weight = item.weight as usize;
+        for c in (weight..=capacity).rev() {
+            dp[c] = dp[c].max(dp[c - weight] + item.value);
+        }
+    }
+    dp[capacity]
+}
+
+/// Maximum achievable value packing `items` into `capacity`, taking each
+/// item any number of times (including zero).
+///
+/// Same recurrence as [`knapsack_01_value_only`], but iterating capacity
+/// *upward*: `dp[c - weight]` may already include this same item, which
+/// is exactly what allows reuse instead of each item being limited to
+/// one copy.
+pub fn knapsack_unbounded(items: &[Item], capacity: u64) -> u64 {
+    let capacity = capacity as usize;
+    let mut dp = vec![0u64; capacity + 1];
+    for item in items {
+        let weight = item.weight as usize;
+        if weight == 0 {
+            continue;
+        }
+        for c in weight..=capacity {
+            dp[c] = dp[c].max(dp[c - weight] + item.value);
+        }
+    }
+    dp[capacity]
+}
+
+/// Maximum achievable value packing `items` into `capacity`, taking item
+/// `i` at most `counts[i]` times.
+///
+/// Splits each item's allowed count into powers of two (binary
+/// splitting: `count = 1 + 2 + 4 + ... + remainder`), turning "this item
+/// up to `count` times" into a handful of 0/1 items whose subset sums
+/// cover exactly `0..=count` copies — then solves with the ordinary 0/1
+/// recurrence, for O(n log(max count) * capacity) instead of O(n * count
+/// * capacity).
+pub fn knapsack_bounded(items: &[Item], counts: &[u32], capacity: u64) -> u64 {
+    assert_eq!(items.len(), counts.len(), "items and counts must be the same length");
+
+    let mut split_items = Vec::new();
+    for (item, &count) in items.iter().zip(counts) {
+        let mut remaining = count;
+        let mut copies = 1u32;
+        while remaining > 0 {
+            let take = copies.min(remaining);
+            split_items.push(Item { weight: item.weight * take as u64, value: item.value * take as u64 });
+            remaining -= take;
+            copies *= 2;
+        }
+    }
+    knapsack_01_value_only(&split_items, capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(weight: u64, value: u64) -> Item {
+        Item { weight, value }
+    }
+
+    #[test]
+    fn matches_the_classic_example() {
+        // weights 1,3,4,5 values 1,4,5,7 capacity 7 -> best is {3,4} = 9.
+        let items = [item(1, 1), item(3, 4), item(4, 5), item(5, 7)];
+        let (value, chosen) = knapsack_01(&items, 7);
+        assert_eq!(value, 9);
+        let total_weight: u64 = chosen.iter().map(|&i| items[i].weight).sum();
+        assert!(total_weight <= 7);
+        assert_eq!(chosen.iter().map(|&i| items[i].value).sum::<u64>(), value);
+    }
+
+    #[test]
+    fn value_only_matches_reconstruction_value() {
+        let items = [item(2, 3), item(3, 4), item(4, 5), item(5, 6)];
+        for capacity in 0..=12 {
+            let (value, _) = knapsack_01(&items, capacity);
+            assert_eq!(knapsack_01_value_only(&items, capacity), value, "capacity={capacity}");
+        }
+    }
+
+    #[test]
+    fn zero_capacity_takes_nothing() {
+        let items = [item(1, 1), item(2, 2)];
+        let (value, chosen) = knapsack_01(&items, 0);
+        assert_eq!(value, 0);
+        assert!(chosen.is_empty());
+    }
+
+    #[test]
+    fn brute_force_matches_on_small_item_sets() {
+        let items = [item(2, 3), item(3, 5), item(4, 6), item(5, 8)];
+        for capacity in 0..=16u64 {
+            let mut best = 0u64;
+            for mask in 0..(1u32 << items.len()) {
+                let weight: u64 =
+                    (0..items.len()).filter(|&i| mask & (1 << i) != 0).map(|i| items[i].weight).sum();
+                if weight <= capacity {
+                    let value: u64 =
+                        (0..items.len()).filter(|&i| mask & (1 << i) != 0).map(|i| items[i].value).sum();
+                    best = best.max(value);
+                }
+            }
+            assert_eq!(knapsack_01_value_only(&items, capacity), best, "capacity={capacity}");
+        }
+    }
+
+    #[test]
+    fn unbounded_can_beat_01_by_reusing_items() {
+        let items = [item(3, 5)];
+        // capacity 9: unbounded takes three copies for 15; 0/1 can only
+        // take it once, for 5.
+        assert_eq!(knapsack_unbounded(&items, 9), 15);
+        assert_eq!(knapsack_01_value_only(&items, 9), 5);
+    }
+
+    #[test]
+    fn unbounded_matches_brute_force_over_small_item_sets() {
+        let items = [item(2, 3), item(3, 5)];
+        for capacity in 0..=12u64 {
+            let mut best = 0u64;
+            for c0 in 0..=capacity / items[0].weight {
+                for c1 in 0..=capacity / items[1].weight {
+                    let weight = c0 * items[0].weight + c1 * items[1].weight;
+                    if weight <= capacity {
+                        best = best.max(c0 * items[0].value + c1 * items[1].value);
+                    }
+                }
+            }
+            assert_eq!(knapsack_unbounded(&items, capacity), best, "capacity={capacity}");
+        }
+    }
+
+    #[test]
+    fn bounded_matches_brute_force_over_small_item_sets() {
+        let items = [item(2, 3), item(3, 5), item(4, 6)];
+        let counts = [2u32, 1, 3];
+        let capacity = 10u64;
+
+        let mut best = 0u64;
+        for c0 in 0..=counts[0] {
+            for c1 in 0..=counts[1] {
+                for c2 in 0..=counts[2] {
+                    let weight =
+                        c0 as u64 * items[0].weight + c1 as u64 * items[1].weight + c2 as u64 * items[2].weight;
+                    if weight <= capacity {
+                        let value =
+                            c0 as u64 * items[0].value + c1 as u64 * items[1].value + c2 as u64 * items[2].value;
+                        best = best.max(value);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(knapsack_bounded(&items, &counts, capacity), best);
+    }
+
+    #[test]
+    fn bounded_with_count_one_matches_01() {
+        let items = [item(2, 3), item(3, 4), item(4, 5), item(5, 6)];
+        let counts = [1u32; 4];
+        for capacity in 0..=14 {
+            assert_eq!(
+                knapsack_bounded(&items, &counts, capacity),
+                knapsack_01_value_only(&items, capacity),
+                "capacity={capacity}"
+            );
+        }
+    }
+
+    #[test]
+    fn bounded_with_large_counts_matches_unbounded() {
+        let items = [item(2, 3), item(3, 5)];
+        let counts = [1000u32, 1000];
+        for capacity in 0..=20u64 {
+            assert_eq!(
+                knapsack_bounded(&items, &counts, capacity),
+                knapsack_unbounded(&items, capacity),
+                "capacity={capacity}"
+            );
+        }
+    }
+}
@@ -0,0 +1,175 @@
+// Longest common subsequence, generic over any equality-comparable
+// element type (not just bytes), plus a linear-space variant for inputs
+// too long to afford the full O(mn) DP table.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Length of the longest common subsequence of `a` and `b`, and one such
+/// subsequence. `dp[i][j]` is the LCS length of `a[..i]` and `b[..j]`;
+/// walking it backward from `dp[m][n]` recovers a witnessing subsequence.
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> (usize, Vec<T>) {
+    let (m, n) = (a.len(), b.len());
+    let mut dp = vec![vec![0usize; n + 1]; m + 1];
+    for i in 1..=m {
+        for j in 1..=n {
+            dp[i][j] =
+                if a[i - 1] == b[j - 1] { dp[i - 1][j - 1] + 1 } else { dp[i - 1][j].max(dp[i][j - 1]) };
+        }
+    }
+
+    let mut result = Vec::with_capacity(dp[m][n]);
+    let (mut i, mut j) = (m, n);
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if dp[i - 1][j] >= dp[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    result.reverse();
+    (dp[m][n], result)
+}
+
+/// Just the LCS length of `a[..i]` and `b`, for every `i` in `0..=a.len()`,
+/// computed with one DP row instead of the full table — the building
+/// block [`lcs_length`] and [`hirschberg_lcs`] share for O(min(m, n))
+/// space.
+fn lcs_length_row<T: Eq>(a: &[T], b: &[T]) -> Vec<usize> {
+    let n = b.len();
+    let mut previous = vec![0usize; n + 1];
+    let mut current = vec![0usize; n + 1];
+    for x in a {
+        for (j, y) in b.iter().enumerate() {
+            current[j + 1] = if x == y { previous[j] + 1 } else { previous[j + 1].max(current[j]) };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+/// Length of the longest common subsequence of `a` and `b`, in O(mn) time
+/// but only O(min(m, n)) space (no subsequence is reconstructed, which is
+/// exactly what makes the smaller footprint possible).
+pub fn lcs_length<T: Eq>(a: &[T], b: &[T]) -> usize {
+    if a.len() > b.len() {
+        return lcs_length(b, a);
+    }
+    lcs_length_row(b, a)[a.len()]
+}
+
+/// Hirschberg's algorithm: the longest common subsequence of `a` and `b`
+/// in O(mn) time but only O(m + n) space, by divide-and-conquer instead
+/// of keeping the full DP table. Splits `a` at its midpoint, finds the
+/// split point in `b` that a combined LCS must cross (via forward and
+/// backward length rows meeting in the middle), and recurses on the two
+/// halves independently.
+pub fn hirschberg_lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let m = a.len();
+    if m == 0 {
+        return Vec::new();
+    }
+    if m == 1 {
+        return if b.contains(&a[0]) { vec![a[0].clone()] } else { Vec::new() };
+    }
+
+    let mid = m / 2;
+    let forward = lcs_length_row(&a[..mid], b);
+    let reversed_a: Vec<T> = a[mid..].iter().rev().cloned().collect();
+    let reversed_b: Vec<T> = b.iter().rev().cloned().collect();
+    let backward = lcs_length_row(&reversed_a, &reversed_b);
+
+    let split = (0..=b.len())
+        .max_by_key(|&j| forward[j] + backward[b.len() - j])
+        .expect("b.len() + 1 >= 1, so the range is never empty");
+
+    let mut left = hirschberg_lcs(&a[..mid], &b[..split]);
+    let right = hirschberg_lcs(&a[mid..], &b[split..]);
+    left.extend(right);
+    left
+}
+
+#[cfg(test)]
+fn is_subsequence<T: Eq>(needle: &[T], haystack: &[T]) -> bool {
+    let mut it = haystack.iter();
+    needle.iter().all(|x| it.any(|y| y == x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_textbook_example() {
+        let a = [b'A', b'B', b'C', b'B', b'D', b'A', b'B'];
+        let b = [b'B', b'D', b'C', b'A', b'B', b'A'];
+        let (length, subsequence) = lcs(&a, &b);
+        assert_eq!(length, 4);
+        assert!(is_subsequence(&subsequence, &a));
+        assert!(is_subsequence(&subsequence, &b));
+    }
+
+    #[test]
+    fn is_generic_over_non_byte_element_types() {
+        let a = ["the", "quick", "brown", "fox"];
+        let b = ["a", "quick", "red", "fox"];
+        let (length, subsequence) = lcs(&a, &b);
+        assert_eq!(length, 2);
+        assert_eq!(subsequence, ["quick", "fox"]);
+    }
+
+    #[test]
+    fn lcs_length_matches_full_lcs_on_random_short_strings() {
+        let alphabet = b"abc";
+        for len_a in 0..6 {
+            for mask_a in 0..(3u32.pow(len_a)) {
+                let a = decode_ternary(mask_a, len_a, alphabet);
+                for len_b in 0..6 {
+                    for mask_b in 0..(3u32.pow(len_b)) {
+                        let b = decode_ternary(mask_b, len_b, alphabet);
+                        let (expected, _) = lcs(&a, &b);
+                        assert_eq!(lcs_length(&a, &b), expected, "a={a:?} b={b:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hirschberg_matches_full_lcs_on_random_short_strings() {
+        let alphabet = b"ab";
+        for len_a in 0..8 {
+            for mask_a in 0..(1u32 << len_a) {
+                let a = decode_binary(mask_a, len_a, alphabet);
+                for len_b in 0..8 {
+                    for mask_b in 0..(1u32 << len_b) {
+                        let b = decode_binary(mask_b, len_b, alphabet);
+                        let (expected_len, _) = lcs(&a, &b);
+                        let got = hirschberg_lcs(&a, &b);
+                        assert_eq!(got.len(), expected_len, "a={a:?} b={b:?}");
+                        assert!(is_subsequence(&got, &a));
+                        assert!(is_subsequence(&got, &b));
+                    }
+                }
+            }
+        }
+    }
+
+    fn decode_ternary(mut mask: u32, len: u32, alphabet: &[u8]) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                let byte = alphabet[(mask % 3) as usize];
+                mask /= 3;
+                byte
+            })
+            .collect()
+    }
+
+    fn decode_binary(mask: u32, len: u32, alphabet: &[u8]) -> Vec<u8> {
+        (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect()
+    }
+}
@@ -0,0 +1,104 @@
+// Two line-breaking strategies for laying words out into lines of a
+// fixed column width: the greedy, fully-justified layout used by most
+// text editors, and the DP-based minimum-raggedness layout (a lite
+// version of Knuth-Plass's TeX algorithm, without hyphenation or
+// stretch/shrink glue) that spreads leftover space evenly across lines
+// instead of dumping it all on the last one.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Greedily packs as many words as fit per line, then pads every line
+/// but the last with extra spaces (distributed as evenly as possible,
+/// favoring the leftmost gaps) so it is exactly `width` columns wide. A
+/// line holding a single word, or the last line, is left-justified
+/// instead of stretched.
+pub fn full_justify(words: &[&str], width: usize) -> Vec<String> {
+    let n = words.len();
+    let mut lines = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let mut j = i;
+        let mut line_len = words[i].len();
+        while j + 1 < n && line_len + 1 + words[j + 1].len() <= width {
+            j += 1;
+            line_len += 1 + words[j].len();
+        }
+
+        let line_words = &words[i..=j];
+        if line_words.len() == 1 || j == n - 1 {
+            let mut line = line_words.join(" ");
+            line.push_str(&" ".repeat(width.saturating_sub(line.len())));
+            lines.push(line);
+        } else {
+            let gaps = line_words.len() - 1;
+            let total_spaces = width - (line_len - gaps);
+            let base = total_spaces / gaps;
+            let extra = total_spaces % gaps;
+
+            let mut line = String::new();
+            for (k, word) in line_words.iter().enumerate() {
+                line.push_str(word);
+                if k != gaps {
+                    line.push_str(&" ".repeat(base + if k < extra { 1 } else { 0 }));
+                }
+            }
+            lines.push(line);
+        }
+        i = j + 1;
+    }
+    lines
+}
+
+/// Packs `words` onto lines of at most `width` columns to minimize total
+/// raggedness: the sum, over every line but the last, of the squared
+/// number of unused columns after placing single spaces between words.
+/// O(n^2) DP over where to break, which — unlike the greedy
+/// [`full_justify`] — can sacrifice space on an earlier line to avoid a
+/// much raggier one later. Assumes every individual word fits within
+/// `width`.
+pub fn minimum_raggedness_lines(words: &[&str], width: usize) -> Vec<String> {
+    let n = words.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // line_len[i][j] = length of words[i..=j] joined by single spaces,
+    // or `None` if that line would overflow `width`.
+    let mut line_len = vec![vec![None; n]; n];
+    for i in 0..n {
+        let mut len = words[i].len();
+        line_len[i][i] = (len <= width).then_some(len);
+        for j in (i + 1)..n {
+            len += 1 + words[j].len();
+            line_len[i][j] = (len <= width).then_some(len);
+        }
+    }
+
+    const INF: u64 = u64::MAX / 2;
+    let mut cost = vec![INF; n + 1]; // cost[i] = best raggedness for words[i..n]
+    let mut split = vec![n; n]; // split[i] = last word on i's line
+    cost[n] = 0;
+    for i in (0..n).rev() {
+        for j in i..n {
+            let Some(len) = line_len[i][j] else { break };
+            let slack = width - len;
+            let line_cost = if j == n - 1 { 0 } else { (slack * slack) as u64 };
+            let total = line_cost + cost[j + 1];
+            if total < cost[i] {
+                cost[i] = total;
+                split[i] = j;
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let j = split[i];
+        lines.push(words[i..=j].join(" "));
+        i = j + 1;
+    }
+    lines
+}
@@ -0,0 +1,127 @@
+// Longest increasing subsequence via patience sorting: maintain, for
+// each tail length seen so far, the smallest possible tail value, found
+// and updated by binary search — O(n log n) instead of the textbook
+// O(n^2) DP, at the cost of needing a predecessor chain (rather than a
+// DP table) to recover the actual indices.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Indices into `values` of one longest strictly increasing subsequence.
+/// Pass `strict = false` for longest *non-decreasing* instead.
+///
+/// `tails[len]` holds the index of the smallest tail value achieving an
+/// increasing subsequence of length `len + 1` seen so far; binary search
+/// over `tails` finds where `values[i]` extends or replaces a tail in
+/// O(log n), and `predecessor[i]` records what preceded `values[i]` in
+/// the subsequence ending there, letting the final answer be recovered
+/// by walking backward from the longest tail found.
+pub fn longest_increasing_subsequence(values: &[i64], strict: bool) -> Vec<usize> {
+    let n = values.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessor = vec![usize::MAX; n];
+
+    for i in 0..n {
+        let extends = |tail_index: usize| -> bool {
+            if strict { values[tail_index] < values[i] } else { values[tail_index] <= values[i] }
+        };
+        let pos = tails.partition_point(|&tail_index| extends(tail_index));
+
+        if pos > 0 {
+            predecessor[i] = tails[pos - 1];
+        }
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
+        }
+    }
+
+    let mut subsequence = Vec::with_capacity(tails.len());
+    let mut current = *tails.last().expect("tails is non-empty since n > 0");
+    loop {
+        subsequence.push(current);
+        if predecessor[current] == usize::MAX {
+            break;
+        }
+        current = predecessor[current];
+    }
+    subsequence.reverse();
+    subsequence
+}
+
+/// The textbook O(n^2) DP, kept as the ground truth
+/// [`longest_increasing_subsequence`] is checked against: `dp[i]` is the
+/// length of the longest qualifying subsequence ending at `i`.
+#[cfg(test)]
+fn lis_length_dp(values: &[i64], strict: bool) -> usize {
+    let n = values.len();
+    let mut dp = vec![1usize; n];
+    for i in 0..n {
+        for j in 0..i {
+            let extends = if strict { values[j] < values[i] } else { values[j] <= values[i] };
+            if extends {
+                dp[i] = dp[i].max(dp[j] + 1);
+            }
+        }
+    }
+    dp.into_iter().max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_increasing(values: &[i64], indices: &[usize], strict: bool) -> bool {
+        indices.windows(2).all(|pair| {
+            let (a, b) = (values[pair[0]], values[pair[1]]);
+            if strict { a < b } else { a <= b }
+        }) && indices.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    #[test]
+    fn matches_textbook_example() {
+        let values = [10, 9, 2, 5, 3, 7, 101, 18];
+        let indices = longest_increasing_subsequence(&values, true);
+        assert_eq!(indices.len(), 4);
+        assert!(is_increasing(&values, &indices, true));
+    }
+
+    #[test]
+    fn matches_on_dp_length_over_random_short_sequences() {
+        let alphabet = [1i64, 2, 2, 3];
+        for len in 0..10 {
+            for mask in 0..(4u32.pow(len)) {
+                let mut m = mask;
+                let values: Vec<i64> = (0..len)
+                    .map(|_| {
+                        let v = alphabet[(m % 4) as usize];
+                        m /= 4;
+                        v
+                    })
+                    .collect();
+                for &strict in &[true, false] {
+                    let indices = longest_increasing_subsequence(&values, strict);
+                    assert_eq!(indices.len(), lis_length_dp(&values, strict), "values={values:?} strict={strict}");
+                    assert!(is_increasing(&values, &indices, strict), "values={values:?} strict={strict}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn strict_and_non_strict_differ_on_plateaus() {
+        let values = [1, 2, 2, 2, 3];
+        assert_eq!(longest_increasing_subsequence(&values, true).len(), 3);
+        assert_eq!(longest_increasing_subsequence(&values, false).len(), 5);
+    }
+
+    #[test]
+    fn empty_input_has_no_subsequence() {
+        assert_eq!(longest_increasing_subsequence(&[], true), Vec::<usize>::new());
+    }
+}
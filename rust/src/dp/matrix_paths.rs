@@ -0,0 +1,216 @@
+// Classic grid-traversal DP problems (minimum path sum, unique paths with
+// obstacles, cherry pickup, dungeon game) that all share the same
+// row-major scaffold and differ only in their recurrence.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A row-major grid of cells, shared by every DP in this module so each
+/// one only has to write its recurrence, not its own bounds-checked
+/// indexing.
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn from_rows(rows: &[Vec<T>]) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "all rows must have equal length");
+        Grid { rows: height, cols: width, cells: rows.concat() }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> &T {
+        &self.cells[r * self.cols + c]
+    }
+}
+
+/// Minimum sum along any path from the top-left to the bottom-right cell,
+/// moving only right or down. `dp[r][c] = grid[r][c] + min(dp[r-1][c],
+/// dp[r][c-1])`.
+pub fn min_path_sum(grid: &Grid<i64>) -> i64 {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let mut dp = vec![vec![i64::MAX; cols]; rows];
+    for r in 0..rows {
+        for c in 0..cols {
+            let best_prior = match (r, c) {
+                (0, 0) => 0,
+                (0, _) => dp[0][c - 1],
+                (_, 0) => dp[r - 1][c],
+                _ => dp[r - 1][c].min(dp[r][c - 1]),
+            };
+            dp[r][c] = best_prior + grid.get(r, c);
+        }
+    }
+    dp[rows - 1][cols - 1]
+}
+
+/// Number of distinct right/down paths from the top-left to the
+/// bottom-right, where `1` marks a blocked cell. `0` if the start or end
+/// cell is itself blocked.
+pub fn unique_paths_with_obstacles(obstacles: &Grid<u8>) -> u64 {
+    let (rows, cols) = (obstacles.rows(), obstacles.cols());
+    let mut dp = vec![vec![0u64; cols]; rows];
+    for r in 0..rows {
+        for c in 0..cols {
+            if *obstacles.get(r, c) != 0 {
+                continue;
+            }
+            dp[r][c] = match (r, c) {
+                (0, 0) => 1,
+                (0, _) => dp[0][c - 1],
+                (_, 0) => dp[r - 1][c],
+                _ => dp[r - 1][c] + dp[r][c - 1],
+            };
+        }
+    }
+    dp[rows - 1][cols - 1]
+}
+
+/// Maximum cherries collected by two round trips from the top-left to the
+/// bottom-right, each moving only right or down, where picking a cherry
+/// (value `1`) empties that cell for the other trip and `-1` marks a
+/// blocked cell. Modeled as two walkers advancing one step together, so
+/// after `t` steps walker `i` sits at `(row_i, t - row_i)`; the state is
+/// `(t, row_a, row_b)`, collapsing the dual-path problem to a single
+/// forward DP.
+pub fn cherry_pickup(grid: &Grid<i64>) -> i64 {
+    let n = grid.rows();
+    assert_eq!(n, grid.cols(), "cherry pickup requires a square grid");
+    const BLOCKED: i64 = i64::MIN / 2;
+
+    let mut dp = vec![vec![vec![BLOCKED; n]; n]; 2 * n - 1];
+    dp[0][0][0] = *grid.get(0, 0);
+
+    for t in 1..2 * n - 1 {
+        for row_a in 0..n {
+            let col_a = t as isize - row_a as isize;
+            if !(0..n as isize).contains(&col_a) {
+                continue;
+            }
+            for row_b in 0..n {
+                let col_b = t as isize - row_b as isize;
+                if !(0..n as isize).contains(&col_b) || *grid.get(row_a, col_a as usize) == -1
+                    || *grid.get(row_b, col_b as usize) == -1
+                {
+                    continue;
+                }
+
+                let mut best = BLOCKED;
+                for prev_a in [row_a, row_a.wrapping_sub(1)] {
+                    if prev_a >= n {
+                        continue;
+                    }
+                    for prev_b in [row_b, row_b.wrapping_sub(1)] {
+                        if prev_b >= n {
+                            continue;
+                        }
+                        best = best.max(dp[t - 1][prev_a][prev_b]);
+                    }
+                }
+                if best == BLOCKED {
+                    continue;
+                }
+
+                let mut collected = *grid.get(row_a, col_a as usize);
+                if row_a != row_b {
+                    collected += *grid.get(row_b, col_b as usize);
+                }
+                dp[t][row_a][row_b] = best + collected;
+            }
+        }
+    }
+
+    dp[2 * n - 2][n - 1][n - 1].max(0)
+}
+
+/// Minimum starting health for a knight to survive a right/down path from
+/// the top-left to the bottom-right of `dungeon`, where each cell is a
+/// health delta (negative for a demon, positive for a potion). Solved by
+/// a reverse DP from the bottom-right: `need[r][c]` is the minimum health
+/// required *entering* `(r, c)`, computed from the minimum health required
+/// entering whichever of `(r+1, c)`/`(r, c+1)` is reached next, since that
+/// is what determines the tightest constraint walking forward.
+pub fn dungeon_game_min_health(dungeon: &Grid<i64>) -> i64 {
+    let (rows, cols) = (dungeon.rows(), dungeon.cols());
+    let mut need = vec![vec![1i64; cols]; rows];
+
+    for r in (0..rows).rev() {
+        for c in (0..cols).rev() {
+            let next_need = if r == rows - 1 && c == cols - 1 {
+                1
+            } else if r == rows - 1 {
+                need[r][c + 1]
+            } else if c == cols - 1 {
+                need[r + 1][c]
+            } else {
+                need[r + 1][c].min(need[r][c + 1])
+            };
+            need[r][c] = (next_need - dungeon.get(r, c)).max(1);
+        }
+    }
+
+    need[0][0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[[i64; 3]]) -> Grid<i64> {
+        Grid::from_rows(&rows.iter().map(|row| row.to_vec()).collect::<Vec<_>>())
+    }
+
+    fn brute_force_min_path_sum(grid: &Grid<i64>, r: usize, c: usize) -> i64 {
+        let here = *grid.get(r, c);
+        if r == grid.rows() - 1 && c == grid.cols() - 1 {
+            return here;
+        }
+        let down = (r + 1 < grid.rows()).then(|| here + brute_force_min_path_sum(grid, r + 1, c));
+        let right = (c + 1 < grid.cols()).then(|| here + brute_force_min_path_sum(grid, r, c + 1));
+        down.into_iter().chain(right).min().expect("bottom-right is always reachable")
+    }
+
+    #[test]
+    fn min_path_sum_matches_brute_force() {
+        let g = grid(&[[1, 3, 1], [1, 5, 1], [4, 2, 1]]);
+        assert_eq!(min_path_sum(&g), brute_force_min_path_sum(&g, 0, 0));
+        assert_eq!(min_path_sum(&g), 7);
+    }
+
+    #[test]
+    fn unique_paths_counts_around_a_blocked_cell() {
+        let obstacles =
+            Grid::from_rows(&[vec![0, 0, 0], vec![0, 1, 0], vec![0, 0, 0]]);
+        assert_eq!(unique_paths_with_obstacles(&obstacles), 2);
+    }
+
+    #[test]
+    fn unique_paths_is_zero_when_start_is_blocked() {
+        let obstacles = Grid::from_rows(&[vec![1, 0], vec![0, 0]]);
+        assert_eq!(unique_paths_with_obstacles(&obstacles), 0);
+    }
+
+    #[test]
+    fn cherry_pickup_collects_every_cherry_when_reachable_twice() {
+        let g = grid(&[[0, 1, -1], [1, 0, -1], [1, 1, 1]]);
+        assert_eq!(cherry_pickup(&g), 5);
+    }
+
+    #[test]
+    fn dungeon_game_matches_textbook_example() {
+        let g = grid(&[[-2, -3, 3], [-5, -10, 1], [10, 30, -5]]);
+        assert_eq!(dungeon_game_min_health(&g), 7);
+    }
+}
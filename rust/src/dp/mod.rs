@@ -0,0 +1,10 @@
+//! Dynamic-programming algorithms.
+
+pub mod knapsack;
+pub mod lcs;
+pub mod line_breaking;
+pub mod lis;
+pub mod matrix_paths;
+pub mod optimal_bst;
+pub mod subset_convolution;
+pub mod subset_sum;
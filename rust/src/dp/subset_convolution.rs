@@ -0,0 +1,104 @@
+// Fast zeta/Mobius transforms on the subset lattice and the resulting
+// O(2^n * n^2) subset convolution, used for counting problems over subsets
+// (e.g. partitioning a small graph into independent sets).
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Zeta transform: `z[S] = sum_{T subset S} a[T]`.
+fn zeta_transform(a: &mut [i64]) {
+    let n = a.len();
+    let bits = n.trailing_zeros();
+    for b in 0..bits {
+        let bit = 1usize << b;
+        for s in 0..n {
+            if s & bit != 0 {
+                a[s] += a[s ^ bit];
+            }
+        }
+    }
+}
+
+/// Mobius transform: the inverse of [`zeta_transform`].
+fn mobius_transform(a: &mut [i64]) {
+    let n = a.len();
+    let bits = n.trailing_zeros();
+    for b in 0..bits {
+        let bit = 1usize << b;
+        for s in 0..n {
+            if s & bit != 0 {
+                a[s] -= a[s ^ bit];
+            }
+        }
+    }
+}
+
+/// Subset convolution: `c[S] = sum_{T subset S} a[T] * b[S \ T]`.
+///
+/// Computed via rank-convolution: split each array by popcount into `n+1`
+/// "rank" slices, zeta-transform each slice, multiply rank-wise with an
+/// ordinary pointwise product (summing over rank pairs that add to the
+/// target rank), then Mobius-transform back.
+pub fn subset_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let n = a.len();
+    let bits = n.trailing_zeros() as usize;
+
+    let mut fa = vec![vec![0i64; n]; bits + 1];
+    let mut fb = vec![vec![0i64; n]; bits + 1];
+    for (s, (&av, &bv)) in a.iter().zip(b.iter()).enumerate() {
+        let r = (s as u32).count_ones() as usize;
+        fa[r][s] = av;
+        fb[r][s] = bv;
+    }
+    for r in 0..=bits {
+        zeta_transform(&mut fa[r]);
+        zeta_transform(&mut fb[r]);
+    }
+
+    let mut fc = vec![vec![0i64; n]; bits + 1];
+    for s in 0..n {
+        for r1 in 0..=bits {
+            for r2 in 0..=(bits - r1) {
+                fc[r1 + r2][s] += fa[r1][s] * fb[r2][s];
+            }
+        }
+    }
+    for row in fc.iter_mut() {
+        mobius_transform(row);
+    }
+
+    let mut c = vec![0i64; n];
+    for (s, cv) in c.iter_mut().enumerate() {
+        let r = (s as u32).count_ones() as usize;
+        *cv = fc[r][s];
+    }
+    c
+}
+
+/// Counts the number of ways to partition the vertex set of a small graph
+/// into exactly `k` independent sets, via the classic subset-convolution
+/// recurrence: `f_k[S] = sum_{T subset S, T nonempty independent} f_{k-1}[S \ T]`.
+pub fn count_k_independent_partitions(adj: &[u32], k: usize) -> i64 {
+    let n_vertices = adj.len();
+    let n = 1usize << n_vertices;
+
+    // i[S] = 1 if S is an independent set, else 0.
+    let mut is_independent = vec![0i64; n];
+    for (s, slot) in is_independent.iter_mut().enumerate() {
+        let mut ok = true;
+        for (v, &adj_v) in adj.iter().enumerate() {
+            if s & (1 << v) != 0 && (adj_v as usize) & s != 0 {
+                ok = false;
+                break;
+            }
+        }
+        *slot = if ok { 1 } else { 0 };
+    }
+
+    let mut f = vec![0i64; n];
+    f[0] = 1;
+    for _ in 0..k {
+        f = subset_convolution(&f, &is_independent);
+    }
+    f[n - 1]
+}
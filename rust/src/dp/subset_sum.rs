@@ -0,0 +1,172 @@
+// Subset-sum feasibility via a manual bitset: the classic "can we reach
+// sum s" DP is a single shift-or per element when the set of reachable
+// sums is packed into u64 words instead of one bool per sum, turning an
+// O(n * target) scalar loop into O(n * target / 64).
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Whether some subset of `values` sums to exactly `target`.
+///
+/// `reachable` is a bitset over sums `0..=target`, one bit per sum,
+/// packed 64 to a word. Folding in one more element shifts the whole
+/// bitset left by that element's value and ORs it back into itself: bit
+/// `s` of the shifted copy is bit `s - value` of the original, i.e.
+/// "reachable without this element, then add it" — exactly the subset
+/// sum recurrence, done to every sum at once.
+pub fn subset_sum_feasible(values: &[u64], target: u64) -> bool {
+    let words = target as usize / WORD_BITS + 1;
+    let mut reachable = vec![0u64; words];
+    reachable[0] = 1; // sum 0 is always reachable (the empty subset).
+    let mut scratch = vec![0u64; words];
+
+    for &value in values {
+        if value > target {
+            continue;
+        }
+        shift_or_in_place(&mut reachable, &mut scratch, value as usize, words, target);
+        if test_bit(&reachable, target as usize) {
+            return true;
+        }
+    }
+    test_bit(&reachable, target as usize)
+}
+
+/// Every reachable sum in `0..=target`, plus a witnessing subset for one
+/// of them if `witness_target` is reachable.
+///
+/// Tracks reachability the same way as [`subset_sum_feasible`], but also
+/// records, per value, the bitset *before* that value was folded in —
+/// enough to walk backward afterward and decide, sum by sum, whether the
+/// current value was needed to first reach it.
+pub fn subset_sum_with_witness(values: &[u64], witness_target: u64) -> Option<Vec<usize>> {
+    let words = witness_target as usize / WORD_BITS + 1;
+    let mut reachable = vec![0u64; words];
+    reachable[0] = 1;
+    let mut scratch = vec![0u64; words];
+
+    let mut history = Vec::with_capacity(values.len());
+    for &value in values {
+        history.push(reachable.clone());
+        if value <= witness_target {
+            shift_or_in_place(&mut reachable, &mut scratch, value as usize, words, witness_target);
+        }
+    }
+
+    if !test_bit(&reachable, witness_target as usize) {
+        return None;
+    }
+
+    // Walk values backward: `history[i]` is the bitset *before* value `i`
+    // was folded in. If `remaining` wasn't reachable without value `i`
+    // but `remaining - value[i]` was, then value `i` must be in the
+    // subset that reaches `remaining`.
+    let mut remaining = witness_target;
+    let mut chosen = Vec::new();
+    for i in (0..values.len()).rev() {
+        let value = values[i];
+        let before = &history[i];
+        let reachable_without = test_bit(before, remaining as usize);
+        if !reachable_without && value <= remaining && test_bit(before, (remaining - value) as usize) {
+            chosen.push(i);
+            remaining -= value;
+        }
+    }
+    chosen.reverse();
+    Some(chosen)
+}
+
+fn shift_or_in_place(reachable: &mut [u64], scratch: &mut [u64], shift_bits: usize, words: usize, target: u64) {
+    let word_shift = shift_bits / WORD_BITS;
+    let bit_shift = shift_bits % WORD_BITS;
+
+    scratch.fill(0);
+    #[allow(clippy::needless_range_loop)] // `i` indexes both `scratch` and an offset `reachable[i - word_shift]`
+    for i in word_shift..words {
+        let src = i - word_shift;
+        let mut word = reachable[src] << bit_shift;
+        if bit_shift > 0 && src > 0 {
+            word |= reachable[src - 1] >> (WORD_BITS - bit_shift);
+        }
+        scratch[i] = word;
+    }
+
+    for i in 0..words {
+        reachable[i] |= scratch[i];
+    }
+
+    // Sums past `target` are never queried, but clear them anyway so a
+    // word-boundary overflow can't corrupt a future shift's arithmetic.
+    let valid_bits_in_last_word = (target as usize % WORD_BITS) + 1;
+    if valid_bits_in_last_word < WORD_BITS {
+        reachable[words - 1] &= (1u64 << valid_bits_in_last_word) - 1;
+    }
+}
+
+fn test_bit(bits: &[u64], index: usize) -> bool {
+    (bits[index / WORD_BITS] >> (index % WORD_BITS)) & 1 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_feasible(values: &[u64], target: u64) -> bool {
+        (0..(1u32 << values.len().min(20)))
+            .any(|mask| (0..values.len()).filter(|&i| mask & (1 << i) != 0).map(|i| values[i]).sum::<u64>() == target)
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_small_sets() {
+        let values = [3u64, 7, 2, 9, 4, 11, 1];
+        for target in 0..=40u64 {
+            assert_eq!(subset_sum_feasible(&values, target), brute_force_feasible(&values, target), "target={target}");
+        }
+    }
+
+    #[test]
+    fn handles_targets_spanning_multiple_words() {
+        let values: Vec<u64> = (1..=10).collect();
+        for target in [0u64, 64, 65, 127, 128, 130, 200] {
+            assert_eq!(
+                subset_sum_feasible(&values, target),
+                brute_force_feasible(&values, target),
+                "target={target}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_set_only_reaches_zero() {
+        assert!(subset_sum_feasible(&[], 0));
+        assert!(!subset_sum_feasible(&[], 1));
+    }
+
+    #[test]
+    fn scales_to_thousands_of_elements_and_a_large_target() {
+        // Every value is even, so any odd target is unreachable no
+        // matter how many elements there are — lets this stay a
+        // meaningful infeasibility check without needing a bitset sized
+        // to the (much larger) total sum of all 5000 elements.
+        let values: Vec<u64> = (1..=5000u64).map(|v| v * 2).collect();
+        assert!(subset_sum_feasible(&values, 1_000_000));
+        assert!(!subset_sum_feasible(&values, 999_999));
+    }
+
+    #[test]
+    fn witness_reconstructs_a_subset_summing_to_the_target() {
+        let values = [3u64, 7, 2, 9, 4, 11, 1];
+        for target in 0..=40u64 {
+            match subset_sum_with_witness(&values, target) {
+                Some(chosen) => {
+                    assert!(brute_force_feasible(&values, target), "target={target} wrongly claimed feasible");
+                    let sum: u64 = chosen.iter().map(|&i| values[i]).sum();
+                    assert_eq!(sum, target, "target={target} witness={chosen:?}");
+                }
+                None => assert!(!brute_force_feasible(&values, target), "target={target} wrongly claimed infeasible"),
+            }
+        }
+    }
+}
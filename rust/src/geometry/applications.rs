@@ -0,0 +1,206 @@
+use core::cmp::Ordering;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use super::rational::Rational;
+use super::types::Point;
+
+/// Orders points by polar angle around the origin without ever touching
+/// a float: split into the upper half-plane (`y > 0`, or `y == 0` and
+/// `x > 0`) and the lower half-plane first, then within a half order by
+/// the sign of the cross product — `a` is counter-clockwise from `b`
+/// (and so comes first) exactly when `a.cross(b) > 0`. Ties (collinear
+/// with the origin) break by distance, closest first.
+fn angular_order(a: Point, b: Point) -> Ordering {
+    fn half(p: Point) -> u8 {
+        if p.y > 0 || (p.y == 0 && p.x > 0) {
+            0
+        } else {
+            1
+        }
+    }
+
+    half(a).cmp(&half(b)).then_with(|| 0i64.cmp(&a.cross(b))).then_with(|| {
+        let origin = Point::new(0, 0);
+        a.squared_distance(origin).cmp(&b.squared_distance(origin))
+    })
+}
+
+/// The points visible from the origin, looking outward: `q` is not
+/// visible if some other point `p` sits strictly between the origin and
+/// `q` on the same ray, blocking the view. Equivalently, one point per
+/// distinct direction survives — the closest one — sorted by polar angle
+/// around the origin.
+///
+/// Points at the origin itself are never visible (there's no direction
+/// to look in) and are dropped.
+pub fn visible_points_from_origin(points: &[Point]) -> Vec<Point> {
+    let origin = Point::new(0, 0);
+    let mut closest_per_direction: BTreeMap<(i64, i64), Point> = BTreeMap::new();
+
+    for &p in points {
+        if p == origin {
+            continue;
+        }
+        let g = gcd(p.x.unsigned_abs(), p.y.unsigned_abs()).max(1) as i64;
+        let direction = (p.x / g, p.y / g);
+        closest_per_direction
+            .entry(direction)
+            .and_modify(|closest| {
+                if p.squared_distance(origin) < closest.squared_distance(origin) {
+                    *closest = p;
+                }
+            })
+            .or_insert(p);
+    }
+
+    let mut visible: Vec<Point> = closest_per_direction.into_values().collect();
+    visible.sort_unstable_by(|&a, &b| angular_order(a, b));
+    visible
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The most points lying on a single common line.
+///
+/// For each point `p`, buckets every other point by its exact slope
+/// from `p` (a [`Rational`], or `None` for a vertical line) — the same
+/// point-on-many-lines problem a `HashMap<f64, _>` would get subtly
+/// wrong near-ties on, solved exactly since `Rational` reduces to a
+/// canonical form instead of rounding.
+pub fn max_points_on_a_line(points: &[Point]) -> usize {
+    if points.len() <= 2 {
+        return points.len();
+    }
+
+    let mut best = 1;
+    for (i, &p) in points.iter().enumerate() {
+        let mut by_slope: BTreeMap<Option<Rational>, usize> = BTreeMap::new();
+        let mut duplicates = 0;
+        for (j, &q) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let (dx, dy) = (q.x - p.x, q.y - p.y);
+            if dx == 0 && dy == 0 {
+                duplicates += 1;
+                continue;
+            }
+            let slope = if dx == 0 { None } else { Some(Rational::new(dy, dx)) };
+            *by_slope.entry(slope).or_insert(0) += 1;
+        }
+        let most_on_one_slope = by_slope.values().copied().max().unwrap_or(0);
+        best = best.max(most_on_one_slope + duplicates + 1);
+    }
+    best
+}
+
+/// The minimum-perimeter convex polygon enclosing every point (the
+/// tightest "fence" around the set) — Andrew's monotone chain: sort by
+/// coordinates, then sweep once building the lower hull and once
+/// building the upper hull, popping any point that would make a
+/// clockwise (non-left) turn.
+///
+/// Returns the hull vertices in counter-clockwise order, starting from
+/// the lexicographically smallest point. Collinear points on an edge of
+/// the hull are excluded, same as a classic convex hull.
+pub fn minimum_enclosing_fence(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    if sorted.len() <= 2 {
+        return sorted;
+    }
+
+    let build_chain = |ordered: &[Point]| -> Vec<Point> {
+        let mut chain: Vec<Point> = Vec::new();
+        for &p in ordered {
+            while chain.len() >= 2 {
+                let turn = (chain[chain.len() - 2] - p).cross(chain[chain.len() - 1] - p);
+                if turn <= 0 {
+                    chain.pop();
+                } else {
+                    break;
+                }
+            }
+            chain.push(p);
+        }
+        chain
+    };
+
+    let mut lower = build_chain(&sorted);
+    let reversed: Vec<Point> = sorted.iter().rev().copied().collect();
+    let mut upper = build_chain(&reversed);
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_closer_point_blocks_one_further_along_the_same_ray() {
+        let points = [Point::new(1, 1), Point::new(2, 2), Point::new(3, 1)];
+        let visible = visible_points_from_origin(&points);
+        assert_eq!(visible, vec![Point::new(3, 1), Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn the_origin_itself_is_never_visible() {
+        let points = [Point::new(0, 0), Point::new(1, 0)];
+        assert_eq!(visible_points_from_origin(&points), vec![Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn visible_points_are_sorted_by_angle() {
+        // Sweeping counter-clockwise starting from the positive x-axis.
+        let points = [Point::new(0, -1), Point::new(-1, 0), Point::new(1, 0), Point::new(0, 1)];
+        let visible = visible_points_from_origin(&points);
+        assert_eq!(visible, vec![Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0), Point::new(0, -1)]);
+    }
+
+    #[test]
+    fn four_collinear_points_are_all_on_one_line() {
+        let points = [Point::new(0, 0), Point::new(1, 1), Point::new(2, 2), Point::new(3, 3)];
+        assert_eq!(max_points_on_a_line(&points), 4);
+    }
+
+    #[test]
+    fn a_vertical_line_is_detected_without_dividing_by_zero() {
+        let points = [Point::new(2, 0), Point::new(2, 5), Point::new(2, -3), Point::new(0, 0)];
+        assert_eq!(max_points_on_a_line(&points), 3);
+    }
+
+    #[test]
+    fn duplicate_points_still_count_toward_every_line_through_them() {
+        let points = [Point::new(0, 0), Point::new(0, 0), Point::new(1, 1), Point::new(5, 9)];
+        assert_eq!(max_points_on_a_line(&points), 3);
+    }
+
+    #[test]
+    fn a_square_hull_excludes_its_center() {
+        let points = [Point::new(0, 0), Point::new(0, 4), Point::new(4, 4), Point::new(4, 0), Point::new(2, 2)];
+        let hull = minimum_enclosing_fence(&points);
+        assert_eq!(hull.len(), 4);
+        assert!(!hull.contains(&Point::new(2, 2)));
+    }
+
+    #[test]
+    fn collinear_points_on_an_edge_are_not_hull_vertices() {
+        let points = [Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(1, 2)];
+        let hull = minimum_enclosing_fence(&points);
+        assert!(!hull.contains(&Point::new(1, 0)));
+        assert_eq!(hull.len(), 3);
+    }
+}
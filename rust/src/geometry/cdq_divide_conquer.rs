@@ -0,0 +1,148 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::types::Point;
+
+struct Fenwick {
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    fn new(n: usize) -> Self {
+        Fenwick { tree: vec![0; n + 1] }
+    }
+
+    fn add(&mut self, mut i: usize, delta: i64) {
+        i += 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn prefix_sum(&self, mut i: usize) -> i64 {
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    fn clear(&mut self, mut i: usize) {
+        // Used to undo point `add`s between independent CDQ calls without
+        // reallocating the whole tree.
+        i += 1;
+        let mut j = i;
+        while j < self.tree.len() {
+            self.tree[j] = 0;
+            j += j & j.wrapping_neg();
+        }
+    }
+}
+
+/// CDQ divide and conquer: an offline-query framework that turns a
+/// three-dimensional dominance count (time, x, y all partially ordered)
+/// into a sequence of two-dimensional problems solved with a Fenwick tree,
+/// by recursing on input order and using the merge step to resolve the x
+/// dimension while sweeping y.
+///
+/// Counts, for each point, how many strictly earlier points (by position
+/// in `points`) have both `x` and `y` no greater than this point's — the
+/// classic "3D partial order" counting problem, with input order standing
+/// in for the time dimension.
+pub fn cdq_dominance_count(points: &[Point]) -> Vec<i64> {
+    let n = points.len();
+    let max_x = points.iter().map(|p| p.x).max().unwrap_or(0) as usize;
+    let mut answer = vec![0i64; n];
+    let mut fenwick = Fenwick::new(max_x + 1);
+
+    let mut indices: Vec<usize> = (0..n).collect();
+    solve(points, &mut indices, &mut answer, &mut fenwick);
+    answer
+}
+
+/// Recursively processes `indices` (already sorted by time, i.e. input
+/// order): split into halves, recurse, then merge by `y` while sweeping in
+/// `x` to count left-half points that dominate each right-half point.
+fn solve(points: &[Point], indices: &mut [usize], answer: &mut [i64], fenwick: &mut Fenwick) {
+    if indices.len() <= 1 {
+        return;
+    }
+    let mid = indices.len() / 2;
+    let (left, right) = indices.split_at_mut(mid);
+    solve(points, left, answer, fenwick);
+    solve(points, right, answer, fenwick);
+
+    // Merge step: sort both halves by y, sweep, and use the Fenwick tree
+    // over x to count dominance contributions from left into right.
+    let mut sorted_left = left.to_vec();
+    let mut sorted_right = right.to_vec();
+    sorted_left.sort_by_key(|&i| points[i].y);
+    sorted_right.sort_by_key(|&i| points[i].y);
+
+    let mut j = 0;
+    let mut touched = Vec::new();
+    for &ri in &sorted_right {
+        while j < sorted_left.len() && points[sorted_left[j]].y <= points[ri].y {
+            fenwick.add(points[sorted_left[j]].x as usize, 1);
+            touched.push(points[sorted_left[j]].x as usize);
+            j += 1;
+        }
+        answer[ri] += fenwick.prefix_sum(points[ri].x as usize + 1);
+    }
+    for x in touched {
+        fenwick.clear(x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(points: &[Point]) -> Vec<i64> {
+        let n = points.len();
+        (0..n)
+            .map(|i| {
+                (0..i).filter(|&j| points[j].x <= points[i].x && points[j].y <= points[i].y).count() as i64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force_on_a_small_point_set() {
+        let raw = [(2, 3), (4, 1), (1, 5), (3, 3), (5, 0)];
+        let points: Vec<Point> = raw.iter().map(|&(x, y)| Point::new(x, y)).collect();
+        assert_eq!(cdq_dominance_count(&points), brute_force(&points));
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_point_sets() {
+        let mut rng = 0x2545F4914F6CDD1Du64;
+        for _ in 0..20 {
+            let n = 1 + (rng % 12) as usize;
+            rng ^= rng << 13;
+            rng ^= rng >> 7;
+            rng ^= rng << 17;
+            let points: Vec<Point> = (0..n)
+                .map(|_| {
+                    rng ^= rng << 13;
+                    rng ^= rng >> 7;
+                    rng ^= rng << 17;
+                    let x = (rng % 10) as i64;
+                    rng ^= rng << 13;
+                    rng ^= rng >> 7;
+                    rng ^= rng << 17;
+                    let y = (rng % 10) as i64;
+                    Point::new(x, y)
+                })
+                .collect();
+            assert_eq!(cdq_dominance_count(&points), brute_force(&points), "points={points:?}");
+        }
+    }
+
+    #[test]
+    fn empty_input_has_no_points_to_count() {
+        assert_eq!(cdq_dominance_count(&[]), Vec::<i64>::new());
+    }
+}
@@ -0,0 +1,9 @@
+//! Computational geometry: a `Point` primitive and an exact `Rational`
+//! type for comparisons that integer cross products alone can't express
+//! (like comparing slopes), plus a handful of classic "points in the
+//! plane" puzzles built on both.
+
+pub mod applications;
+pub mod cdq_divide_conquer;
+pub mod rational;
+pub mod types;
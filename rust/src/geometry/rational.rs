@@ -0,0 +1,74 @@
+use core::cmp::Ordering;
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// An exact fraction in lowest terms, denominator always positive — so
+/// two equal fractions always normalize to the same `(numerator,
+/// denominator)` pair and can be compared, hashed, or used as a map key
+/// without ever drifting the way repeated `f64` division would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Rational {
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "Rational denominator must be nonzero");
+        let sign: i64 = if denominator < 0 { -1 } else { 1 };
+        let numerator = numerator * sign;
+        let denominator = denominator * sign;
+        let g = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()).max(1) as i64;
+        Rational { numerator: numerator / g, denominator: denominator / g }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Cross-multiply rather than divide, to stay exact; widen to
+        // i128 since both factors can be up to i64::MAX in magnitude.
+        let lhs = self.numerator as i128 * other.denominator as i128;
+        let rhs = other.numerator as i128 * self.denominator as i128;
+        lhs.cmp(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equivalent_fractions_reduce_to_the_same_value() {
+        assert_eq!(Rational::new(2, 4), Rational::new(1, 2));
+        assert_eq!(Rational::new(-3, -6), Rational::new(1, 2));
+    }
+
+    #[test]
+    fn a_negative_denominator_is_normalized_onto_the_numerator() {
+        let r = Rational::new(3, -4);
+        assert_eq!(r, Rational::new(-3, 4));
+        assert_eq!(r.denominator, 4);
+    }
+
+    #[test]
+    fn ordering_matches_the_fractions_true_value() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(1, 3));
+        assert_eq!(Rational::new(2, 3).cmp(&Rational::new(4, 6)), Ordering::Equal);
+    }
+}
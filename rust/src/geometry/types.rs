@@ -0,0 +1,39 @@
+// Shared geometry primitives so the applications built on them don't
+// each reinvent their own point type (mirrors `graph::types`).
+
+use core::ops::Sub;
+
+/// An integer point in the plane. Kept to whole coordinates so every
+/// primitive built on it (cross products, squared distances, exact
+/// slopes) stays exact — no `f64` rounding to worry about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The z-component of `self x other` (both treated as vectors from
+    /// the origin): positive when `other` is counter-clockwise from
+    /// `self`, negative when clockwise, zero when collinear.
+    pub fn cross(self, other: Point) -> i64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn squared_distance(self, other: Point) -> i64 {
+        let d = self - other;
+        d.x * d.x + d.y * d.y
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
@@ -0,0 +1,230 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::types::WeightedEdge;
+
+/// Minimum spanning arborescence rooted at `root`: the cheapest set of
+/// edges connecting every other vertex to `root` via a unique directed
+/// path, found by the Chu-Liu/Edmonds algorithm. Returns the total
+/// weight, or `None` if some vertex isn't reachable from `root` (no
+/// arborescence exists).
+///
+/// Repeatedly picks each non-root vertex's cheapest incoming edge. If
+/// that never closes a cycle, those edges already form the answer.
+/// Otherwise every cycle found gets contracted into one super-vertex —
+/// iteratively, not recursively: the contracted graph just becomes the
+/// next loop iteration's input — with incoming-edge weights adjusted so
+/// the next round's cheapest-incoming-edge choice already accounts for
+/// the cost the cycle "paid for itself" before contraction.
+pub fn min_arborescence(vertices: usize, edges: &[WeightedEdge], root: usize) -> Option<i64> {
+    let mut n = vertices;
+    let mut root = root;
+    let mut edge_list: Vec<WeightedEdge> = edges.to_vec();
+    let mut total = 0i64;
+
+    loop {
+        // Step 1: cheapest incoming edge for every non-root vertex.
+        let mut min_in = vec![i64::MAX; n];
+        let mut min_in_edge: Vec<Option<usize>> = vec![None; n];
+        for (i, edge) in edge_list.iter().enumerate() {
+            if edge.from == edge.to || edge.to == root {
+                continue;
+            }
+            if edge.weight < min_in[edge.to] {
+                min_in[edge.to] = edge.weight;
+                min_in_edge[edge.to] = Some(i);
+            }
+        }
+        for (v, edge) in min_in_edge.iter().enumerate() {
+            if v != root && edge.is_none() {
+                return None;
+            }
+        }
+
+        // Step 2: follow each vertex's chosen incoming edge back towards
+        // the root; a chain that revisits a vertex from the *same* walk
+        // has found a cycle, which gets assigned a fresh component id.
+        let mut visited_by = vec![usize::MAX; n];
+        let mut component = vec![usize::MAX; n];
+        let mut cycle_count = 0;
+        for start in 0..n {
+            let mut v = start;
+            while visited_by[v] == usize::MAX {
+                visited_by[v] = start;
+                if v == root {
+                    break;
+                }
+                v = edge_list[min_in_edge[v].unwrap()].from;
+            }
+            if v != root && visited_by[v] == start {
+                let mut u = v;
+                loop {
+                    component[u] = cycle_count;
+                    u = edge_list[min_in_edge[u].unwrap()].from;
+                    if u == v {
+                        break;
+                    }
+                }
+                cycle_count += 1;
+            }
+        }
+
+        if cycle_count == 0 {
+            for (v, &weight) in min_in.iter().enumerate() {
+                if v != root {
+                    total += weight;
+                }
+            }
+            return Some(total);
+        }
+
+        // Every vertex inside a freshly-found cycle already "spent" its
+        // chosen incoming edge's weight; vertices outside any cycle carry
+        // over unchanged as their own singleton component.
+        for (v, &comp) in component.iter().enumerate() {
+            if v != root && comp < cycle_count {
+                total += min_in[v];
+            }
+        }
+        let mut next_id = cycle_count;
+        for comp in component.iter_mut() {
+            if *comp == usize::MAX {
+                *comp = next_id;
+                next_id += 1;
+            }
+        }
+
+        let mut contracted = Vec::new();
+        for edge in &edge_list {
+            let (cu, cv) = (component[edge.from], component[edge.to]);
+            if cu == cv {
+                continue;
+            }
+            // An edge entering a vertex that was absorbed into a cycle
+            // only needs to beat what that cycle already paid for itself.
+            let weight = if component[edge.to] < cycle_count { edge.weight - min_in[edge.to] } else { edge.weight };
+            contracted.push(WeightedEdge { from: cu, to: cv, weight });
+        }
+
+        n = next_id;
+        root = component[root];
+        edge_list = contracted;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exhaustively tries every way of picking one incoming edge per
+    /// non-root vertex and keeps the cheapest choice that forms a valid
+    /// arborescence (every vertex's parent chain reaches `root` without
+    /// cycling), as a ground truth for small digraphs.
+    fn brute_force_min_arborescence(vertices: usize, edges: &[WeightedEdge], root: usize) -> Option<i64> {
+        let candidates: Vec<Vec<usize>> = (0..vertices)
+            .map(|v| (0..edges.len()).filter(|&i| edges[i].to == v && edges[i].from != v).collect())
+            .collect();
+
+        let mut best = None;
+        let mut choice = vec![usize::MAX; vertices];
+        search(vertices, root, edges, &candidates, 0, &mut choice, &mut best);
+        best
+    }
+
+    fn search(
+        vertices: usize,
+        root: usize,
+        edges: &[WeightedEdge],
+        candidates: &[Vec<usize>],
+        v: usize,
+        choice: &mut [usize],
+        best: &mut Option<i64>,
+    ) {
+        if v == vertices {
+            if forms_arborescence(vertices, root, edges, choice) {
+                let total: i64 = choice.iter().filter(|&&i| i != usize::MAX).map(|&i| edges[i].weight).sum();
+                if best.is_none_or(|b| total < b) {
+                    *best = Some(total);
+                }
+            }
+            return;
+        }
+        if v == root {
+            search(vertices, root, edges, candidates, v + 1, choice, best);
+            return;
+        }
+        if candidates[v].is_empty() {
+            return;
+        }
+        for &edge_index in &candidates[v] {
+            choice[v] = edge_index;
+            search(vertices, root, edges, candidates, v + 1, choice, best);
+        }
+        choice[v] = usize::MAX;
+    }
+
+    fn forms_arborescence(vertices: usize, root: usize, edges: &[WeightedEdge], choice: &[usize]) -> bool {
+        for start in 0..vertices {
+            let mut v = start;
+            for _ in 0..=vertices {
+                if v == root {
+                    break;
+                }
+                if choice[v] == usize::MAX {
+                    return false;
+                }
+                v = edges[choice[v]].from;
+            }
+            if v != root {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn matches_brute_force_on_small_random_digraphs() {
+        let cases: &[(usize, &[WeightedEdge], usize)] = &[
+            (
+                4,
+                &[
+                    WeightedEdge { from: 0, to: 1, weight: 3 },
+                    WeightedEdge { from: 0, to: 2, weight: 2 },
+                    WeightedEdge { from: 2, to: 1, weight: 1 },
+                    WeightedEdge { from: 1, to: 3, weight: 4 },
+                    WeightedEdge { from: 2, to: 3, weight: 5 },
+                    WeightedEdge { from: 3, to: 1, weight: 1 },
+                ],
+                0,
+            ),
+            (
+                5,
+                &[
+                    WeightedEdge { from: 0, to: 1, weight: 4 },
+                    WeightedEdge { from: 0, to: 2, weight: 4 },
+                    WeightedEdge { from: 1, to: 2, weight: 2 },
+                    WeightedEdge { from: 2, to: 1, weight: 1 },
+                    WeightedEdge { from: 2, to: 3, weight: 1 },
+                    WeightedEdge { from: 3, to: 4, weight: 1 },
+                    WeightedEdge { from: 4, to: 2, weight: 1 },
+                ],
+                0,
+            ),
+        ];
+
+        for &(vertices, edges, root) in cases {
+            assert_eq!(min_arborescence(vertices, edges, root), brute_force_min_arborescence(vertices, edges, root));
+        }
+    }
+
+    #[test]
+    fn unreachable_vertex_has_no_arborescence() {
+        let edges = [WeightedEdge { from: 0, to: 1, weight: 1 }];
+        assert_eq!(min_arborescence(3, &edges, 0), None);
+    }
+
+    #[test]
+    fn single_vertex_needs_no_edges() {
+        assert_eq!(min_arborescence(1, &[], 0), Some(0));
+    }
+}
@@ -0,0 +1,34 @@
+use alloc::vec::Vec;
+
+use super::types::WeightedEdge;
+
+/// Bellman-Ford shortest paths from `src` over an edge list. Returns
+/// `None` if a negative-weight cycle reachable from `src` is detected.
+pub fn bellman_ford(vertices: usize, edges: &[WeightedEdge], src: usize) -> Option<Vec<f64>> {
+    let mut dist = vec![f64::INFINITY; vertices];
+    dist[src] = 0.0;
+
+    // Step 1: Relax edges |V| - 1 times
+    for _ in 0..vertices - 1 {
+        let mut updated = false;
+        for edge in edges.iter() {
+            if dist[edge.from] + (edge.weight as f64) < dist[edge.to] {
+                dist[edge.to] = dist[edge.from] + edge.weight as f64;
+                updated = true;
+            }
+        }
+        // Optimization: if no update in a full pass, stop early
+        if !updated {
+            break;
+        }
+    }
+
+    // Step 2: Detect negative weight cycles
+    for edge in edges.iter() {
+        if dist[edge.from] + (edge.weight as f64) < dist[edge.to] {
+            return None;
+        }
+    }
+
+    Some(dist)
+}
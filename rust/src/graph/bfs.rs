@@ -1,6 +1,9 @@
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 
-fn bfs(adj: &Vec<Vec<usize>>, start: usize) -> Vec<usize> {
+/// Breadth-first traversal order of an unweighted adjacency list, starting
+/// from `start`.
+pub fn bfs(adj: &[Vec<usize>], start: usize) -> Vec<usize> {
     let n = adj.len();
     let mut visited = vec![false; n];
     let mut order = Vec::new();
@@ -18,17 +21,3 @@ fn bfs(adj: &Vec<Vec<usize>>, start: usize) -> Vec<usize> {
     }
     order
 }
-
-fn main() {
-    let adj = vec![
-        vec![1, 2],
-        vec![0, 3],
-        vec![0, 3],
-        vec![1, 2, 4],
-        vec![3],
-    ];
-    let order = bfs(&adj, 0);
-    for v in order {
-        println!("{}", v);
-    }
-}
@@ -0,0 +1,279 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+#[derive(Clone, Copy)]
+struct Edge {
+    to: usize,
+    weight: i64,
+}
+
+pub struct Graph {
+    n: usize,
+    adj: Vec<Vec<Edge>>,
+}
+
+impl Graph {
+    fn new(n: usize) -> Self {
+        Graph { n, adj: vec![Vec::new(); n] }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize, w: i64) {
+        self.adj[u].push(Edge { to: v, weight: w });
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    dist: i64,
+    node: usize,
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.cmp(&self.dist)
+    }
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Shortest distance from `src` to `dst` within `graph`, avoiding `avoid`
+/// and giving up after `max_hops` relaxations per path — a bounded
+/// "witness search" used during contraction to check whether a shortcut
+/// through the vertex being contracted is actually necessary.
+fn bounded_dijkstra(graph: &Graph, src: usize, dst: usize, avoid: usize, max_hops: usize) -> i64 {
+    let mut dist = vec![i64::MAX; graph.n];
+    dist[src] = 0;
+    let mut heap = BinaryHeap::new();
+    heap.push(State { dist: 0, node: src });
+    let mut hops = vec![0usize; graph.n];
+
+    while let Some(State { dist: d, node: u }) = heap.pop() {
+        if d > dist[u] {
+            continue;
+        }
+        if u == dst {
+            return d;
+        }
+        if hops[u] >= max_hops {
+            continue;
+        }
+        for e in &graph.adj[u] {
+            if e.to == avoid {
+                continue;
+            }
+            let nd = d + e.weight;
+            if nd < dist[e.to] {
+                dist[e.to] = nd;
+                hops[e.to] = hops[u] + 1;
+                heap.push(State { dist: nd, node: e.to });
+            }
+        }
+    }
+    dist[dst]
+}
+
+/// Contraction hierarchies preprocessing result: every vertex's rank, and
+/// the "up" (toward higher rank) and "down" (from higher rank, reversed)
+/// search graphs that [`ContractionHierarchy::query`] runs a bidirectional
+/// Dijkstra over.
+pub struct ContractionHierarchy {
+    n: usize,
+    pub rank: Vec<usize>,
+    up_graph: Vec<Vec<Edge>>,
+    down_graph: Vec<Vec<Edge>>,
+}
+
+fn build_live_graph(n: usize, live: &[Vec<Edge>], contracted: &[bool]) -> Graph {
+    let mut g = Graph::new(n);
+    for u in 0..n {
+        if contracted[u] {
+            continue;
+        }
+        for e in &live[u] {
+            if !contracted[e.to] {
+                g.add_edge(u, e.to, e.weight);
+            }
+        }
+    }
+    g
+}
+
+fn edge_difference(v: usize, live: &[Vec<Edge>], rev: &[Vec<Edge>], contracted: &[bool]) -> i64 {
+    let preds = rev[v].iter().filter(|e| !contracted[e.to]).count();
+    let succs = live[v].iter().filter(|e| !contracted[e.to]).count();
+    (preds * succs) as i64 - (preds + succs) as i64
+}
+
+/// Preprocesses `graph` into a [`ContractionHierarchy`]: orders vertices
+/// by a simple "edge difference" heuristic (shortcuts added minus edges
+/// removed) and contracts them one at a time, adding shortcut edges that
+/// preserve shortest-path distances among still-uncontracted neighbors.
+pub fn preprocess(graph: &Graph) -> ContractionHierarchy {
+    let n = graph.n;
+    let mut live: Vec<Vec<Edge>> = graph.adj.clone();
+    let mut rev: Vec<Vec<Edge>> = vec![Vec::new(); n];
+    for u in 0..n {
+        for e in &graph.adj[u] {
+            rev[e.to].push(Edge { to: u, weight: e.weight });
+        }
+    }
+
+    let mut contracted = vec![false; n];
+    let mut rank = vec![0usize; n];
+    let mut up_graph = vec![Vec::new(); n];
+    let mut down_graph = vec![Vec::new(); n];
+
+    let mut remaining: Vec<usize> = (0..n).collect();
+
+    for step in 0..n {
+        remaining.retain(|&v| !contracted[v]);
+        let mut best = remaining[0];
+        let mut best_score = i64::MAX;
+        for &v in &remaining {
+            let score = edge_difference(v, &live, &rev, &contracted);
+            if score < best_score {
+                best_score = score;
+                best = v;
+            }
+        }
+
+        let v = best;
+        rank[v] = step;
+        contracted[v] = true;
+
+        let witness_graph = build_live_graph(n, &live, &contracted);
+        let preds: Vec<(usize, i64)> = rev[v].iter().filter(|e| !contracted[e.to]).map(|e| (e.to, e.weight)).collect();
+        let succs: Vec<(usize, i64)> = live[v].iter().filter(|e| !contracted[e.to]).map(|e| (e.to, e.weight)).collect();
+
+        for &(p, wp) in &preds {
+            up_graph[p].push(Edge { to: v, weight: wp });
+            down_graph[v].push(Edge { to: p, weight: wp });
+        }
+        for &(s, ws) in &succs {
+            down_graph[s].push(Edge { to: v, weight: ws });
+            up_graph[v].push(Edge { to: s, weight: ws });
+        }
+
+        for &(p, wp) in &preds {
+            for &(s, ws) in &succs {
+                if p == s {
+                    continue;
+                }
+                let through_v = wp + ws;
+                let witness = bounded_dijkstra(&witness_graph, p, s, v, 5);
+                if through_v < witness {
+                    live[p].push(Edge { to: s, weight: through_v });
+                    rev[s].push(Edge { to: p, weight: through_v });
+                }
+            }
+        }
+    }
+
+    ContractionHierarchy { n, rank, up_graph, down_graph }
+}
+
+/// Builds a directed graph from a weighted edge list, for [`preprocess`]
+/// to contract. Edges given as `(u, v, weight)`; callers wanting an
+/// undirected graph should add both `(u, v, w)` and `(v, u, w)`.
+pub fn build_graph(n: usize, edges: &[(usize, usize, i64)]) -> Graph {
+    let mut graph = Graph::new(n);
+    for &(u, v, w) in edges {
+        graph.add_edge(u, v, w);
+    }
+    graph
+}
+
+impl ContractionHierarchy {
+    /// Shortest distance from `src` to `dst`, via a bidirectional search
+    /// that only relaxes edges toward higher rank — provably still finds
+    /// the true shortest path once every vertex has been contracted.
+    pub fn query(&self, src: usize, dst: usize) -> i64 {
+        let mut dist_fwd = vec![i64::MAX; self.n];
+        let mut dist_bwd = vec![i64::MAX; self.n];
+        dist_fwd[src] = 0;
+        dist_bwd[dst] = 0;
+
+        let mut fwd_heap = BinaryHeap::new();
+        fwd_heap.push(State { dist: 0, node: src });
+        let mut bwd_heap = BinaryHeap::new();
+        bwd_heap.push(State { dist: 0, node: dst });
+
+        let mut best = i64::MAX;
+
+        while !fwd_heap.is_empty() || !bwd_heap.is_empty() {
+            if let Some(State { dist: d, node: u }) = fwd_heap.pop() {
+                if d <= dist_fwd[u] {
+                    if dist_bwd[u] < i64::MAX {
+                        best = best.min(d + dist_bwd[u]);
+                    }
+                    for e in &self.up_graph[u] {
+                        let nd = d + e.weight;
+                        if nd < dist_fwd[e.to] {
+                            dist_fwd[e.to] = nd;
+                            fwd_heap.push(State { dist: nd, node: e.to });
+                        }
+                    }
+                }
+            }
+            if let Some(State { dist: d, node: u }) = bwd_heap.pop() {
+                if d <= dist_bwd[u] {
+                    if dist_fwd[u] < i64::MAX {
+                        best = best.min(d + dist_fwd[u]);
+                    }
+                    for e in &self.down_graph[u] {
+                        let nd = d + e.weight;
+                        if nd < dist_bwd[e.to] {
+                            dist_bwd[e.to] = nd;
+                            bwd_heap.push(State { dist: nd, node: e.to });
+                        }
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_dijkstra(n: usize, edges: &[(usize, usize, i64)], src: usize, dst: usize) -> i64 {
+        let graph = build_graph(n, edges);
+        bounded_dijkstra(&graph, src, dst, usize::MAX, usize::MAX)
+    }
+
+    #[test]
+    fn matches_plain_dijkstra_on_a_small_road_network() {
+        let edges = [(0, 1, 4), (1, 2, 3), (2, 3, 2), (3, 4, 1), (4, 5, 6), (0, 5, 20), (1, 4, 9)];
+        let undirected: Vec<(usize, usize, i64)> =
+            edges.iter().flat_map(|&(u, v, w)| [(u, v, w), (v, u, w)]).collect();
+        let n = 6;
+
+        let ch = preprocess(&build_graph(n, &undirected));
+        for u in 0..n {
+            for v in 0..n {
+                if u == v {
+                    continue;
+                }
+                assert_eq!(ch.query(u, v), brute_force_dijkstra(n, &undirected, u, v), "u={u} v={v}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_vertex_gets_a_distinct_rank() {
+        let edges = [(0, 1, 1), (1, 2, 1), (2, 0, 1)];
+        let undirected: Vec<(usize, usize, i64)> =
+            edges.iter().flat_map(|&(u, v, w)| [(u, v, w), (v, u, w)]).collect();
+        let ch = preprocess(&build_graph(3, &undirected));
+        let mut ranks = ch.rank.clone();
+        ranks.sort_unstable();
+        assert_eq!(ranks, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Degeneracy ordering and k-core decomposition of an undirected graph
+/// given as an adjacency list. `order` is the peeling order (repeatedly
+/// removing the lowest-degree remaining vertex); `core_number[v]` is the
+/// largest `k` such that `v` survives peeling down to a k-core. Runs in
+/// O(n + m) via a bucket queue keyed by current degree.
+pub fn degeneracy_ordering(adj: &[Vec<usize>]) -> (Vec<usize>, Vec<usize>) {
+    let n = adj.len();
+    let mut degree: Vec<usize> = adj.iter().map(|a| a.len()).collect();
+    let max_degree = degree.iter().copied().max().unwrap_or(0);
+
+    let mut bucket: Vec<Vec<usize>> = vec![Vec::new(); max_degree + 1];
+    for (v, &d) in degree.iter().enumerate() {
+        bucket[d].push(v);
+    }
+
+    let mut removed = vec![false; n];
+    let mut core_number = vec![0usize; n];
+    let mut order = Vec::with_capacity(n);
+    let mut current_min = 0usize;
+
+    for _ in 0..n {
+        while bucket[current_min].is_empty() {
+            current_min += 1;
+        }
+        let v = bucket[current_min].pop().unwrap();
+        if removed[v] {
+            continue;
+        }
+        removed[v] = true;
+        core_number[v] = current_min;
+        order.push(v);
+
+        for &u in &adj[v] {
+            if !removed[u] {
+                degree[u] -= 1;
+                bucket[degree[u]].push(u);
+                // `current_min` is a lower bound on every future core number,
+                // so it must never decrease even if a neighbor's degree
+                // drops below it — that neighbor just sits in a lower
+                // bucket until `current_min` catches back up to it.
+            }
+        }
+    }
+
+    (order, core_number)
+}
+
+/// The vertices belonging to the k-core (core number >= `k`).
+pub fn k_core(core_number: &[usize], k: usize) -> Vec<usize> {
+    (0..core_number.len()).filter(|&v| core_number[v] >= k).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_undirected(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        adj
+    }
+
+    /// Peels lowest-degree vertices one at a time with no bucket-queue
+    /// cleverness, as the ground truth `degeneracy_ordering` is checked
+    /// against.
+    fn brute_force_core_numbers(adj: &[Vec<usize>]) -> Vec<usize> {
+        let n = adj.len();
+        let mut neighbors: Vec<Vec<usize>> = adj.to_vec();
+        let mut removed = vec![false; n];
+        let mut core_number = vec![0usize; n];
+        let mut current_min = 0usize;
+
+        for _ in 0..n {
+            let v = (0..n)
+                .filter(|&v| !removed[v])
+                .min_by_key(|&v| neighbors[v].iter().filter(|&&u| !removed[u]).count())
+                .unwrap();
+            let degree = neighbors[v].iter().filter(|&&u| !removed[u]).count();
+            current_min = current_min.max(degree);
+            core_number[v] = current_min;
+            removed[v] = true;
+            for u in neighbors[v].clone() {
+                neighbors[u].retain(|&x| x != v);
+            }
+        }
+        core_number
+    }
+
+    #[test]
+    fn triangle_with_pendants_matches_expected_cores() {
+        let adj = make_undirected(5, &[(0, 1), (0, 2), (1, 2), (0, 3), (2, 4)]);
+        let (_, core_number) = degeneracy_ordering(&adj);
+        assert_eq!(core_number, vec![2, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn matches_brute_force_peeling_on_small_graphs() {
+        let edge_sets: [&[(usize, usize)]; 3] = [
+            &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)],
+            &[(0, 1), (1, 2), (2, 3), (3, 4)],
+            &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)],
+        ];
+        for edges in edge_sets {
+            let n = edges.iter().flat_map(|&(u, v)| [u, v]).max().unwrap() + 1;
+            let adj = make_undirected(n, edges);
+            let (_, core_number) = degeneracy_ordering(&adj);
+            assert_eq!(core_number, brute_force_core_numbers(&adj), "edges={edges:?}");
+        }
+    }
+
+    #[test]
+    fn k_core_filters_by_core_number() {
+        let core_number = vec![2, 2, 2, 1, 1];
+        assert_eq!(k_core(&core_number, 2), vec![0, 1, 2]);
+        assert_eq!(k_core(&core_number, 1), vec![0, 1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,21 @@
+use alloc::vec::Vec;
+
+fn dfs_util(u: usize, adj: &[Vec<usize>], visited: &mut Vec<bool>, order: &mut Vec<usize>) {
+    visited[u] = true;
+    order.push(u);
+    for &v in &adj[u] {
+        if !visited[v] {
+            dfs_util(v, adj, visited, order);
+        }
+    }
+}
+
+/// Depth-first traversal order of an unweighted adjacency list, starting
+/// from `start`.
+pub fn dfs(adj: &[Vec<usize>], start: usize) -> Vec<usize> {
+    let n = adj.len();
+    let mut visited = vec![false; n];
+    let mut order = Vec::new();
+    dfs_util(start, adj, &mut visited, &mut order);
+    order
+}
@@ -0,0 +1,103 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::types::AdjList;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    cost: i64,
+    position: usize,
+}
+
+// Implement `Ord` for min-heap using BinaryHeap
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Notice we flip the ordering here
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Dijkstra's algorithm over a non-negatively weighted adjacency list.
+/// Unreachable vertices are left at `i64::MAX`.
+pub fn dijkstra(graph: &AdjList, start: usize) -> Vec<i64> {
+    let n = graph.len();
+    let mut dist = vec![i64::MAX; n];
+    let mut heap = BinaryHeap::new();
+
+    dist[start] = 0;
+    heap.push(State { cost: 0, position: start });
+
+    while let Some(State { cost, position }) = heap.pop() {
+        if cost > dist[position] {
+            continue;
+        }
+
+        for edge in &graph[position] {
+            let next_cost = cost + edge.weight;
+            if next_cost < dist[edge.to] {
+                dist[edge.to] = next_cost;
+                heap.push(State { cost: next_cost, position: edge.to });
+            }
+        }
+    }
+
+    dist
+}
+
+/// Dijkstra's algorithm from `start` to a single `end`, also reconstructing
+/// one shortest path. Returns `None` if `end` is unreachable.
+pub fn dijkstra_with_path(graph: &AdjList, start: usize, end: usize) -> Option<(i64, Vec<usize>)> {
+    let n = graph.len();
+    if start >= n || end >= n {
+        return None;
+    }
+    let mut dist = vec![i64::MAX; n];
+    let mut prev = vec![usize::MAX; n];
+    let mut heap = BinaryHeap::new();
+
+    dist[start] = 0;
+    heap.push(State { cost: 0, position: start });
+
+    while let Some(State { cost, position }) = heap.pop() {
+        if cost > dist[position] {
+            continue;
+        }
+
+        for edge in &graph[position] {
+            let next_cost = cost + edge.weight;
+            if next_cost < dist[edge.to] {
+                dist[edge.to] = next_cost;
+                prev[edge.to] = position;
+                heap.push(State { cost: next_cost, position: edge.to });
+            }
+        }
+    }
+
+    if dist[end] == i64::MAX {
+        return None;
+    }
+
+    let mut path = vec![end];
+    let mut cur = end;
+    while cur != start {
+        // `prev` is a shortest-path tree and shouldn't cycle, but a
+        // corrupted or hand-built `graph` (e.g. via fuzzing) could still
+        // produce one via a zero-weight edge cycle; bail out rather than
+        // loop forever instead of trusting that invariant unconditionally.
+        if path.len() > n {
+            return None;
+        }
+        cur = prev[cur];
+        path.push(cur);
+    }
+    path.reverse();
+
+    Some((dist[end], path))
+}
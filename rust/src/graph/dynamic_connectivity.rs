@@ -0,0 +1,205 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::collections::rollback_dsu::RollbackDsu;
+
+const NULL: u32 = u32::MAX;
+
+/// One offline operation on the graph's edge set or connectivity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    InsertEdge(usize, usize),
+    /// Removes the edge most recently inserted between these two
+    /// vertices (edges aren't required to be distinct across time, only
+    /// non-overlapping between one insert and its matching delete).
+    DeleteEdge(usize, usize),
+    /// Were `a` and `b` connected using only edges active at this point
+    /// in the timeline?
+    Query(usize, usize),
+}
+
+/// One node of the segment tree built over the `[0, len(events))`
+/// timeline: every edge's active interval gets decomposed into the
+/// O(log T) canonical nodes covering it, same as a normal segment-tree
+/// range update, except what's stored per node is the literal list of
+/// edges rather than a combined value — there's nothing to combine, the
+/// point is to visit each edge's unions exactly when its interval is in
+/// scope during the DFS.
+struct TimeNode {
+    lo: usize,
+    hi: usize,
+    edges: Vec<(usize, usize)>,
+    left: u32,
+    right: u32,
+}
+
+struct TimeSegmentTree {
+    nodes: Vec<TimeNode>,
+    root: u32,
+}
+
+impl TimeSegmentTree {
+    fn build(len: usize) -> Self {
+        let mut tree = TimeSegmentTree { nodes: Vec::new(), root: NULL };
+        tree.root = tree.build_node(0, len);
+        tree
+    }
+
+    fn build_node(&mut self, lo: usize, hi: usize) -> u32 {
+        let (left, right) = if hi - lo == 1 {
+            (NULL, NULL)
+        } else {
+            let mid = lo + (hi - lo) / 2;
+            (self.build_node(lo, mid), self.build_node(mid, hi))
+        };
+        self.nodes.push(TimeNode { lo, hi, edges: Vec::new(), left, right });
+        (self.nodes.len() - 1) as u32
+    }
+
+    /// Adds `edge` to every canonical node covering the half-open active
+    /// interval `[l, r)`.
+    fn add_edge(&mut self, node: u32, l: usize, r: usize, edge: (usize, usize)) {
+        let (lo, hi) = (self.nodes[node as usize].lo, self.nodes[node as usize].hi);
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            self.nodes[node as usize].edges.push(edge);
+            return;
+        }
+        let (left, right) = (self.nodes[node as usize].left, self.nodes[node as usize].right);
+        self.add_edge(left, l, r, edge);
+        self.add_edge(right, l, r, edge);
+    }
+}
+
+/// Answers "were `a` and `b` connected at this point" for every
+/// [`Event::Query`] in `events`, given that edges can be inserted and
+/// later deleted over the timeline — the classic "segment tree on time"
+/// offline trick: each edge's active interval is decomposed into
+/// O(log T) segment-tree nodes up front, then one DFS over the tree
+/// unions the edges in scope on the way down and [`rollback_to`]s them
+/// on the way back up, so every leaf (one timeline position) sees
+/// exactly the DSU state for the edges active at that moment.
+///
+/// [`rollback_to`]: RollbackDsu::rollback_to
+pub fn offline_connectivity(vertices: usize, events: &[Event]) -> Vec<bool> {
+    let len = events.len();
+    if len == 0 {
+        return Vec::new();
+    }
+
+    let mut tree = TimeSegmentTree::build(len);
+    let mut open: Vec<((usize, usize), usize)> = Vec::new();
+    for (time, event) in events.iter().enumerate() {
+        match *event {
+            Event::InsertEdge(a, b) => open.push((normalize(a, b), time)),
+            Event::DeleteEdge(a, b) => {
+                let key = normalize(a, b);
+                let position = open.iter().rposition(|&(edge, _)| edge == key).expect("delete without a matching insert");
+                let (_, start) = open.remove(position);
+                tree.add_edge(tree.root, start, time, key);
+            }
+            Event::Query(..) => {}
+        }
+    }
+    for (edge, start) in open {
+        tree.add_edge(tree.root, start, len, edge);
+    }
+
+    let mut queries_at: Vec<Vec<(usize, usize, usize)>> = vec![Vec::new(); len];
+    let mut answers = Vec::new();
+    for (time, event) in events.iter().enumerate() {
+        if let Event::Query(a, b) = *event {
+            let query_index = answers.len();
+            answers.push(false);
+            queries_at[time].push((a, b, query_index));
+        }
+    }
+
+    let mut dsu = RollbackDsu::new(vertices);
+    dfs(&tree, tree.root, &queries_at, &mut dsu, &mut answers);
+    answers
+}
+
+fn normalize(a: usize, b: usize) -> (usize, usize) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn dfs(
+    tree: &TimeSegmentTree,
+    node: u32,
+    queries_at: &[Vec<(usize, usize, usize)>],
+    dsu: &mut RollbackDsu,
+    answers: &mut [bool],
+) {
+    let snapshot = dsu.snapshot();
+    for &(a, b) in &tree.nodes[node as usize].edges {
+        dsu.union(a, b);
+    }
+
+    let (lo, hi, left, right) = {
+        let n = &tree.nodes[node as usize];
+        (n.lo, n.hi, n.left, n.right)
+    };
+    if hi - lo == 1 {
+        for &(a, b, query_index) in &queries_at[lo] {
+            answers[query_index] = dsu.connected(a, b);
+        }
+    } else {
+        dfs(tree, left, queries_at, dsu, answers);
+        dfs(tree, right, queries_at, dsu, answers);
+    }
+
+    dsu.rollback_to(snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_queries_around_an_edge_that_comes_and_goes() {
+        let events = vec![
+            Event::Query(0, 1),   // not connected yet
+            Event::InsertEdge(0, 1),
+            Event::Query(0, 1),   // connected now
+            Event::DeleteEdge(0, 1),
+            Event::Query(0, 1),   // disconnected again
+        ];
+        assert_eq!(offline_connectivity(2, &events), vec![false, true, false]);
+    }
+
+    #[test]
+    fn separate_components_never_connect_without_a_bridging_edge() {
+        let events = vec![
+            Event::InsertEdge(0, 1),
+            Event::InsertEdge(2, 3),
+            Event::Query(0, 1),
+            Event::Query(0, 2),
+            Event::InsertEdge(1, 2),
+            Event::Query(0, 3),
+        ];
+        assert_eq!(offline_connectivity(4, &events), vec![true, false, true]);
+    }
+
+    #[test]
+    fn overlapping_edge_lifetimes_are_each_respected_independently() {
+        let events = vec![
+            Event::InsertEdge(0, 1),
+            Event::InsertEdge(1, 2),
+            Event::Query(0, 2),
+            Event::DeleteEdge(0, 1),
+            Event::Query(0, 2),
+            Event::InsertEdge(0, 1),
+            Event::Query(0, 2),
+            Event::DeleteEdge(1, 2),
+            Event::Query(0, 2),
+        ];
+        assert_eq!(offline_connectivity(3, &events), vec![true, false, true, false]);
+    }
+}
@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+
+use super::types::WeightedEdge;
+
+/// All-pairs shortest paths by successive relaxation through each
+/// possible intermediate vertex. Returns `dist[u][v]`, with
+/// `f64::INFINITY` where `v` is unreachable from `u`. Does not detect
+/// negative-weight cycles; distances through one are meaningless.
+pub fn floyd_warshall(vertices: usize, edges: &[WeightedEdge]) -> Vec<Vec<f64>> {
+    let mut dist = vec![vec![f64::INFINITY; vertices]; vertices];
+    for (v, row) in dist.iter_mut().enumerate() {
+        row[v] = 0.0;
+    }
+    for edge in edges {
+        let w = edge.weight as f64;
+        if w < dist[edge.from][edge.to] {
+            dist[edge.from][edge.to] = w;
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)] // each index walks a different axis of `dist`
+    for k in 0..vertices {
+        for i in 0..vertices {
+            if dist[i][k].is_infinite() {
+                continue;
+            }
+            for j in 0..vertices {
+                let via_k = dist[i][k] + dist[k][j];
+                if via_k < dist[i][j] {
+                    dist[i][j] = via_k;
+                }
+            }
+        }
+    }
+    dist
+}
@@ -0,0 +1,190 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy)]
+struct Edge {
+    to: usize,
+    cap: i64,
+    flow: i64,
+}
+
+struct Dinic {
+    n: usize,
+    edges: Vec<Edge>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl Dinic {
+    fn new(n: usize) -> Self {
+        Dinic { n, edges: Vec::new(), adj: vec![Vec::new(); n] }
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, cap: i64) {
+        let idx = self.edges.len();
+        self.edges.push(Edge { to, cap, flow: 0 });
+        self.adj[from].push(idx);
+        self.edges.push(Edge { to: from, cap, flow: 0 }); // undirected: same capacity both ways
+        self.adj[to].push(idx + 1);
+    }
+
+    fn bfs(&self, src: usize, sink: usize, level: &mut [i32]) -> bool {
+        level.fill(-1);
+        level[src] = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(src);
+        while let Some(u) = queue.pop_front() {
+            for &idx in &self.adj[u] {
+                let e = self.edges[idx];
+                if level[e.to] < 0 && e.cap - e.flow > 0 {
+                    level[e.to] = level[u] + 1;
+                    queue.push_back(e.to);
+                }
+            }
+        }
+        level[sink] >= 0
+    }
+
+    fn dfs(&mut self, u: usize, sink: usize, pushed: i64, level: &[i32], it: &mut [usize]) -> i64 {
+        if u == sink || pushed == 0 {
+            return pushed;
+        }
+        while it[u] < self.adj[u].len() {
+            let idx = self.adj[u][it[u]];
+            let e = self.edges[idx];
+            if level[e.to] == level[u] + 1 && e.cap - e.flow > 0 {
+                let d = self.dfs(e.to, sink, pushed.min(e.cap - e.flow), level, it);
+                if d > 0 {
+                    self.edges[idx].flow += d;
+                    self.edges[idx ^ 1].flow -= d;
+                    return d;
+                }
+            }
+            it[u] += 1;
+        }
+        0
+    }
+
+    fn max_flow(&mut self, src: usize, sink: usize) -> i64 {
+        for e in self.edges.iter_mut() {
+            e.flow = 0;
+        }
+        let mut total = 0;
+        let mut level = vec![-1; self.n];
+        while self.bfs(src, sink, &mut level) {
+            let mut it = vec![0usize; self.n];
+            loop {
+                let pushed = self.dfs(src, sink, i64::MAX, &level, &mut it);
+                if pushed == 0 {
+                    break;
+                }
+                total += pushed;
+            }
+        }
+        total
+    }
+
+    /// Vertices reachable from `src` in the residual graph after `max_flow`.
+    fn reachable_from(&self, src: usize) -> Vec<bool> {
+        let mut visited = vec![false; self.n];
+        visited[src] = true;
+        let mut stack = vec![src];
+        while let Some(u) = stack.pop() {
+            for &idx in &self.adj[u] {
+                let e = self.edges[idx];
+                if !visited[e.to] && e.cap - e.flow > 0 {
+                    visited[e.to] = true;
+                    stack.push(e.to);
+                }
+            }
+        }
+        visited
+    }
+}
+
+/// Builds the Gomory-Hu tree of an undirected weighted graph on `n`
+/// vertices given as a weighted edge list: a weighted tree on the same
+/// vertices such that the min cut between any two vertices equals the
+/// minimum edge weight on the tree path between them. Returns the tree as
+/// a list of `(parent, child, weight)` triples, built with n-1 max-flow
+/// computations (Dinic's algorithm) rather than the naive O(n^2).
+pub fn gomory_hu_tree(n: usize, edges: &[(usize, usize, i64)]) -> Vec<(usize, usize, i64)> {
+    let mut parent = vec![0usize; n];
+    let mut weight = vec![0i64; n];
+
+    for i in 1..n {
+        let mut dinic = Dinic::new(n);
+        for &(u, v, c) in edges {
+            dinic.add_edge(u, v, c);
+        }
+        let flow = dinic.max_flow(i, parent[i]);
+        weight[i] = flow;
+
+        let reachable = dinic.reachable_from(i);
+        for j in (i + 1)..n {
+            if reachable[j] && parent[j] == parent[i] {
+                parent[j] = i;
+            }
+        }
+    }
+
+    (1..n).map(|i| (parent[i], i, weight[i])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree_path_min(tree: &[(usize, usize, i64)], n: usize, u: usize, v: usize) -> i64 {
+        let mut adj: Vec<Vec<(usize, i64)>> = vec![Vec::new(); n];
+        for &(p, c, w) in tree {
+            adj[p].push((c, w));
+            adj[c].push((p, w));
+        }
+        let mut dist = vec![i64::MAX; n];
+        let mut min_on_path = vec![i64::MAX; n];
+        dist[u] = 0;
+        let mut stack = vec![u];
+        while let Some(x) = stack.pop() {
+            for &(y, w) in &adj[x] {
+                if dist[y] == i64::MAX {
+                    dist[y] = dist[x] + 1;
+                    min_on_path[y] = min_on_path[x].min(w);
+                    stack.push(y);
+                }
+            }
+        }
+        min_on_path[v]
+    }
+
+    fn max_flow_between(n: usize, edges: &[(usize, usize, i64)], s: usize, t: usize) -> i64 {
+        let mut dinic = Dinic::new(n);
+        for &(u, v, c) in edges {
+            dinic.add_edge(u, v, c);
+        }
+        dinic.max_flow(s, t)
+    }
+
+    #[test]
+    fn tree_path_minimum_matches_pairwise_min_cut() {
+        let edges = vec![(0, 1, 1), (0, 2, 7), (1, 2, 1), (1, 3, 3), (2, 3, 2)];
+        let n = 4;
+        let tree = gomory_hu_tree(n, &edges);
+        for u in 0..n {
+            for v in (u + 1)..n {
+                assert_eq!(
+                    tree_path_min(&tree, n, u, v),
+                    max_flow_between(n, &edges, u, v),
+                    "u={u} v={v}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn single_edge_graph_has_one_tree_edge() {
+        let edges = vec![(0, 1, 5)];
+        let tree = gomory_hu_tree(2, &edges);
+        assert_eq!(tree, vec![(0, 1, 5)]);
+    }
+}
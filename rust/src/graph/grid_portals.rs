@@ -0,0 +1,186 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// One cell of a [`Grid`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Cell {
+    Open,
+    Wall,
+    /// Passable, but [`StepCost::step`] implementations are free to
+    /// charge extra for walking through it (e.g. a "death").
+    Hazard,
+}
+
+/// A grid of [`Cell`]s plus zero or more teleporter pairs: stepping onto
+/// one end of a pair moves you to the other end for the same
+/// [`StepCost::step`] price as a normal move, regardless of distance.
+pub struct Grid {
+    cells: Vec<Vec<Cell>>,
+    portal_of: Vec<Vec<Option<(usize, usize)>>>,
+}
+
+impl Grid {
+    pub fn new(cells: Vec<Vec<Cell>>) -> Self {
+        let portal_of = cells.iter().map(|row| vec![None; row.len()]).collect();
+        Grid { cells, portal_of }
+    }
+
+    /// Links two cells as a bidirectional teleporter pair.
+    pub fn add_portal(&mut self, a: (usize, usize), b: (usize, usize)) {
+        self.portal_of[a.0][a.1] = Some(b);
+        self.portal_of[b.0][b.1] = Some(a);
+    }
+
+    fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+}
+
+/// A move's cost, accumulated lexicographically (e.g. minimize deaths,
+/// then minimize steps among paths with equally few deaths) by deriving
+/// `Ord` over the fields in priority order — the same trick a plain
+/// tuple's derived `Ord` already gives for free, packaged as a trait so
+/// [`shortest_path`] can plug in whatever criteria a caller needs without
+/// hardcoding "deaths then steps" into the search itself.
+pub trait StepCost: Ord + Copy {
+    /// The cost of not having moved yet.
+    fn zero() -> Self;
+    /// The cost of stepping onto `cell`, added to the cost so far.
+    fn step(self, cell: Cell) -> Self;
+}
+
+/// Minimizes deaths (walking through a [`Cell::Hazard`]) first, breaking
+/// ties by fewest steps.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct DeathsThenSteps {
+    pub deaths: u32,
+    pub steps: u32,
+}
+
+impl StepCost for DeathsThenSteps {
+    fn zero() -> Self {
+        DeathsThenSteps { deaths: 0, steps: 0 }
+    }
+
+    fn step(self, cell: Cell) -> Self {
+        DeathsThenSteps { deaths: self.deaths + (cell == Cell::Hazard) as u32, steps: self.steps + 1 }
+    }
+}
+
+/// Dijkstra over `grid`'s 4-directionally-connected open/hazard cells,
+/// plus an extra zero-distance-but-not-zero-cost edge at every
+/// teleporter, generic over the [`StepCost`] so the same search answers
+/// both a plain "fewest steps" query and a lexicographic multi-criteria
+/// one. Returns `None` if `end` is unreachable.
+pub fn shortest_path<C: StepCost>(grid: &Grid, start: (usize, usize), end: (usize, usize)) -> Option<C> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let mut best: Vec<Vec<Option<C>>> = vec![vec![None; cols]; rows];
+
+    let mut heap = BinaryHeap::new();
+    best[start.0][start.1] = Some(C::zero());
+    heap.push(Reverse((C::zero(), start)));
+
+    while let Some(Reverse((cost, position))) = heap.pop() {
+        if best[position.0][position.1] != Some(cost) {
+            continue;
+        }
+        if position == end {
+            return Some(cost);
+        }
+
+        for next in neighbors(grid, position) {
+            if grid.cells[next.0][next.1] == Cell::Wall {
+                continue;
+            }
+            let next_cost = cost.step(grid.cells[next.0][next.1]);
+            let improves = match best[next.0][next.1] {
+                Some(current) => next_cost < current,
+                None => true,
+            };
+            if improves {
+                best[next.0][next.1] = Some(next_cost);
+                heap.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    best[end.0][end.1]
+}
+
+fn neighbors(grid: &Grid, position: (usize, usize)) -> Vec<(usize, usize)> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let (r, c) = position;
+    let mut result = Vec::with_capacity(5);
+    if r > 0 {
+        result.push((r - 1, c));
+    }
+    if r + 1 < rows {
+        result.push((r + 1, c));
+    }
+    if c > 0 {
+        result.push((r, c - 1));
+    }
+    if c + 1 < cols {
+        result.push((r, c + 1));
+    }
+    if let Some(portal) = grid.portal_of[r][c] {
+        result.push(portal);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_grid_finds_the_manhattan_shortest_path() {
+        let grid = Grid::new(vec![vec![Cell::Open; 4]; 4]);
+        let cost = shortest_path::<DeathsThenSteps>(&grid, (0, 0), (3, 3)).unwrap();
+        assert_eq!(cost, DeathsThenSteps { deaths: 0, steps: 6 });
+    }
+
+    #[test]
+    fn walls_force_a_detour() {
+        let mut cells = vec![vec![Cell::Open; 3]; 3];
+        cells[0][1] = Cell::Wall;
+        cells[1][1] = Cell::Wall;
+        let grid = Grid::new(cells);
+        let cost = shortest_path::<DeathsThenSteps>(&grid, (0, 0), (0, 2)).unwrap();
+        assert_eq!(cost, DeathsThenSteps { deaths: 0, steps: 6 });
+    }
+
+    #[test]
+    fn portal_shortcuts_past_a_long_detour() {
+        let cells = vec![vec![Cell::Open; 5]; 1];
+        let mut grid = Grid::new(cells);
+        grid.add_portal((0, 0), (0, 4));
+        let cost = shortest_path::<DeathsThenSteps>(&grid, (0, 0), (0, 4)).unwrap();
+        assert_eq!(cost, DeathsThenSteps { deaths: 0, steps: 1 });
+    }
+
+    #[test]
+    fn lexicographic_cost_prefers_fewer_deaths_over_fewer_steps() {
+        let mut cells = vec![vec![Cell::Open; 3]; 1];
+        cells[0][1] = Cell::Hazard;
+        let grid = Grid::new(cells);
+        let cost = shortest_path::<DeathsThenSteps>(&grid, (0, 0), (0, 2)).unwrap();
+        assert_eq!(cost, DeathsThenSteps { deaths: 1, steps: 2 });
+    }
+
+    #[test]
+    fn unreachable_end_returns_none() {
+        let mut cells = vec![vec![Cell::Open; 3]; 3];
+        for cell in cells[1].iter_mut() {
+            *cell = Cell::Wall;
+        }
+        let grid = Grid::new(cells);
+        assert_eq!(shortest_path::<DeathsThenSteps>(&grid, (0, 0), (2, 0)), None);
+    }
+}
@@ -0,0 +1,40 @@
+use super::bellman_ford::bellman_ford;
+use super::dijkstra::dijkstra;
+use super::types::{AdjList, Edge, WeightedEdge};
+
+/// Johnson's all-pairs shortest paths: reweights the graph with
+/// Bellman-Ford from an added source so every edge becomes non-negative,
+/// then runs Dijkstra from each original vertex. Returns `None` if the
+/// graph has a negative-weight cycle.
+pub fn johnson(n: usize, edges: &[WeightedEdge]) -> Option<Vec<Vec<i64>>> {
+    // Add a virtual vertex `n` with a zero-weight edge to every vertex.
+    let mut augmented: Vec<WeightedEdge> = edges.to_vec();
+    for v in 0..n {
+        augmented.push(WeightedEdge { from: n, to: v, weight: 0 });
+    }
+    let h = bellman_ford(n + 1, &augmented, n)?;
+
+    // Reweight: w'(u, v) = w(u, v) + h[u] - h[v] >= 0.
+    let mut adj: AdjList = vec![Vec::new(); n];
+    for edge in edges {
+        let reweighted = edge.weight + (h[edge.from] - h[edge.to]).round() as i64;
+        adj[edge.from].push(Edge { to: edge.to, weight: reweighted });
+    }
+
+    let mut distances = Vec::with_capacity(n);
+    for src in 0..n {
+        let reweighted_dist = dijkstra(&adj, src);
+        let real_dist: Vec<i64> = (0..n)
+            .map(|v| {
+                if reweighted_dist[v] == i64::MAX {
+                    i64::MAX
+                } else {
+                    reweighted_dist[v] - (h[src] - h[v]).round() as i64
+                }
+            })
+            .collect();
+        distances.push(real_dist);
+    }
+
+    Some(distances)
+}
@@ -0,0 +1,30 @@
+//! Graph algorithms: traversal, shortest paths, and the shared
+//! adjacency-list / edge-list types they're built on.
+
+pub mod arborescence;
+pub mod bellman_ford;
+pub mod bfs;
+pub mod contraction_hierarchies;
+pub mod degeneracy_kcore;
+pub mod dfs;
+pub mod dijkstra;
+pub mod dominator_tree;
+pub mod dynamic_connectivity;
+pub mod floyd_warshall;
+pub mod gomory_hu;
+pub mod grid_portals;
+// `.round()` is a libm float op `core` doesn't provide, so this module is
+// unavailable in the `#![no_std]` build (see the crate root docs).
+#[cfg(feature = "std")]
+pub mod johnson;
+pub mod min_cost_flow;
+pub mod parse;
+pub mod push_relabel;
+pub mod random_graph_gen;
+pub mod shortest_path;
+// `f64::sqrt` is a libm call `core` doesn't provide, so this module is
+// unavailable in the `#![no_std]` build (see the crate root docs).
+#[cfg(feature = "std")]
+pub mod spectral;
+pub mod stoer_wagner;
+pub mod types;
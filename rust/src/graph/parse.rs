@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+
+use super::types::WeightedEdge;
+
+/// Parses a graph from a simple edge-list text format: one edge per
+/// line, `from to weight`, whitespace-separated. Blank lines and lines
+/// starting with `#` are ignored; malformed lines are skipped rather
+/// than aborting the whole parse, since this is meant to tolerate
+/// hand-edited or generated input files.
+pub fn parse_edge_list(input: &str) -> Vec<WeightedEdge> {
+    let mut edges = Vec::new();
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let (Some(from), Some(to), Some(weight)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let (Ok(from), Ok(to), Ok(weight)) = (from.parse(), to.parse(), weight.parse()) else {
+            continue;
+        };
+        edges.push(WeightedEdge { from, to, weight });
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_lines() {
+        let edges = parse_edge_list("# a tiny graph\n0 1 4\n1 2 -3\n\n2 0 0\n");
+        assert_eq!(edges.len(), 3);
+        assert_eq!((edges[1].from, edges[1].to, edges[1].weight), (1, 2, -3));
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let edges = parse_edge_list("0 1 4\nnot an edge\n1\n2 3 x\n3 4 5\n");
+        assert_eq!(edges.len(), 2);
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::collections::{BTreeSet, BinaryHeap};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// Small xorshift RNG, seeded so a given seed always reproduces the same
+/// graph — what the generators below need for benchmarking and
+/// regression tests elsewhere in this crate.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed ^ 0x9E3779B97F4A7C15)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn gen_range(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Erdos-Renyi G(n, p): each of the n*(n-1)/2 possible undirected edges
+/// is included independently with probability `p`.
+pub fn erdos_renyi(n: usize, p: f64, seed: u64) -> Vec<(usize, usize)> {
+    let mut rng = Rng::new(seed);
+    let mut edges = Vec::new();
+    for u in 0..n {
+        for v in (u + 1)..n {
+            if rng.next_f64() < p {
+                edges.push((u, v));
+            }
+        }
+    }
+    edges
+}
+
+/// A uniformly random labeled tree on `n` vertices, generated from a
+/// uniformly random Prufer sequence of length `n - 2`.
+pub fn random_tree(n: usize, seed: u64) -> Vec<(usize, usize)> {
+    if n <= 1 {
+        return Vec::new();
+    }
+    if n == 2 {
+        return alloc::vec![(0, 1)];
+    }
+
+    let mut rng = Rng::new(seed);
+    let prufer: Vec<usize> = (0..n - 2).map(|_| rng.gen_range(n)).collect();
+
+    let mut degree = alloc::vec![1i64; n];
+    for &v in &prufer {
+        degree[v] += 1;
+    }
+
+    let mut edges = Vec::with_capacity(n - 1);
+    let mut leaves: BinaryHeap<Reverse<usize>> =
+        degree.iter().enumerate().filter(|&(_, &d)| d == 1).map(|(i, _)| Reverse(i)).collect();
+
+    for &v in &prufer {
+        let Reverse(leaf) = leaves.pop().unwrap();
+        edges.push((leaf, v));
+        degree[v] -= 1;
+        if degree[v] == 1 {
+            leaves.push(Reverse(v));
+        }
+    }
+
+    // Exactly two degree-1 vertices remain; connect them.
+    let Reverse(a) = leaves.pop().unwrap();
+    let Reverse(b) = leaves.pop().unwrap();
+    edges.push((a, b));
+
+    edges
+}
+
+/// Barabasi-Albert preferential attachment: starts from a small clique of
+/// `m` vertices, then each new vertex attaches to `m` existing vertices
+/// chosen with probability proportional to their current degree.
+pub fn barabasi_albert(n: usize, m: usize, seed: u64) -> Vec<(usize, usize)> {
+    let mut rng = Rng::new(seed);
+    let mut edges = Vec::new();
+    let mut endpoints = Vec::new(); // each edge contributes both endpoints, so sampling uniformly from here is degree-proportional
+
+    for u in 0..m.min(n) {
+        for v in 0..u {
+            edges.push((u, v));
+            endpoints.push(u);
+            endpoints.push(v);
+        }
+    }
+
+    for v in m..n {
+        let mut targets = BTreeSet::new();
+        while targets.len() < m.min(v) {
+            let idx = rng.gen_range(endpoints.len());
+            targets.insert(endpoints[idx]);
+        }
+        for &u in &targets {
+            edges.push((v, u));
+            endpoints.push(v);
+            endpoints.push(u);
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erdos_renyi_is_deterministic_per_seed_and_respects_extremes() {
+        assert_eq!(erdos_renyi(8, 0.3, 1), erdos_renyi(8, 0.3, 1));
+        assert!(erdos_renyi(6, 0.0, 1).is_empty());
+        assert_eq!(erdos_renyi(5, 1.0, 1).len(), 5 * 4 / 2);
+    }
+
+    #[test]
+    fn random_tree_has_n_minus_one_edges_and_connects_everything() {
+        for seed in 0..5 {
+            let n = 10;
+            let edges = random_tree(n, seed);
+            assert_eq!(edges.len(), n - 1);
+
+            let mut adj = alloc::vec![Vec::new(); n];
+            for &(u, v) in &edges {
+                adj[u].push(v);
+                adj[v].push(u);
+            }
+            let mut visited = alloc::vec![false; n];
+            visited[0] = true;
+            let mut stack = alloc::vec![0];
+            let mut count = 1;
+            while let Some(x) = stack.pop() {
+                for &y in &adj[x] {
+                    if !visited[y] {
+                        visited[y] = true;
+                        count += 1;
+                        stack.push(y);
+                    }
+                }
+            }
+            assert_eq!(count, n, "seed={seed}");
+        }
+    }
+
+    #[test]
+    fn barabasi_albert_grows_by_exactly_m_edges_per_new_vertex() {
+        let n = 10;
+        let m = 2;
+        let edges = barabasi_albert(n, m, 1);
+        // The initial clique contributes m*(m-1)/2 edges; each of the
+        // remaining n - m vertices adds exactly m more.
+        assert_eq!(edges.len(), m * (m - 1) / 2 + (n - m) * m);
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use super::bellman_ford::bellman_ford;
+use super::dijkstra::dijkstra;
+use super::floyd_warshall::floyd_warshall;
+use super::types::{AdjList, WeightedEdge};
+
+/// A common interface over shortest-path algorithms, so callers can swap
+/// implementations (based on whether weights can be negative, or whether
+/// they need all sources at once) without changing call sites. Distances
+/// are `f64`, with `f64::INFINITY` for unreachable vertices; `None`
+/// signals a negative-weight cycle reachable from the source.
+pub trait ShortestPath {
+    fn shortest_paths(&self, source: usize) -> Option<Vec<f64>>;
+}
+
+/// Dijkstra's algorithm, for graphs with only non-negative weights.
+pub struct DijkstraSp<'a> {
+    pub graph: &'a AdjList,
+}
+
+impl ShortestPath for DijkstraSp<'_> {
+    fn shortest_paths(&self, source: usize) -> Option<Vec<f64>> {
+        Some(
+            dijkstra(self.graph, source)
+                .into_iter()
+                .map(|d| if d == i64::MAX { f64::INFINITY } else { d as f64 })
+                .collect(),
+        )
+    }
+}
+
+/// Bellman-Ford, for graphs that may have negative weights (but not a
+/// negative-weight cycle reachable from the source).
+pub struct BellmanFordSp<'a> {
+    pub vertices: usize,
+    pub edges: &'a [WeightedEdge],
+}
+
+impl ShortestPath for BellmanFordSp<'_> {
+    fn shortest_paths(&self, source: usize) -> Option<Vec<f64>> {
+        bellman_ford(self.vertices, self.edges, source)
+    }
+}
+
+/// Floyd-Warshall, computing all-pairs distances and slicing out the row
+/// for `source`. Wasteful if only one source is ever needed, but handy
+/// when the same graph will be queried from many sources.
+pub struct FloydWarshallSp<'a> {
+    pub vertices: usize,
+    pub edges: &'a [WeightedEdge],
+}
+
+impl ShortestPath for FloydWarshallSp<'_> {
+    fn shortest_paths(&self, source: usize) -> Option<Vec<f64>> {
+        Some(floyd_warshall(self.vertices, self.edges)[source].clone())
+    }
+}
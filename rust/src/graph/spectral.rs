@@ -0,0 +1,230 @@
+// Spectral graph utilities: building the adjacency/Laplacian matrices
+// that describe a graph's structure algebraically, and power iteration
+// for reading the dominant behavior (or, via deflation, the Fiedler
+// vector) back out of them without ever computing a full eigendecomposition.
+//
+// Needs `f64::sqrt`, a libm call `core` doesn't provide, so (like
+// `graph::johnson`) this module stays behind the `std` feature.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::types::{AdjList, Edge, WeightedEdge};
+
+/// Builds a dense, symmetric adjacency matrix (treating every edge as
+/// undirected, as spectral graph theory assumes): `adj[u][v] = weight` of
+/// the `u`-`v` edge, `0.0` if none.
+pub fn adjacency_matrix(vertices: usize, edges: &[WeightedEdge]) -> Vec<Vec<f64>> {
+    let mut adj = vec![vec![0.0; vertices]; vertices];
+    for edge in edges {
+        adj[edge.from][edge.to] = edge.weight as f64;
+        adj[edge.to][edge.from] = edge.weight as f64;
+    }
+    adj
+}
+
+/// Builds the dense graph Laplacian `L = D - A`, where `D` is the diagonal
+/// degree matrix (weighted degree: the sum of incident edge weights) and
+/// `A` is [`adjacency_matrix`].
+pub fn laplacian_matrix(vertices: usize, edges: &[WeightedEdge]) -> Vec<Vec<f64>> {
+    let adj = adjacency_matrix(vertices, edges);
+    let mut laplacian = adj.iter().map(|row| row.iter().map(|&w| -w).collect::<Vec<_>>()).collect::<Vec<_>>();
+    for (v, row) in laplacian.iter_mut().enumerate() {
+        row[v] = adj[v].iter().sum();
+    }
+    laplacian
+}
+
+/// The graph Laplacian in adjacency-list form: `L * v` can be computed in
+/// O(edges) via [`mul`](Self::mul) without ever materializing the dense
+/// `vertices x vertices` matrix, the representation power iteration
+/// actually wants for a sparse graph.
+pub struct SparseLaplacian {
+    degree: Vec<f64>,
+    adjacency: AdjList,
+}
+
+impl SparseLaplacian {
+    pub fn from_edges(vertices: usize, edges: &[WeightedEdge]) -> Self {
+        let mut adjacency: AdjList = vec![Vec::new(); vertices];
+        let mut degree = vec![0.0; vertices];
+        for edge in edges {
+            let w = edge.weight as f64;
+            adjacency[edge.from].push(Edge { to: edge.to, weight: edge.weight });
+            adjacency[edge.to].push(Edge { to: edge.from, weight: edge.weight });
+            degree[edge.from] += w;
+            degree[edge.to] += w;
+        }
+        SparseLaplacian { degree, adjacency }
+    }
+
+    pub fn vertices(&self) -> usize {
+        self.degree.len()
+    }
+
+    pub fn max_degree(&self) -> f64 {
+        self.degree.iter().copied().fold(0.0, f64::max)
+    }
+
+    /// `L * v = D * v - A * v`.
+    pub fn mul(&self, v: &[f64]) -> Vec<f64> {
+        (0..self.vertices())
+            .map(|u| {
+                let neighbor_sum: f64 = self.adjacency[u].iter().map(|e| e.weight as f64 * v[e.to]).sum();
+                self.degree[u] * v[u] - neighbor_sum
+            })
+            .collect()
+    }
+}
+
+fn normalize(v: &mut [f64]) {
+    let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for x in v.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
+/// Power iteration: given a symmetric matrix's `mul` (matrix-vector
+/// product) and dimension `n`, repeatedly applies and renormalizes a
+/// vector until it converges to the eigenvector of the largest-magnitude
+/// eigenvalue, reading that eigenvalue off via the Rayleigh quotient
+/// `v^T M v` on the final (unit-length) vector.
+pub fn power_iteration(n: usize, mul: impl Fn(&[f64]) -> Vec<f64>, iterations: usize) -> (f64, Vec<f64>) {
+    let mut v: Vec<f64> = (0..n).map(|i| (i + 1) as f64).collect();
+    normalize(&mut v);
+
+    for _ in 0..iterations {
+        v = mul(&v);
+        normalize(&mut v);
+    }
+
+    let mv = mul(&v);
+    let eigenvalue: f64 = v.iter().zip(&mv).map(|(a, b)| a * b).sum();
+    (eigenvalue, v)
+}
+
+/// Splits a connected graph into two roughly-equal halves by the sign of
+/// its Fiedler vector (the eigenvector of the Laplacian's second-smallest
+/// eigenvalue), the classic spectral-bisection heuristic: vertices on the
+/// same side of that eigenvector's zero crossing tend to be more densely
+/// connected to each other than across the split.
+///
+/// The Laplacian's *smallest* eigenvalue is always `0`, with eigenvector
+/// the all-ones vector, so finding the second-smallest by power iteration
+/// needs a shift-and-deflate trick: running power iteration on
+/// `shift * I minus L` (for a `shift` at least the largest Laplacian
+/// eigenvalue) finds that matrix's dominant eigenvector, which is `L`'s
+/// *smallest* eigenvalue and would just re-find the all-ones vector, so
+/// every iteration also projects the current vector orthogonal to
+/// all-ones first, converging instead to the second-smallest eigenvalue's
+/// eigenvector.
+pub fn spectral_bisection(vertices: usize, edges: &[WeightedEdge]) -> (Vec<usize>, Vec<usize>) {
+    let laplacian = SparseLaplacian::from_edges(vertices, edges);
+    let shift = 2.0 * laplacian.max_degree().max(1.0);
+
+    let mut v: Vec<f64> = (0..vertices).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+    deflate_and_normalize(&mut v);
+
+    for _ in 0..200 {
+        let lv = laplacian.mul(&v);
+        v = v.iter().zip(&lv).map(|(&vi, &lvi)| shift * vi - lvi).collect();
+        deflate_and_normalize(&mut v);
+    }
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for (i, &value) in v.iter().enumerate() {
+        if value >= 0.0 {
+            left.push(i);
+        } else {
+            right.push(i);
+        }
+    }
+    (left, right)
+}
+
+/// Subtracts the mean (projecting out the all-ones direction) before
+/// normalizing to unit length.
+fn deflate_and_normalize(v: &mut [f64]) {
+    let mean = v.iter().sum::<f64>() / v.len() as f64;
+    for x in v.iter_mut() {
+        *x -= mean;
+    }
+    normalize(v);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_graph(vertices: usize) -> Vec<WeightedEdge> {
+        (0..vertices - 1).map(|i| WeightedEdge { from: i, to: i + 1, weight: 1 }).collect()
+    }
+
+    #[test]
+    fn laplacian_rows_sum_to_zero() {
+        let edges = path_graph(5);
+        let laplacian = laplacian_matrix(5, &edges);
+        for row in &laplacian {
+            assert!((row.iter().sum::<f64>()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn sparse_laplacian_matches_dense_matrix_vector_product() {
+        let edges = path_graph(6);
+        let dense = laplacian_matrix(6, &edges);
+        let sparse = SparseLaplacian::from_edges(6, &edges);
+
+        let v = [1.0, 2.0, -1.0, 0.5, 3.0, -2.0];
+        let expected: Vec<f64> = dense.iter().map(|row| row.iter().zip(&v).map(|(a, b)| a * b).sum()).collect();
+        let actual = sparse.mul(&v);
+
+        for (a, b) in expected.iter().zip(&actual) {
+            assert!((a - b).abs() < 1e-9, "{a} vs {b}");
+        }
+    }
+
+    #[test]
+    fn power_iteration_finds_the_dominant_eigenvalue_of_a_triangle() {
+        // A triangle's adjacency matrix has eigenvalues 2, -1, -1: a
+        // single dominant eigenvalue power iteration converges to
+        // cleanly, unlike a bipartite graph's symmetric +/- spectrum.
+        let edges = [
+            WeightedEdge { from: 0, to: 1, weight: 1 },
+            WeightedEdge { from: 1, to: 2, weight: 1 },
+            WeightedEdge { from: 2, to: 0, weight: 1 },
+        ];
+        let adj = adjacency_matrix(3, &edges);
+        let (eigenvalue, _) = power_iteration(3, |v| adj.iter().map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum()).collect(), 100);
+        assert!((eigenvalue - 2.0).abs() < 1e-6, "eigenvalue={eigenvalue}");
+    }
+
+    #[test]
+    fn spectral_bisection_splits_two_disjoint_triangles_along_the_cut() {
+        // Two triangles (0,1,2) and (3,4,5) joined by a single bridge
+        // edge: the only sensible 2-way split is exactly along the bridge.
+        let mut edges = vec![
+            WeightedEdge { from: 0, to: 1, weight: 1 },
+            WeightedEdge { from: 1, to: 2, weight: 1 },
+            WeightedEdge { from: 2, to: 0, weight: 1 },
+            WeightedEdge { from: 3, to: 4, weight: 1 },
+            WeightedEdge { from: 4, to: 5, weight: 1 },
+            WeightedEdge { from: 5, to: 3, weight: 1 },
+        ];
+        edges.push(WeightedEdge { from: 0, to: 3, weight: 1 });
+
+        let (left, right) = spectral_bisection(6, &edges);
+        let (small, large) = if left.len() <= right.len() { (left, right) } else { (right, left) };
+        assert_eq!(small.len(), 3);
+        assert_eq!(large.len(), 3);
+        for &triangle in &[[0usize, 1, 2], [3, 4, 5]] {
+            assert!(
+                triangle.iter().all(|v| small.contains(v)) || triangle.iter().all(|v| large.contains(v)),
+                "triangle {triangle:?} was split across the cut"
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// Shared graph representations so shortest-path algorithms don't each
+// reinvent their own `Edge`/adjacency types.
+
+use alloc::vec::Vec;
+
+/// One out-edge in an adjacency list: the neighbor and the edge weight.
+#[derive(Debug, Clone, Copy)]
+pub struct Edge {
+    pub to: usize,
+    pub weight: i64,
+}
+
+/// Adjacency-list representation: `adj[u]` is the list of edges out of `u`.
+pub type AdjList = Vec<Vec<Edge>>;
+
+/// One edge in edge-list form, used by algorithms (Bellman-Ford, Johnson's)
+/// that relax every edge directly rather than walking an adjacency list.
+#[derive(Debug, Clone, Copy)]
+pub struct WeightedEdge {
+    pub from: usize,
+    pub to: usize,
+    pub weight: i64,
+}
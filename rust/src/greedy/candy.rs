@@ -0,0 +1,65 @@
+// LeetCode 135 "Candy": minimum candies to hand out so every child gets at
+// least one, and any child with a strictly higher rating than a neighbor
+// gets strictly more candy than that neighbor.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Minimum candy count per child satisfying both neighbor constraints.
+///
+/// Two greedy passes, each enforcing one direction of the constraint:
+/// left-to-right bumps a child above its left neighbor when its rating is
+/// higher, right-to-left does the same against the right neighbor. Taking
+/// the max of both passes per child keeps whichever pass raised it higher
+/// without the other undoing it, since neither pass ever lowers a count.
+pub fn candy(ratings: &[u32]) -> Vec<u32> {
+    let n = ratings.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut candies = vec![1u32; n];
+
+    for i in 1..n {
+        if ratings[i] > ratings[i - 1] {
+            candies[i] = candies[i - 1] + 1;
+        }
+    }
+    for i in (0..n - 1).rev() {
+        if ratings[i] > ratings[i + 1] {
+            candies[i] = candies[i].max(candies[i + 1] + 1);
+        }
+    }
+
+    candies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strictly_increasing_ratings_need_increasing_candy() {
+        assert_eq!(candy(&[1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn peak_in_the_middle() {
+        assert_eq!(candy(&[1, 2, 2]), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn textbook_valley_example() {
+        assert_eq!(candy(&[1, 0, 2]), vec![2, 1, 2]);
+    }
+
+    #[test]
+    fn flat_ratings_need_one_each() {
+        assert_eq!(candy(&[5, 5, 5]), vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn empty_input_needs_no_candy() {
+        assert_eq!(candy(&[]), Vec::<u32>::new());
+    }
+}
@@ -0,0 +1,6 @@
+//! Greedy algorithms whose correctness rests on an exchange-argument
+//! proof rather than exhaustive search.
+
+pub mod candy;
+pub mod optimal_merge;
+pub mod platforms;
@@ -0,0 +1,83 @@
+// Optimal k-ary file-merge cost, the generalization of Huffman coding's
+// merge step to merging k piles at a time instead of 2.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// One merge: combining `inputs` (the k smallest piles remaining) into a
+/// single pile costing their sum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeStep {
+    pub inputs: Vec<u64>,
+    pub cost: u64,
+}
+
+/// Minimum total cost to merge `sizes` into a single pile, k piles at a
+/// time (`k = 2` is the classic binary Huffman merge). Greedily always
+/// merges the k smallest piles, via a min-heap — the same exchange
+/// argument as Huffman coding: any optimal merge order can be rearranged
+/// to merge the two (here, k) globally smallest piles first without
+/// increasing cost.
+///
+/// Pads with zero-cost dummy piles first so `(sizes.len() - 1) % (k - 1)
+/// == 0`; without that, the final merge would be forced to combine fewer
+/// than k real piles, which is never optimal.
+pub fn optimal_merge_cost(sizes: &[u64], k: usize) -> (u64, Vec<MergeStep>) {
+    assert!(k >= 2, "k must be at least 2");
+
+    let mut heap: BinaryHeap<Reverse<u64>> = sizes.iter().map(|&s| Reverse(s)).collect();
+    if heap.len() > 1 {
+        let pad = ((k - 1) - (heap.len() - 1) % (k - 1)) % (k - 1);
+        for _ in 0..pad {
+            heap.push(Reverse(0));
+        }
+    }
+
+    let mut total_cost = 0u64;
+    let mut steps = Vec::new();
+    while heap.len() > 1 {
+        let mut inputs = Vec::with_capacity(k);
+        for _ in 0..k.min(heap.len()) {
+            let Reverse(v) = heap.pop().expect("heap.len() > 1 checked above");
+            inputs.push(v);
+        }
+        let merged: u64 = inputs.iter().sum();
+        total_cost += merged;
+        steps.push(MergeStep { inputs, cost: merged });
+        heap.push(Reverse(merged));
+    }
+    (total_cost, steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_merge_matches_textbook_huffman_cost() {
+        // classic file-merge example: merging {2, 3, 4} costs
+        // (2 + 3) + (5 + 4) = 14.
+        let (cost, steps) = optimal_merge_cost(&[2, 3, 4], 2);
+        assert_eq!(cost, 14);
+        assert_eq!(steps.len(), 2);
+    }
+
+    #[test]
+    fn ternary_merge_pads_with_dummies() {
+        // n = 4 piles, k = 3: (n - 1) % (k - 1) = 3 % 2 = 1, so one dummy
+        // pile is needed to make every merge combine exactly 3 piles.
+        let (cost, steps) = optimal_merge_cost(&[1, 2, 3, 4], 3);
+        // merge {0, 1, 2} = 3, then merge {3, 3, 4} = 10, total 13.
+        assert_eq!(cost, 13);
+        assert_eq!(steps.len(), 2);
+        assert!(steps.iter().all(|s| s.inputs.len() <= 3));
+    }
+
+    #[test]
+    fn single_pile_needs_no_merge() {
+        let (cost, steps) = optimal_merge_cost(&[5], 2);
+        assert_eq!(cost, 0);
+        assert!(steps.is_empty());
+    }
+}
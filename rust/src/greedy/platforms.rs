@@ -0,0 +1,79 @@
+// Minimum-platforms scheduling: the classic "how many platforms does a
+// railway station need" problem, also known as interval partitioning or
+// meeting-rooms-II.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// Minimum number of platforms needed so no two trains scheduled over
+/// `[arrivals[i], departures[i])` ever share one, plus which platform
+/// each train (by its original index) is assigned.
+///
+/// Greedy: process trains in arrival order, and reuse whichever already-open
+/// platform frees up earliest if it's free by the time this train arrives
+/// (tracked in a min-heap keyed by free time); open a new platform only
+/// when none are free yet. Never reusing a later-freeing platform over an
+/// earlier one can only ever leave more platforms free for later trains,
+/// so this never does worse than any other valid assignment.
+pub fn assign_platforms(arrivals: &[u64], departures: &[u64]) -> (usize, Vec<usize>) {
+    assert_eq!(arrivals.len(), departures.len());
+    let n = arrivals.len();
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&i| arrivals[i]);
+
+    let mut free_at: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+    let mut assignment = vec![0usize; n];
+    let mut platform_count = 0;
+
+    for train in order {
+        let reused = match free_at.peek() {
+            Some(&Reverse((free_time, _))) if free_time <= arrivals[train] => {
+                let Reverse((_, platform)) = free_at.pop().expect("peeked above");
+                Some(platform)
+            }
+            _ => None,
+        };
+        let platform = reused.unwrap_or_else(|| {
+            let p = platform_count;
+            platform_count += 1;
+            p
+        });
+        assignment[train] = platform;
+        free_at.push(Reverse((departures[train], platform)));
+    }
+
+    (platform_count, assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn textbook_example_needs_three_platforms() {
+        let arrivals = [900, 940, 950, 1100, 1500, 1800];
+        let departures = [910, 1200, 1120, 1130, 1900, 2000];
+        let (count, assignment) = assign_platforms(&arrivals, &departures);
+        assert_eq!(count, 3);
+
+        // No two trains assigned the same platform may overlap in time.
+        for i in 0..arrivals.len() {
+            for j in (i + 1)..arrivals.len() {
+                if assignment[i] == assignment[j] {
+                    let overlap = arrivals[i] < departures[j] && arrivals[j] < departures[i];
+                    assert!(!overlap, "trains {i} and {j} share platform {} but overlap", assignment[i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn disjoint_trains_share_one_platform() {
+        let (count, assignment) = assign_platforms(&[0, 10, 20], &[5, 15, 25]);
+        assert_eq!(count, 1);
+        assert_eq!(assignment, vec![0, 0, 0]);
+    }
+}
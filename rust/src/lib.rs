@@ -0,0 +1,35 @@
+//! Library crate for the algorithms in this directory. Grouped by domain
+//! so related algorithms can share types and call into each other (e.g.
+//! `graph::johnson` reuses `graph::bellman_ford` and `graph::dijkstra`)
+//! instead of each standalone file duplicating its own `Edge` type.
+//!
+//! Files that don't yet belong to one of these domains remain standalone
+//! binaries under `examples/`.
+//!
+//! Builds `#![no_std]` (plus `alloc`) when the default `std` feature is
+//! off, so the core data structures and fixed-size graph algorithms can
+//! be embedded on targets without an OS. `graph::johnson`,
+//! `graph::spectral`, `sorting::introsort`, and `streaming::decayed_count`
+//! need libm floating-point ops `core` doesn't provide, so they stay
+//! behind the `std` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+pub mod collections;
+pub mod compression;
+pub mod dp;
+pub mod geometry;
+pub mod graph;
+pub mod greedy;
+pub mod math;
+pub mod search;
+pub mod sorting;
+pub mod streaming;
+pub mod string;
+pub mod tree;
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub mod wasm;
@@ -0,0 +1,116 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+const MOD: i64 = 998_244_353;
+
+fn norm(x: i64) -> i64 {
+    ((x % MOD) + MOD) % MOD
+}
+
+fn mod_pow(mut base: i64, mut exp: i64, m: i64) -> i64 {
+    let mut result = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result
+}
+
+fn mod_inv(a: i64) -> i64 {
+    mod_pow(norm(a), MOD - 2, MOD)
+}
+
+/// Berlekamp-Massey: recovers the minimal linear recurrence (mod a prime)
+/// that generates a given sequence. Pairs naturally with a Kitamasa-style
+/// solver for evaluating far-out terms of the recurrence.
+///
+/// Finds the shortest linear recurrence `c` such that
+/// `s[i] = sum_{j=1..=c.len()} c[j-1] * s[i-j]` for all valid `i`.
+/// Returns the coefficients `c[0..k]` (so the recurrence has order `k`).
+pub fn berlekamp_massey(s: &[i64]) -> Vec<i64> {
+    let n = s.len();
+    let mut ls = 0usize; // length of the last update
+    let mut cur = vec![0i64; n + 1]; // current connection polynomial, C[0] = 1
+    let mut prev = vec![0i64; n + 1]; // connection polynomial before the last update
+    cur[0] = 1;
+    prev[0] = 1;
+    let mut b = 1i64; // discrepancy at the last update
+    let mut m = 0usize; // steps since the last update
+
+    for i in 0..n {
+        m += 1;
+        let mut d = s[i];
+        for j in 1..=ls {
+            d = norm(d + cur[j] * s[i - j]);
+        }
+        if d == 0 {
+            continue;
+        }
+        let t = cur.clone();
+        let coef = d * mod_inv(b) % MOD;
+        for j in m..n {
+            cur[j] = norm(cur[j] - coef * prev[j - m]);
+        }
+        if 2 * ls <= i {
+            ls = i + 1 - ls;
+            prev = t;
+            b = d;
+            m = 0;
+        }
+    }
+
+    let mut c = cur[1..=ls].to_vec();
+    for x in c.iter_mut() {
+        *x = norm(-*x);
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next_terms(seed: &[i64], recurrence: &[i64], count: usize) -> Vec<i64> {
+        let mut s = seed.to_vec();
+        let order = recurrence.len();
+        for i in s.len()..s.len() + count {
+            let mut next = 0i64;
+            for (j, &c) in recurrence.iter().enumerate() {
+                next = norm(next + c * s[i - 1 - j]);
+            }
+            let _ = order;
+            s.push(next);
+        }
+        s
+    }
+
+    #[test]
+    fn recovers_the_fibonacci_recurrence() {
+        let mut fib = vec![0i64, 1];
+        for i in 2..10 {
+            fib.push(norm(fib[i - 1] + fib[i - 2]));
+        }
+        assert_eq!(berlekamp_massey(&fib), vec![1, 1]);
+    }
+
+    #[test]
+    fn recovered_recurrence_predicts_further_terms() {
+        let mut fib = vec![0i64, 1];
+        for i in 2..12 {
+            fib.push(norm(fib[i - 1] + fib[i - 2]));
+        }
+        let recurrence = berlekamp_massey(&fib[..10]);
+        let extended = next_terms(&fib[..10], &recurrence, 2);
+        assert_eq!(extended, fib);
+    }
+
+    #[test]
+    fn constant_sequence_has_order_one_recurrence() {
+        let constant = vec![5i64; 8];
+        assert_eq!(berlekamp_massey(&constant), vec![1]);
+    }
+}
@@ -0,0 +1,181 @@
+// Expression trees over a single variable `x`, with symbolic
+// differentiation and algebraic simplification — textbook tree-rewriting
+// algorithms, each node type mapping to one differentiation rule and one
+// simplification rule.
+
+use alloc::boxed::Box;
+use core::ops::{Add, Mul, Sub};
+
+/// A symbolic expression in one variable `x`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Var,
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    /// Integer power: `base^exponent`.
+    Pow(Box<Expr>, i32),
+}
+
+/// `base^exponent` by repeated squaring, since `core` doesn't provide
+/// `f64::powi` (it's a libm call, not a basic arithmetic intrinsic).
+/// Negative exponents take the reciprocal of the positive power.
+fn powi(base: f64, exponent: i32) -> f64 {
+    if exponent < 0 {
+        return 1.0 / powi(base, -exponent);
+    }
+    let mut result = 1.0;
+    let mut base = base;
+    let mut e = exponent as u32;
+    while e > 0 {
+        if e & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        e >>= 1;
+    }
+    result
+}
+
+impl Expr {
+    pub fn num(v: f64) -> Self {
+        Expr::Num(v)
+    }
+
+    pub fn pow(self, exponent: i32) -> Self {
+        Expr::Pow(Box::new(self), exponent)
+    }
+
+    /// Evaluates the expression at `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        match self {
+            Expr::Num(v) => *v,
+            Expr::Var => x,
+            Expr::Add(a, b) => a.eval(x) + b.eval(x),
+            Expr::Sub(a, b) => a.eval(x) - b.eval(x),
+            Expr::Mul(a, b) => a.eval(x) * b.eval(x),
+            Expr::Pow(base, n) => powi(base.eval(x), *n),
+        }
+    }
+}
+
+impl Add for Expr {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Expr::Add(Box::new(self), Box::new(other))
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Expr::Sub(Box::new(self), Box::new(other))
+    }
+}
+
+impl Mul for Expr {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Expr::Mul(Box::new(self), Box::new(other))
+    }
+}
+
+impl Expr {
+    /// Symbolic derivative with respect to `x`, via the standard
+    /// sum/product/power rules. The result is not simplified; pass it
+    /// through [`Expr::simplify`] to fold constants and drop identities.
+    pub fn differentiate(&self) -> Expr {
+        match self {
+            Expr::Num(_) => Expr::num(0.0),
+            Expr::Var => Expr::num(1.0),
+            Expr::Add(a, b) => a.differentiate().add(b.differentiate()),
+            Expr::Sub(a, b) => a.differentiate().sub(b.differentiate()),
+            Expr::Mul(a, b) => {
+                // product rule: (a*b)' = a'*b + a*b'
+                a.differentiate().mul((**b).clone()).add((**a).clone().mul(b.differentiate()))
+            }
+            Expr::Pow(base, n) => {
+                // power rule: (base^n)' = n * base^(n-1) * base'
+                Expr::num(*n as f64)
+                    .mul((**base).clone().pow(n - 1))
+                    .mul(base.differentiate())
+            }
+        }
+    }
+
+    /// Algebraically simplifies the expression: folds constant
+    /// subexpressions and eliminates additive/multiplicative identities
+    /// (`x + 0`, `x * 1`, `x * 0`, `x^1`, `x^0`). Recurses bottom-up so a
+    /// fold deep in the tree can enable one higher up.
+    pub fn simplify(&self) -> Expr {
+        match self {
+            Expr::Num(_) | Expr::Var => self.clone(),
+            Expr::Add(a, b) => match (a.simplify(), b.simplify()) {
+                (Expr::Num(x), Expr::Num(y)) => Expr::num(x + y),
+                (Expr::Num(x), other) | (other, Expr::Num(x)) if x == 0.0 => other,
+                (a, b) => a.add(b),
+            },
+            Expr::Sub(a, b) => match (a.simplify(), b.simplify()) {
+                (Expr::Num(x), Expr::Num(y)) => Expr::num(x - y),
+                (a, Expr::Num(0.0)) => a,
+                (a, b) => a.sub(b),
+            },
+            Expr::Mul(a, b) => match (a.simplify(), b.simplify()) {
+                (Expr::Num(x), Expr::Num(y)) => Expr::num(x * y),
+                (Expr::Num(x), _) | (_, Expr::Num(x)) if x == 0.0 => Expr::num(0.0),
+                (Expr::Num(x), other) | (other, Expr::Num(x)) if x == 1.0 => other,
+                (a, b) => a.mul(b),
+            },
+            Expr::Pow(base, n) => match base.simplify() {
+                Expr::Num(x) => Expr::num(powi(x, *n)),
+                base if *n == 0 => {
+                    let _ = base; // exponent alone decides the value
+                    Expr::num(1.0)
+                }
+                base if *n == 1 => base,
+                base => base.pow(*n),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn differentiates_a_polynomial() {
+        // d/dx (x^3 - 2x) = 3x^2 - 2
+        let e = Expr::Var.pow(3).sub(Expr::num(2.0).mul(Expr::Var));
+        let d = e.differentiate().simplify();
+        for x in [-2.0, 0.0, 1.0, 5.0] {
+            assert!((d.eval(x) - (3.0 * x * x - 2.0)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn simplifies_identities() {
+        let e = Expr::Var.add(Expr::num(0.0)).mul(Expr::num(1.0));
+        assert_eq!(e.simplify(), Expr::Var);
+
+        let e = Expr::Var.mul(Expr::num(0.0));
+        assert_eq!(e.simplify(), Expr::num(0.0));
+
+        let e = Expr::num(2.0).add(Expr::num(3.0));
+        assert_eq!(e.simplify(), Expr::num(5.0));
+    }
+
+    #[test]
+    fn differentiates_a_product() {
+        // d/dx (x^2 * x) = 3x^2
+        let e = Expr::Var.pow(2).mul(Expr::Var);
+        let d = e.differentiate().simplify();
+        for x in [-3.0, 0.0, 2.0] {
+            assert!((d.eval(x) - 3.0 * x * x).abs() < 1e-9);
+        }
+    }
+}
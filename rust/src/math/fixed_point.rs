@@ -0,0 +1,175 @@
+// A fixed-point decimal type: an `i128` mantissa representing
+// `mantissa / 10^scale`. Exact for decimal arithmetic (no binary-fraction
+// rounding surprises like `f64`), and far cheaper than a full bignum
+// rational when a handful of fractional digits is all a problem needs
+// (money, measurements, anything specified in decimal to begin with).
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Sub};
+
+/// How to round when an operation's exact result has more fractional
+/// digits than the type's scale can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Truncate toward zero.
+    Down,
+    /// Round half away from zero.
+    HalfUp,
+    /// Round half to the nearest even digit (banker's rounding).
+    HalfEven,
+}
+
+/// Divides `numerator` by `denominator`, rounding the quotient per
+/// `rounding`.
+fn div_round(numerator: i128, denominator: i128, rounding: Rounding) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return quotient;
+    }
+
+    let twice_remainder = remainder.unsigned_abs() * 2;
+    let denom_abs = denominator.unsigned_abs();
+    let round_away_from_zero = match rounding {
+        Rounding::Down => false,
+        Rounding::HalfUp => twice_remainder >= denom_abs,
+        Rounding::HalfEven => twice_remainder > denom_abs || (twice_remainder == denom_abs && quotient % 2 != 0),
+    };
+    if round_away_from_zero {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+/// A fixed-point decimal number at a given `scale` (number of fractional
+/// digits). Two values at different scales compare and add correctly —
+/// ordering and `+`/`-` implicitly promote to the coarser operand's
+/// finer scale, which is always exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FixedPoint {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl FixedPoint {
+    /// Builds a value directly from its scaled integer representation:
+    /// `mantissa / 10^scale`.
+    pub fn from_scaled(mantissa: i128, scale: u32) -> Self {
+        FixedPoint { mantissa, scale }
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Parses a decimal string such as `"-12.345"` at the given `scale`,
+    /// rounding any extra fractional digits per `rounding`. Returns
+    /// `None` if `s` isn't a plain optionally-signed decimal number.
+    pub fn parse(s: &str, scale: u32, rounding: Rounding) -> Option<Self> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (int_part, frac_part) = s.split_once('.').unwrap_or((s, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+        if !int_part.chars().all(|c| c.is_ascii_digit())
+            || !frac_part.chars().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let int_digits: i128 = if int_part.is_empty() { 0 } else { int_part.parse().ok()? };
+
+        let scale_usize = scale as usize;
+        let mut frac_digits: Vec<u32> = frac_part.chars().map(|c| c.to_digit(10).unwrap()).collect();
+        frac_digits.resize(frac_digits.len().max(scale_usize + 1), 0);
+
+        let kept = frac_digits[..scale_usize].iter().fold(0i128, |acc, &d| acc * 10 + d as i128);
+        let next_digit = frac_digits[scale_usize];
+        let rest_nonzero = frac_digits[scale_usize + 1..].iter().any(|&d| d != 0);
+        let last_kept_even =
+            if scale_usize > 0 { kept % 10 % 2 == 0 } else { int_digits % 10 % 2 == 0 };
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::HalfUp => next_digit >= 5,
+            Rounding::HalfEven => next_digit > 5 || (next_digit == 5 && (rest_nonzero || !last_kept_even)),
+        };
+
+        let mut mantissa = int_digits * 10i128.pow(scale) + kept;
+        if round_up {
+            mantissa += 1;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        Some(FixedPoint { mantissa, scale })
+    }
+
+    /// Converts to a different scale, rounding per `rounding` if it loses
+    /// precision (exact, with no rounding needed, when `new_scale >=
+    /// self.scale()`).
+    pub fn rescale(self, new_scale: u32, rounding: Rounding) -> Self {
+        if new_scale >= self.scale {
+            let factor = 10i128.pow(new_scale - self.scale);
+            FixedPoint { mantissa: self.mantissa * factor, scale: new_scale }
+        } else {
+            let factor = 10i128.pow(self.scale - new_scale);
+            FixedPoint { mantissa: div_round(self.mantissa, factor, rounding), scale: new_scale }
+        }
+    }
+
+    /// Multiplies, rounding the exact product down to `self`'s scale.
+    pub fn mul(self, other: Self, rounding: Rounding) -> Self {
+        let raw = FixedPoint { mantissa: self.mantissa * other.mantissa, scale: self.scale + other.scale };
+        raw.rescale(self.scale, rounding)
+    }
+
+    /// Divides, rounding the exact quotient to `self`'s scale.
+    pub fn div(self, other: Self, rounding: Rounding) -> Self {
+        let numerator = self.mantissa * 10i128.pow(other.scale);
+        FixedPoint { mantissa: div_round(numerator, other.mantissa, rounding), scale: self.scale }
+    }
+}
+
+impl Add for FixedPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let scale = self.scale.max(other.scale);
+        let a = self.rescale(scale, Rounding::Down).mantissa;
+        let b = other.rescale(scale, Rounding::Down).mantissa;
+        FixedPoint { mantissa: a + b, scale }
+    }
+}
+
+impl Sub for FixedPoint {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let scale = self.scale.max(other.scale);
+        let a = self.rescale(scale, Rounding::Down).mantissa;
+        let b = other.rescale(scale, Rounding::Down).mantissa;
+        FixedPoint { mantissa: a - b, scale }
+    }
+}
+
+impl fmt::Display for FixedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+        let factor = 10u128.pow(self.scale);
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let abs = self.mantissa.unsigned_abs();
+        let int_part = abs / factor;
+        let frac_part = abs % factor;
+        write!(f, "{sign}{int_part}.{frac_part:0width$}", width = self.scale as usize)
+    }
+}
@@ -0,0 +1,121 @@
+// Fast Walsh-Hadamard transform over XOR/AND/OR, used for subset-convolution
+// style counting problems. Complements the FFT/NTT modules.
+
+use alloc::vec::Vec;
+
+const MOD: i64 = 998_244_353;
+
+/// In-place XOR-convolution transform (self-inverse up to scaling by `n`).
+fn fwht_xor(a: &mut [i64]) {
+    let n = a.len();
+    let mut len = 1;
+    while len < n {
+        let mut i = 0;
+        while i < n {
+            for j in i..i + len {
+                let x = a[j];
+                let y = a[j + len];
+                a[j] = (x + y) % MOD;
+                a[j + len] = ((x - y) % MOD + MOD) % MOD;
+            }
+            i += len * 2;
+        }
+        len *= 2;
+    }
+}
+
+fn fwht_and(a: &mut [i64], invert: bool) {
+    let n = a.len();
+    let mut len = 1;
+    while len < n {
+        let mut i = 0;
+        while i < n {
+            for j in i..i + len {
+                let x = a[j];
+                let y = a[j + len];
+                if !invert {
+                    a[j + len] = (x + y) % MOD;
+                } else {
+                    a[j + len] = ((y - x) % MOD + MOD) % MOD;
+                }
+            }
+            i += len * 2;
+        }
+        len *= 2;
+    }
+}
+
+fn fwht_or(a: &mut [i64], invert: bool) {
+    let n = a.len();
+    let mut len = 1;
+    while len < n {
+        let mut i = 0;
+        while i < n {
+            for j in i..i + len {
+                let x = a[j];
+                let y = a[j + len];
+                if !invert {
+                    a[j] = (x + y) % MOD;
+                } else {
+                    a[j] = ((x - y) % MOD + MOD) % MOD;
+                }
+            }
+            i += len * 2;
+        }
+        len *= 2;
+    }
+}
+
+fn inv(mut a: i64, m: i64) -> i64 {
+    // modular inverse via Fermat's little theorem, m is prime here
+    let mut result = 1;
+    let mut e = m - 2;
+    a %= m;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * a % m;
+        }
+        a = a * a % m;
+        e >>= 1;
+    }
+    result
+}
+
+/// XOR-convolution: `c[k] = sum_{i^j=k} a[i]*b[j] (mod MOD)`.
+/// `a` and `b` must have the same power-of-two length.
+pub fn xor_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let n = a.len();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fwht_xor(&mut fa);
+    fwht_xor(&mut fb);
+    let mut fc: Vec<i64> = fa.iter().zip(fb.iter()).map(|(&x, &y)| x * y % MOD).collect();
+    fwht_xor(&mut fc);
+    let n_inv = inv(n as i64, MOD);
+    for v in fc.iter_mut() {
+        *v = *v * n_inv % MOD;
+    }
+    fc
+}
+
+/// AND-convolution: `c[k] = sum_{i&j=k} a[i]*b[j] (mod MOD)`.
+pub fn and_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fwht_and(&mut fa, false);
+    fwht_and(&mut fb, false);
+    let mut fc: Vec<i64> = fa.iter().zip(fb.iter()).map(|(&x, &y)| x * y % MOD).collect();
+    fwht_and(&mut fc, true);
+    fc
+}
+
+/// OR-convolution: `c[k] = sum_{i|j=k} a[i]*b[j] (mod MOD)`.
+pub fn or_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fwht_or(&mut fa, false);
+    fwht_or(&mut fb, false);
+    let mut fc: Vec<i64> = fa.iter().zip(fb.iter()).map(|(&x, &y)| x * y % MOD).collect();
+    fwht_or(&mut fc, true);
+    fc
+}
@@ -0,0 +1,102 @@
+// Linear algebra over GF(2): rows are packed into u64 words, so rank,
+// solve and basis-extraction run in O(rows * words) machine-word
+// operations. Useful for XOR-linear-algebra problems (e.g. maximal XOR
+// subset, checking linear independence of bitmasks).
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A basis for a GF(2) vector space, built incrementally from row vectors.
+/// `basis[i]` always has its highest set bit strictly greater than that of
+/// `basis[i + 1]` (row-echelon order), so insertion and rank are O(bits).
+#[derive(Default)]
+pub struct Gf2Basis {
+    basis: Vec<u64>,
+}
+
+impl Gf2Basis {
+    pub fn new() -> Self {
+        Gf2Basis { basis: Vec::new() }
+    }
+
+    /// Inserts `v` into the basis, returning `true` if it extended the span.
+    pub fn insert(&mut self, mut v: u64) -> bool {
+        for &b in &self.basis {
+            v = v.min(v ^ b);
+        }
+        if v == 0 {
+            return false;
+        }
+        self.basis.push(v);
+        self.basis.sort_unstable_by(|a, b| b.cmp(a));
+        true
+    }
+
+    pub fn rank(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Largest XOR of any subset of the inserted vectors.
+    pub fn max_xor(&self) -> u64 {
+        let mut result = 0u64;
+        for &b in &self.basis {
+            result = result.max(result ^ b);
+        }
+        result
+    }
+}
+
+/// Row-reduces `rows` in place over GF(2) and returns the rank.
+pub fn gf2_rank(rows: &mut [u64]) -> usize {
+    let mut rank = 0;
+    for col in (0..64).rev() {
+        let bit = 1u64 << col;
+        if let Some(pivot) = rows[rank..].iter().position(|&r| r & bit != 0) {
+            let pivot = pivot + rank;
+            rows.swap(rank, pivot);
+            for i in 0..rows.len() {
+                if i != rank && rows[i] & bit != 0 {
+                    rows[i] ^= rows[rank];
+                }
+            }
+            rank += 1;
+        }
+    }
+    rank
+}
+
+/// Solves `A x = b` over GF(2), where `a` holds the rows of `A` augmented
+/// with the matching bit of `b` in bit position `cols` (so each row has
+/// `cols + 1` meaningful low bits). Returns `None` if inconsistent.
+pub fn gf2_solve(a: &[u64], cols: usize) -> Option<u64> {
+    let mut rows = a.to_vec();
+    let mut rank = 0;
+    let mut pivot_col = vec![usize::MAX; cols];
+    for (col, slot) in pivot_col.iter_mut().enumerate() {
+        let bit = 1u64 << col;
+        if let Some(p) = rows[rank..].iter().position(|&r| r & bit != 0) {
+            let p = p + rank;
+            rows.swap(rank, p);
+            for i in 0..rows.len() {
+                if i != rank && rows[i] & bit != 0 {
+                    rows[i] ^= rows[rank];
+                }
+            }
+            *slot = rank;
+            rank += 1;
+        }
+    }
+    let rhs_bit = 1u64 << cols;
+    for row in rows.iter().skip(rank) {
+        if row & rhs_bit != 0 {
+            return None; // 0 = 1, inconsistent
+        }
+    }
+    let mut x = 0u64;
+    for col in 0..cols {
+        if pivot_col[col] != usize::MAX && rows[pivot_col[col]] & rhs_bit != 0 {
+            x |= 1u64 << col;
+        }
+    }
+    Some(x)
+}
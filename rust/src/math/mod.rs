@@ -0,0 +1,8 @@
+//! Numeric and algebraic algorithms (transforms, linear algebra, etc.).
+
+pub mod berlekamp_massey;
+pub mod expr;
+pub mod fixed_point;
+pub mod fwht;
+pub mod gf2_linalg;
+pub mod weighted_sampling;
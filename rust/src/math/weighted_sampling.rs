@@ -0,0 +1,198 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Small xorshift RNG, seeded so a given seed always reproduces the same
+/// draws — what the samplers below need for simulations and randomized
+/// algorithms elsewhere in this crate to reproduce a run exactly.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed ^ 0x9E3779B97F4A7C15)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Walker's alias method: O(n) preprocessing, O(1) sampling per draw.
+/// Best when the same weight distribution is sampled many times.
+pub struct AliasSampler {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+    rng: Rng,
+}
+
+impl AliasSampler {
+    pub fn new(weights: &[f64], seed: u64) -> Self {
+        let n = weights.len();
+        let total: f64 = weights.iter().sum();
+        let scaled: Vec<f64> = weights.iter().map(|&w| w * n as f64 / total).collect();
+
+        let mut prob = vec![0.0; n];
+        let mut alias = vec![0usize; n];
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        for (i, &s) in scaled.iter().enumerate() {
+            if s < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        let mut scaled = scaled;
+        // Pop only once both sides are confirmed non-empty: matching on
+        // `(small.pop(), large.pop())` directly would call `.pop()` on
+        // both regardless of whether the match succeeds, silently
+        // discarding whichever side still had an element when the other
+        // ran dry.
+        while !small.is_empty() && !large.is_empty() {
+            let s = small.pop().unwrap();
+            let l = large.pop().unwrap();
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] = scaled[l] + scaled[s] - 1.0;
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+        for i in large {
+            prob[i] = 1.0;
+        }
+        for i in small {
+            prob[i] = 1.0;
+        }
+
+        AliasSampler { prob, alias, rng: Rng::new(seed) }
+    }
+
+    pub fn sample(&mut self) -> usize {
+        let n = self.prob.len();
+        let i = (self.rng.next_f64() * n as f64) as usize;
+        let i = i.min(n - 1);
+        if self.rng.next_f64() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// Fenwick-tree-backed weighted sampler supporting O(log n) weight
+/// updates and O(log n) sampling, for distributions that change between
+/// draws (e.g. sampling without replacement, or reweighting over time).
+pub struct DynamicWeightedSampler {
+    tree: Vec<f64>,
+    n: usize,
+    rng: Rng,
+}
+
+impl DynamicWeightedSampler {
+    pub fn new(weights: &[f64], seed: u64) -> Self {
+        let n = weights.len();
+        let mut sampler = DynamicWeightedSampler { tree: vec![0.0; n + 1], n, rng: Rng::new(seed) };
+        for (i, &w) in weights.iter().enumerate() {
+            sampler.add(i, w);
+        }
+        sampler
+    }
+
+    fn add(&mut self, i: usize, delta: f64) {
+        let mut idx = i + 1;
+        while idx <= self.n {
+            self.tree[idx] += delta;
+            idx += idx & idx.wrapping_neg();
+        }
+    }
+
+    pub fn set_weight(&mut self, i: usize, new_weight: f64) {
+        let current = self.prefix_sum(i + 1) - self.prefix_sum(i);
+        self.add(i, new_weight - current);
+    }
+
+    fn prefix_sum(&self, mut idx: usize) -> f64 {
+        let mut sum = 0.0;
+        while idx > 0 {
+            sum += self.tree[idx];
+            idx -= idx & idx.wrapping_neg();
+        }
+        sum
+    }
+
+    pub fn total(&self) -> f64 {
+        self.prefix_sum(self.n)
+    }
+
+    /// Finds the smallest index whose cumulative weight reaches `target`.
+    fn find_by_prefix(&self, target: f64) -> usize {
+        let mut idx = 0;
+        let mut remaining = target;
+        let mut step = self.n.next_power_of_two();
+        while step > 0 {
+            if idx + step <= self.n && self.tree[idx + step] < remaining {
+                idx += step;
+                remaining -= self.tree[idx];
+            }
+            step /= 2;
+        }
+        idx.min(self.n - 1)
+    }
+
+    pub fn sample(&mut self) -> usize {
+        let target = self.rng.next_f64() * self.total();
+        self.find_by_prefix(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alias_sampler_never_returns_a_zero_weight_index() {
+        let weights = [0.0, 4.0, 2.0, 3.0];
+        let mut alias = AliasSampler::new(&weights, 42);
+        for _ in 0..2_000 {
+            assert_ne!(alias.sample(), 0);
+        }
+    }
+
+    #[test]
+    fn alias_sampler_matches_weight_ratios_over_many_draws() {
+        let weights = [1.0, 4.0, 2.0, 3.0];
+        let mut alias = AliasSampler::new(&weights, 42);
+        let mut counts = [0u32; 4];
+        for _ in 0..20_000 {
+            counts[alias.sample()] += 1;
+        }
+        // Index 1 has 4x the weight of index 0, so it should be drawn
+        // roughly 4x as often; loose bound to keep the test non-flaky.
+        let ratio = counts[1] as f64 / counts[0] as f64;
+        assert!((2.0..6.0).contains(&ratio), "ratio={ratio}");
+    }
+
+    #[test]
+    fn dynamic_sampler_reweighting_shifts_the_distribution() {
+        let weights = [1.0, 1.0, 1.0, 1.0];
+        let mut dynamic = DynamicWeightedSampler::new(&weights, 42);
+        dynamic.set_weight(0, 50.0);
+        assert_eq!(dynamic.total(), 53.0);
+
+        let mut counts = [0u32; 4];
+        for _ in 0..10_000 {
+            counts[dynamic.sample()] += 1;
+        }
+        assert!(counts[0] > counts[1] + counts[2] + counts[3]);
+    }
+}
@@ -0,0 +1,230 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+const ROOT: usize = 0;
+
+/// Exact-cover solver via Knuth's Dancing Links (DLX): the sparse
+/// 0/1 matrix is a circular doubly-linked list per row and per column,
+/// so "remove every row that conflicts with a chosen row" (`cover`) and
+/// its exact inverse (`uncover`) are both O(size of the removed rows)
+/// pointer surgery instead of rebuilding any structure — the trick that
+/// makes backtracking over this matrix practical.
+///
+/// Column `i` (0-indexed) is internal node `i + 1`; node `0` is the root
+/// that the column headers link into a ring via `left`/`right`.
+pub struct Dlx {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column: Vec<usize>,
+    row_of: Vec<usize>,
+    column_size: Vec<usize>,
+    num_rows: usize,
+}
+
+impl Dlx {
+    pub fn new(num_columns: usize) -> Self {
+        let mut dlx = Dlx {
+            left: Vec::new(),
+            right: Vec::new(),
+            up: Vec::new(),
+            down: Vec::new(),
+            column: Vec::new(),
+            row_of: Vec::new(),
+            column_size: vec![0; num_columns + 1],
+            num_rows: 0,
+        };
+        for i in 0..=num_columns {
+            dlx.left.push(if i == 0 { num_columns } else { i - 1 });
+            dlx.right.push(if i == num_columns { 0 } else { i + 1 });
+            dlx.up.push(i);
+            dlx.down.push(i);
+            dlx.column.push(i);
+            dlx.row_of.push(usize::MAX);
+        }
+        dlx
+    }
+
+    /// Adds one row of the exact-cover matrix: the 0-indexed columns it
+    /// covers. Returns the row's id (0-indexed, in insertion order),
+    /// used to report which rows a solution picked.
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        let row_id = self.num_rows;
+        self.num_rows += 1;
+
+        let mut row_nodes = Vec::with_capacity(columns.len());
+        for &col in columns {
+            let header = col + 1;
+            let node = self.left.len();
+
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            self.down[self.up[header]] = node;
+            self.up[header] = node;
+            self.column.push(header);
+            self.row_of.push(row_id);
+            self.column_size[header] += 1;
+
+            // Placeholder; every node in this row is relinked below
+            // once the whole row is known.
+            self.left.push(node);
+            self.right.push(node);
+            row_nodes.push(node);
+        }
+
+        let n = row_nodes.len();
+        for (i, &node) in row_nodes.iter().enumerate() {
+            self.right[node] = row_nodes[(i + 1) % n];
+            self.left[node] = row_nodes[(i + n - 1) % n];
+        }
+        row_id
+    }
+
+    /// Enumerates every exact-cover solution, each as the set of row ids
+    /// that together cover every column exactly once. `on_solution` is
+    /// called once per solution found; returning `false` from it stops
+    /// the search early.
+    pub fn solve<F: FnMut(&[usize]) -> bool>(&mut self, mut on_solution: F) {
+        let mut partial = Vec::new();
+        self.search(&mut partial, &mut on_solution);
+    }
+
+    /// Returns `false` if `on_solution` asked the search to stop.
+    fn search<F: FnMut(&[usize]) -> bool>(&mut self, partial: &mut Vec<usize>, on_solution: &mut F) -> bool {
+        if self.right[ROOT] == ROOT {
+            return on_solution(partial);
+        }
+
+        // Covering the column with the fewest remaining rows first is
+        // the standard DLX heuristic: it fails (or succeeds) as fast as
+        // possible instead of branching wide on an easy column early.
+        let mut chosen = self.right[ROOT];
+        let mut column = self.right[chosen];
+        while column != ROOT {
+            if self.column_size[column] < self.column_size[chosen] {
+                chosen = column;
+            }
+            column = self.right[column];
+        }
+        if self.column_size[chosen] == 0 {
+            return true;
+        }
+
+        self.cover(chosen);
+        let mut row = self.down[chosen];
+        while row != chosen {
+            partial.push(self.row_of[row]);
+            let mut j = self.right[row];
+            while j != row {
+                self.cover(self.column[j]);
+                j = self.right[j];
+            }
+
+            let keep_going = self.search(partial, on_solution);
+
+            let mut j = self.left[row];
+            while j != row {
+                self.uncover(self.column[j]);
+                j = self.left[j];
+            }
+            partial.pop();
+
+            if !keep_going {
+                self.uncover(chosen);
+                return false;
+            }
+            row = self.down[row];
+        }
+        self.uncover(chosen);
+        true
+    }
+
+    fn cover(&mut self, column_header: usize) {
+        self.right[self.left[column_header]] = self.right[column_header];
+        self.left[self.right[column_header]] = self.left[column_header];
+
+        let mut i = self.down[column_header];
+        while i != column_header {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.column_size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, column_header: usize) {
+        let mut i = self.up[column_header];
+        while i != column_header {
+            let mut j = self.left[i];
+            while j != i {
+                self.column_size[self.column[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+
+        self.right[self.left[column_header]] = column_header;
+        self.left[self.right[column_header]] = column_header;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_both_solutions_of_a_small_exact_cover() {
+        // Columns 0..4; rows: {0,1}, {2,3}, {0,2}, {1,3}. Two disjoint
+        // pairs of rows each cover every column exactly once.
+        let mut dlx = Dlx::new(4);
+        let r0 = dlx.add_row(&[0, 1]);
+        let r1 = dlx.add_row(&[2, 3]);
+        let r2 = dlx.add_row(&[0, 2]);
+        let r3 = dlx.add_row(&[1, 3]);
+
+        let mut solutions = Vec::new();
+        dlx.solve(|rows| {
+            let mut rows = rows.to_vec();
+            rows.sort_unstable();
+            solutions.push(rows);
+            true
+        });
+        solutions.sort_unstable();
+
+        assert_eq!(solutions, vec![vec![r0, r1], vec![r2, r3]]);
+    }
+
+    #[test]
+    fn reports_no_solutions_when_a_column_is_uncoverable() {
+        let mut dlx = Dlx::new(2);
+        dlx.add_row(&[0]);
+
+        let mut count = 0;
+        dlx.solve(|_| {
+            count += 1;
+            true
+        });
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn early_stop_prevents_later_solutions_from_being_reported() {
+        let mut dlx = Dlx::new(1);
+        dlx.add_row(&[0]);
+        dlx.add_row(&[0]);
+
+        let mut count = 0;
+        dlx.solve(|_| {
+            count += 1;
+            false
+        });
+        assert_eq!(count, 1);
+    }
+}
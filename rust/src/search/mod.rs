@@ -0,0 +1,5 @@
+//! Exact-cover search (Algorithm X via Dancing Links) and the problems
+//! it gets reduced to.
+
+pub mod dancing_links;
+pub mod polyomino_tiling;
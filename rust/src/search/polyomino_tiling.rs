@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+use super::dancing_links::Dlx;
+
+/// A cell offset, `(row, col)`, relative to some arbitrary anchor.
+pub type Offset = (i32, i32);
+
+/// One polyomino, as the offsets of its cells from an arbitrary anchor
+/// cell (itself at `(0, 0)`, though [`orientations`](Self::orientations)
+/// re-normalizes every rotation/reflection anyway).
+#[derive(Clone, Debug)]
+pub struct Piece {
+    cells: Vec<Offset>,
+}
+
+impl Piece {
+    pub fn new(cells: Vec<Offset>) -> Self {
+        Piece { cells }
+    }
+
+    /// Every geometrically distinct orientation of this piece — up to 4
+    /// rotations times 2 reflections, deduped for pieces with enough
+    /// symmetry to repeat one under a different transform (e.g. a
+    /// square tetromino has only 1 distinct orientation, not 8).
+    pub fn orientations(&self) -> Vec<Vec<Offset>> {
+        let mut seen: Vec<Vec<Offset>> = Vec::new();
+        let mut shape = self.cells.clone();
+        for _ in 0..4 {
+            for candidate in [normalize(&shape), normalize(&reflect(&shape))] {
+                let mut key = candidate.clone();
+                key.sort_unstable();
+                if !seen.contains(&key) {
+                    seen.push(key);
+                }
+            }
+            shape = rotate(&shape);
+        }
+        seen
+    }
+}
+
+fn rotate(cells: &[Offset]) -> Vec<Offset> {
+    cells.iter().map(|&(r, c)| (c, -r)).collect()
+}
+
+fn reflect(cells: &[Offset]) -> Vec<Offset> {
+    cells.iter().map(|&(r, c)| (r, -c)).collect()
+}
+
+fn normalize(cells: &[Offset]) -> Vec<Offset> {
+    let min_r = cells.iter().map(|&(r, _)| r).min().unwrap_or(0);
+    let min_c = cells.iter().map(|&(_, c)| c).min().unwrap_or(0);
+    cells.iter().map(|&(r, c)| (r - min_r, c - min_c)).collect()
+}
+
+/// Counts the ways to exactly tile a `rows x cols` board using every
+/// piece in `pieces` exactly once each (the classic pentomino-puzzle
+/// framing), by reducing to exact cover: one [`Dlx`] column per board
+/// cell (must be covered by some piece) plus one column per piece
+/// (must be placed exactly once), and one row per (piece, orientation,
+/// position) placement.
+pub fn count_tilings(rows: usize, cols: usize, pieces: &[Piece]) -> usize {
+    let num_cells = rows * cols;
+    let mut dlx = Dlx::new(num_cells + pieces.len());
+
+    for (piece_index, piece) in pieces.iter().enumerate() {
+        for orientation in piece.orientations() {
+            let height = orientation.iter().map(|&(r, _)| r).max().unwrap_or(0) as usize + 1;
+            let width = orientation.iter().map(|&(_, c)| c).max().unwrap_or(0) as usize + 1;
+            if height > rows || width > cols {
+                continue;
+            }
+            for anchor_r in 0..=(rows - height) {
+                for anchor_c in 0..=(cols - width) {
+                    let mut columns = Vec::with_capacity(orientation.len() + 1);
+                    columns.push(num_cells + piece_index);
+                    for &(r, c) in &orientation {
+                        let board_r = anchor_r + r as usize;
+                        let board_c = anchor_c + c as usize;
+                        columns.push(board_r * cols + board_c);
+                    }
+                    dlx.add_row(&columns);
+                }
+            }
+        }
+    }
+
+    let mut count = 0usize;
+    dlx.solve(|_| {
+        count += 1;
+        true
+    });
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domino() -> Piece {
+        Piece::new(vec![(0, 0), (0, 1)])
+    }
+
+    fn l_tromino() -> Piece {
+        Piece::new(vec![(0, 0), (1, 0), (1, 1)])
+    }
+
+    #[test]
+    fn a_domino_has_two_orientations() {
+        assert_eq!(domino().orientations().len(), 2);
+    }
+
+    #[test]
+    fn an_l_tromino_has_only_four_orientations() {
+        // Unlike a generic tromino, this one is symmetric across its
+        // diagonal, so reflecting it lands on one of its own rotations
+        // instead of a fifth, sixth, seventh, and eighth distinct shape.
+        assert_eq!(l_tromino().orientations().len(), 4);
+    }
+
+    #[test]
+    fn a_square_tetromino_has_exactly_one_orientation() {
+        let square = Piece::new(vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(square.orientations().len(), 1);
+    }
+
+    #[test]
+    fn two_dominoes_tile_a_two_by_two_board_four_ways() {
+        // Two physical layouts (both horizontal, or both vertical), each
+        // counted twice since the two (distinguishable) domino pieces
+        // can swap which physical domino they are.
+        assert_eq!(count_tilings(2, 2, &[domino(), domino()]), 4);
+    }
+
+    #[test]
+    fn mismatched_piece_area_has_no_tiling() {
+        assert_eq!(count_tilings(2, 2, &[domino()]), 0);
+    }
+
+    #[test]
+    fn two_l_trominoes_tile_a_two_by_three_board() {
+        // Two physical layouts, again doubled by which piece goes where.
+        assert_eq!(count_tilings(2, 3, &[l_tromino(), l_tromino()]), 4);
+    }
+}
@@ -5,11 +5,11 @@ pub fn introsort<T: Ord>(arr: &mut [T]) {
     introsort_impl(arr, depth_limit);
 }
 
-fn introsort_impl<T: Ord>(arr: &mut [T], mut depth_limit: usize) {
+fn introsort_impl<T: Ord>(arr: &mut [T], depth_limit: usize) {
     const INSERTION_THRESHOLD: usize = 16;
 
-    let mut low = 0usize;
-    let mut high = arr.len();
+    let low = 0usize;
+    let high = arr.len();
 
     // Use a manual stack to avoid deep recursion on tail calls
     let mut stack: Vec<(usize, usize, usize)> = Vec::new();
@@ -132,10 +132,7 @@ mod tests {
     fn test_introsort_simple() {
         let mut v = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
         introsort(&mut v);
-        assert_eq!(v, {
-            let mut t = vec![1,1,2,3,4,5,5,6,9];
-            t
-        });
+        assert_eq!(v, vec![1, 1, 2, 3, 4, 5, 5, 6, 9]);
     }
 
     #[test]
@@ -0,0 +1,7 @@
+//! Comparison-based sorting algorithms with their own tests, as opposed
+//! to the single-file demo binaries under `examples/`.
+
+// `.log2()`/`.floor()` are libm float ops `core` doesn't provide, so this
+// module is unavailable in the `#![no_std]` build (see the crate root docs).
+#[cfg(feature = "std")]
+pub mod introsort;
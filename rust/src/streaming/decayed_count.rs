@@ -0,0 +1,80 @@
+// Exponentially decayed frequency counting: each observation of an item
+// adds 1 to its score, but every prior score is first decayed by
+// `2^(-lambda * elapsed)`, so recent activity dominates a long-running
+// stream without ever needing to explicitly evict old items.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Tracks a decayed score per item, higher `lambda` forgetting history
+/// faster. Time is a caller-supplied logical counter (e.g. an event
+/// index), not wall-clock, and must be non-decreasing across calls.
+pub struct DecayedCounter<K> {
+    lambda: f64,
+    scores: BTreeMap<K, (f64, u64)>,
+}
+
+impl<K: Ord + Clone> DecayedCounter<K> {
+    pub fn new(lambda: f64) -> Self {
+        DecayedCounter { lambda, scores: BTreeMap::new() }
+    }
+
+    /// Records one occurrence of `item` at `time`.
+    pub fn observe(&mut self, item: K, time: u64) {
+        let entry = self.scores.entry(item).or_insert((0.0, time));
+        let elapsed = time.saturating_sub(entry.1) as f64;
+        entry.0 = entry.0 * 2f64.powf(-self.lambda * elapsed) + 1.0;
+        entry.1 = time;
+    }
+
+    /// `item`'s score decayed up to `time`, even if it hasn't been
+    /// observed since its last update. `0.0` if `item` was never
+    /// observed.
+    pub fn score(&self, item: &K, time: u64) -> f64 {
+        match self.scores.get(item) {
+            Some(&(score, last)) => score * 2f64.powf(-self.lambda * (time.saturating_sub(last)) as f64),
+            None => 0.0,
+        }
+    }
+
+    /// The `k` highest-scoring items as of `time`, highest first.
+    pub fn top_k(&self, k: usize, time: u64) -> Vec<(K, f64)> {
+        let mut scored: Vec<(K, f64)> =
+            self.scores.keys().map(|item| (item.clone(), self.score(item, time))).collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("decayed scores are never NaN"));
+        scored.truncate(k);
+        scored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_bursts_outrank_decayed_old_ones() {
+        let mut counter = DecayedCounter::new(0.1);
+        for t in 0..5 {
+            counter.observe("old", t);
+        }
+        for t in 50..55 {
+            counter.observe("new", t);
+        }
+        let top = counter.top_k(2, 55);
+        assert_eq!(top[0].0, "new");
+    }
+
+    #[test]
+    fn score_decays_toward_zero_over_time() {
+        let mut counter = DecayedCounter::new(1.0);
+        counter.observe("a", 0);
+        assert!(counter.score(&"a", 0) > 0.9);
+        assert!(counter.score(&"a", 20) < 0.001);
+    }
+
+    #[test]
+    fn unobserved_items_score_zero() {
+        let counter: DecayedCounter<&str> = DecayedCounter::new(0.5);
+        assert_eq!(counter.score(&"nope", 100), 0.0);
+    }
+}
@@ -0,0 +1,129 @@
+// DGIM (Datar-Gionis-Indyk-Motwani) bucket counting: an approximate count
+// of how many of the last k bits of a 0/1 stream are 1, using O(log(N)^2)
+// memory for a window of N bits instead of storing the window outright.
+
+use alloc::collections::VecDeque;
+
+/// One bucket: the `size` most recent 1-bits seen by `timestamp`, where
+/// `size` is always a power of two.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    timestamp: u64,
+    size: u32,
+}
+
+/// Estimates the number of 1s in the last `k <= window` bits of a stream,
+/// keeping at most 2 buckets of each power-of-two size so old buckets get
+/// merged (doubling in size) instead of the bucket list growing without
+/// bound.
+pub struct Dgim {
+    window: u64,
+    time: u64,
+    /// Front = newest bucket, back = oldest; sizes are non-decreasing
+    /// front-to-back, since older buckets have absorbed more merges.
+    buckets: VecDeque<Bucket>,
+}
+
+impl Dgim {
+    pub fn new(window: u64) -> Self {
+        assert!(window > 0, "DGIM needs a non-empty window");
+        Dgim { window, time: 0, buckets: VecDeque::new() }
+    }
+
+    /// Feeds one more bit into the stream.
+    pub fn record(&mut self, bit: bool) {
+        self.time += 1;
+        while let Some(oldest) = self.buckets.back() {
+            if self.time - oldest.timestamp >= self.window {
+                self.buckets.pop_back();
+            } else {
+                break;
+            }
+        }
+        if !bit {
+            return;
+        }
+        self.buckets.push_front(Bucket { timestamp: self.time, size: 1 });
+        self.merge_triples();
+    }
+
+    /// Repeatedly merges the older two of any three same-size buckets
+    /// into one double-size bucket, until no size has more than two.
+    fn merge_triples(&mut self) {
+        loop {
+            let triple = (0..self.buckets.len().saturating_sub(2))
+                .find(|&i| self.buckets[i].size == self.buckets[i + 1].size && self.buckets[i + 1].size == self.buckets[i + 2].size);
+            let Some(i) = triple else { break };
+            let merged = Bucket { timestamp: self.buckets[i + 1].timestamp, size: self.buckets[i + 1].size + self.buckets[i + 2].size };
+            self.buckets.remove(i + 2);
+            self.buckets[i + 1] = merged;
+        }
+    }
+
+    /// Estimated number of 1s among the last `k` bits (`k <= window`):
+    /// the full size of every bucket entirely within the last `k` bits,
+    /// plus half the size of the one bucket straddling that boundary.
+    pub fn estimate(&self, k: u64) -> u64 {
+        let mut total = 0u64;
+        for bucket in &self.buckets {
+            let age = self.time - bucket.timestamp + 1;
+            if age <= k {
+                total += u64::from(bucket.size);
+            } else {
+                total += u64::from(bucket.size) / 2;
+                break;
+            }
+        }
+        total
+    }
+
+    /// Estimated number of 1s in the whole window.
+    pub fn count(&self) -> u64 {
+        self.estimate(self.window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn estimate_stays_within_the_dgim_error_bound() {
+        let window = 64u64;
+        let mut dgim = Dgim::new(window);
+        let mut bits: Vec<bool> = Vec::new();
+
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..300 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let bit = state & 1 == 1;
+
+            bits.push(bit);
+            dgim.record(bit);
+
+            let actual = bits.iter().rev().take(window as usize).filter(|&&b| b).count() as u64;
+            let estimate = dgim.count();
+            // DGIM guarantees the estimate is off by at most 50% of the
+            // true count (from the one bucket straddling the window
+            // boundary being halved).
+            let tolerance = actual / 2 + 1;
+            assert!(actual.abs_diff(estimate) <= tolerance, "actual={actual} estimate={estimate}");
+        }
+    }
+
+    #[test]
+    fn never_keeps_more_than_two_buckets_per_size() {
+        let mut dgim = Dgim::new(1000);
+        for _ in 0..200 {
+            dgim.record(true);
+        }
+        let mut counts = alloc::collections::BTreeMap::new();
+        for bucket in &dgim.buckets {
+            *counts.entry(bucket.size).or_insert(0) += 1;
+        }
+        assert!(counts.values().all(|&count| count <= 2));
+    }
+}
@@ -0,0 +1,9 @@
+//! Single-pass algorithms over a data stream too large (or too fast) to
+//! store in full, trading exactness for bounded memory.
+
+// `f64::powf` is a libm call `core` doesn't provide, so this module is
+// unavailable in the `#![no_std]` build (see the crate root docs).
+#[cfg(feature = "std")]
+pub mod decayed_count;
+pub mod dgim;
+pub mod sliding_window_median;
@@ -0,0 +1,115 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Maintains order statistics over a sliding window of fixed size, one
+/// slide at a time, using a sorted multiset (`BTreeMap<value, count>`)
+/// with a cached window length for O(log n) insert/remove and O(log n)
+/// rank queries via a running count per slide.
+pub struct SlidingWindowStats {
+    counts: BTreeMap<i64, usize>,
+    window_len: usize,
+}
+
+impl SlidingWindowStats {
+    pub fn new() -> Self {
+        SlidingWindowStats { counts: BTreeMap::new(), window_len: 0 }
+    }
+
+    pub fn insert(&mut self, value: i64) {
+        *self.counts.entry(value).or_insert(0) += 1;
+        self.window_len += 1;
+    }
+
+    pub fn remove(&mut self, value: i64) {
+        if let Some(c) = self.counts.get_mut(&value) {
+            *c -= 1;
+            if *c == 0 {
+                self.counts.remove(&value);
+            }
+            self.window_len -= 1;
+        }
+    }
+
+    /// The `k`-th smallest value (0-indexed) currently in the window.
+    pub fn kth_smallest(&self, k: usize) -> i64 {
+        let mut remaining = k;
+        for (&value, &count) in self.counts.iter() {
+            if remaining < count {
+                return value;
+            }
+            remaining -= count;
+        }
+        unreachable!("k out of range for current window")
+    }
+
+    pub fn median(&self) -> f64 {
+        if self.window_len % 2 == 1 {
+            self.kth_smallest(self.window_len / 2) as f64
+        } else {
+            let lo = self.kth_smallest(self.window_len / 2 - 1);
+            let hi = self.kth_smallest(self.window_len / 2);
+            (lo + hi) as f64 / 2.0
+        }
+    }
+}
+
+impl Default for SlidingWindowStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the median of every window of length `k` in `a`.
+pub fn sliding_window_medians(a: &[i64], k: usize) -> Vec<f64> {
+    let mut stats = SlidingWindowStats::new();
+    let mut result = Vec::with_capacity(a.len().saturating_sub(k) + 1);
+
+    for i in 0..a.len() {
+        stats.insert(a[i]);
+        if i >= k {
+            stats.remove(a[i - k]);
+        }
+        if i + 1 >= k {
+            result.push(stats.median());
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_medians(a: &[i64], k: usize) -> Vec<f64> {
+        let mut result = Vec::new();
+        for start in 0..=a.len().saturating_sub(k) {
+            let mut window = a[start..start + k].to_vec();
+            window.sort_unstable();
+            let median = if k % 2 == 1 {
+                window[k / 2] as f64
+            } else {
+                (window[k / 2 - 1] + window[k / 2]) as f64 / 2.0
+            };
+            result.push(median);
+        }
+        result
+    }
+
+    #[test]
+    fn matches_brute_force_on_a_known_array() {
+        let a = [1, 3, -1, -3, 5, 3, 6, 7];
+        assert_eq!(sliding_window_medians(&a, 3), brute_force_medians(&a, 3));
+    }
+
+    #[test]
+    fn even_window_size_averages_the_two_middle_values() {
+        let a = [1, 2, 3, 4];
+        assert_eq!(sliding_window_medians(&a, 2), vec![1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn window_equal_to_array_length_gives_one_median() {
+        let a = [5, 1, 4, 2, 3];
+        assert_eq!(sliding_window_medians(&a, 5), vec![3.0]);
+    }
+}
@@ -0,0 +1,182 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// One trie node, extended with a suffix/"failure" link and an output
+/// list. `output` is pre-merged with every fail-linked ancestor's
+/// output during [`AhoCorasick::new`], so a haystack walk never needs
+/// to chase the fail chain per character to find every pattern ending
+/// at the current position — which is what lets overlapping matches
+/// (e.g. both `"he"` and `"she"` ending at the same position) fall out
+/// of a single push per node, not a separate walk.
+struct Node {
+    children: BTreeMap<u8, u32>,
+    fail: u32,
+    output: Vec<usize>,
+}
+
+impl Node {
+    fn new() -> Self {
+        Node { children: BTreeMap::new(), fail: 0, output: Vec::new() }
+    }
+}
+
+/// A multi-pattern matcher: a trie of the patterns plus Aho-Corasick
+/// failure links, so a haystack is scanned once, in O(haystack length +
+/// matches), regardless of how many patterns there are.
+pub struct AhoCorasick {
+    arena: Vec<Node>,
+    pattern_lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Builds the automaton for `patterns`, indexed by position (a
+    /// match against `patterns[i]` is reported as pattern id `i`).
+    pub fn new(patterns: &[&[u8]]) -> Self {
+        let mut automaton =
+            AhoCorasick { arena: alloc::vec![Node::new()], pattern_lengths: patterns.iter().map(|p| p.len()).collect() };
+        for (id, &pattern) in patterns.iter().enumerate() {
+            automaton.insert(pattern, id);
+        }
+        automaton.build_fail_links();
+        automaton
+    }
+
+    /// Every match of any pattern in `haystack`, as `(pattern_id,
+    /// start_position)` pairs in the order their end positions are
+    /// scanned. Overlapping and repeated matches are all reported.
+    pub fn find_all(&self, haystack: &[u8]) -> Vec<(usize, usize)> {
+        let mut results = Vec::new();
+        let mut node = 0u32;
+        for (i, &byte) in haystack.iter().enumerate() {
+            node = self.advance(node, byte);
+            for &pattern_id in &self.arena[node as usize].output {
+                let start = i + 1 - self.pattern_lengths[pattern_id];
+                results.push((pattern_id, start));
+            }
+        }
+        results
+    }
+
+    fn advance(&self, node: u32, byte: u8) -> u32 {
+        let mut node = node;
+        loop {
+            if let Some(&next) = self.arena[node as usize].children.get(&byte) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.arena[node as usize].fail;
+        }
+    }
+
+    fn insert(&mut self, pattern: &[u8], id: usize) {
+        let mut node = 0u32;
+        for &byte in pattern {
+            node = match self.arena[node as usize].children.get(&byte) {
+                Some(&next) => next,
+                None => {
+                    self.arena.push(Node::new());
+                    let new_index = (self.arena.len() - 1) as u32;
+                    self.arena[node as usize].children.insert(byte, new_index);
+                    new_index
+                }
+            };
+        }
+        self.arena[node as usize].output.push(id);
+    }
+
+    /// Breadth-first over the trie: every node's fail link points to
+    /// the longest proper suffix of its path that is also some other
+    /// node's path, found by following its parent's (already-computed)
+    /// fail link rather than walking the whole trie again.
+    fn build_fail_links(&mut self) {
+        let mut queue: VecDeque<u32> = VecDeque::new();
+        let root_children: Vec<u32> = self.arena[0].children.values().copied().collect();
+        for &child in &root_children {
+            self.arena[child as usize].fail = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(u) = queue.pop_front() {
+            let children: Vec<(u8, u32)> = self.arena[u as usize].children.iter().map(|(&b, &c)| (b, c)).collect();
+            for (byte, v) in children {
+                let mut f = self.arena[u as usize].fail;
+                while f != 0 && !self.arena[f as usize].children.contains_key(&byte) {
+                    f = self.arena[f as usize].fail;
+                }
+                let target = self.arena[f as usize].children.get(&byte).copied().unwrap_or(0);
+
+                self.arena[v as usize].fail = target;
+                let fail_output = self.arena[target as usize].output.clone();
+                self.arena[v as usize].output.extend(fail_output);
+                queue.push_back(v);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_matches(patterns: &[&[u8]], haystack: &[u8]) -> Vec<(usize, usize)> {
+        let mut results = Vec::new();
+        for i in 0..haystack.len() {
+            for (id, pattern) in patterns.iter().enumerate() {
+                if !pattern.is_empty() && haystack[i..].starts_with(pattern) {
+                    results.push((id, i));
+                }
+            }
+        }
+        results
+    }
+
+    #[test]
+    fn finds_overlapping_matches() {
+        let patterns: Vec<&[u8]> = alloc::vec![b"he", b"she", b"his", b"hers"];
+        let automaton = AhoCorasick::new(&patterns);
+        let mut found = automaton.find_all(b"ushers");
+        found.sort_unstable();
+
+        let mut expected = brute_force_matches(&patterns, b"ushers");
+        expected.sort_unstable();
+        assert_eq!(found, expected);
+        // "ushers" should report both "she" (id 1, pos 1) and "he" (id 0, pos 2).
+        assert!(found.contains(&(1, 1)));
+        assert!(found.contains(&(0, 2)));
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_small_inputs() {
+        let alphabet = b"ab";
+        let patterns: Vec<Vec<u8>> = (0..5)
+            .flat_map(|len| {
+                (0..1u32 << len).map(move |mask| {
+                    (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect::<Vec<u8>>()
+                })
+            })
+            .filter(|p| !p.is_empty())
+            .collect();
+        let pattern_refs: Vec<&[u8]> = patterns.iter().map(|p| p.as_slice()).collect();
+        let automaton = AhoCorasick::new(&pattern_refs);
+
+        for haystack_len in 0..8 {
+            for mask in 0..(1u32 << haystack_len) {
+                let haystack: Vec<u8> = (0..haystack_len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                let mut found = automaton.find_all(&haystack);
+                found.sort_unstable();
+                let mut expected = brute_force_matches(&pattern_refs, &haystack);
+                expected.sort_unstable();
+                assert_eq!(found, expected, "{haystack:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn no_patterns_never_matches() {
+        let automaton = AhoCorasick::new(&[]);
+        assert!(automaton.find_all(b"anything").is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The start position of the lexicographically smallest rotation of `s`,
+/// via Booth's algorithm: a single O(n) pass over `s` doubled, using a
+/// KMP-style failure function to skip past rotations that can't possibly
+/// beat the current best candidate `k` instead of comparing every
+/// rotation from scratch. Returns `0` for the empty string.
+pub fn least_rotation(s: &[u8]) -> usize {
+    let n = s.len();
+    if n <= 1 {
+        return 0;
+    }
+
+    let doubled: Vec<u8> = s.iter().chain(s.iter()).copied().collect();
+    let m = doubled.len();
+    let mut failure = vec![-1i64; m];
+    let mut k = 0i64;
+
+    for j in 1..m as i64 {
+        let sj = doubled[j as usize];
+        let mut i = failure[(j - k - 1) as usize];
+        while i != -1 && sj != doubled[(k + i + 1) as usize] {
+            if sj < doubled[(k + i + 1) as usize] {
+                k = j - i - 1;
+            }
+            i = failure[i as usize];
+        }
+        if sj != doubled[(k + i + 1) as usize] {
+            if sj < doubled[k as usize] {
+                k = j;
+            }
+            failure[(j - k) as usize] = -1;
+        } else {
+            failure[(j - k) as usize] = i + 1;
+        }
+    }
+
+    k as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_least_rotation(s: &[u8]) -> usize {
+        let n = s.len();
+        if n == 0 {
+            return 0;
+        }
+        let rotation = |k: usize| -> Vec<u8> { s[k..].iter().chain(s[..k].iter()).copied().collect() };
+        (0..n).min_by_key(|&k| rotation(k)).unwrap()
+    }
+
+    #[test]
+    fn matches_brute_force_on_known_examples() {
+        for s in [&b""[..], b"a", b"bbaaccaadd", b"baabaa", b"alphabet"] {
+            let expected = brute_force_least_rotation(s);
+            let got = least_rotation(s);
+            // Several rotation starts can tie for lexicographically
+            // smallest; compare the rotated strings themselves instead of
+            // requiring the exact same index.
+            let rotate = |k: usize| -> Vec<u8> { s[k..].iter().chain(s[..k].iter()).copied().collect() };
+            assert_eq!(rotate(got), rotate(expected), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_on_every_short_binary_string() {
+        let alphabet = b"ab";
+        for len in 0..10 {
+            for mask in 0..(1u32 << len) {
+                let s: Vec<u8> = (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                let expected = brute_force_least_rotation(&s);
+                let got = least_rotation(&s);
+                let rotate = |k: usize| -> Vec<u8> { s[k..].iter().chain(s[..k].iter()).copied().collect() };
+                assert_eq!(rotate(got), rotate(expected), "{s:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The Lyndon factorization of `s`: the unique decomposition into a
+/// non-increasing sequence of Lyndon words (a Lyndon word is strictly
+/// smaller than every one of its own proper, nontrivial rotations) whose
+/// concatenation, in order, is `s`. Computed by Duval's algorithm in a
+/// single O(n) pass.
+pub fn lyndon_factorization(s: &[u8]) -> Vec<Range<usize>> {
+    let n = s.len();
+    let mut factors = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let mut j = i + 1;
+        let mut k = i;
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        while i <= k {
+            factors.push(i..i + (j - k));
+            i += j - k;
+        }
+    }
+    factors
+}
+
+/// The start position of the lexicographically smallest suffix of `s`:
+/// exactly the last factor of [`lyndon_factorization`], a standard
+/// corollary of Duval's algorithm (the last factor is itself a Lyndon
+/// word, hence smaller than every one of its own suffixes, and nothing
+/// after it exists to make some other suffix smaller still).
+pub fn smallest_suffix_start(s: &[u8]) -> usize {
+    lyndon_factorization(s).last().map_or(0, |factor| factor.start)
+}
+
+/// The start position of the lexicographically smallest rotation of `s`,
+/// derived from Duval's algorithm rather than Booth's: factorize `s + s`
+/// and take the start of the *last* factor that begins within the first
+/// copy of `s`. Lyndon factors are produced in strictly decreasing order
+/// of the (full, unbounded) suffix starting at each factor's position, so
+/// among factors starting before position `n` — whose suffixes are all
+/// longer than `n` and thus free of the "ran out of string" bias that
+/// would otherwise make a short trailing suffix look artificially small —
+/// the last one is exactly the smallest, which is exactly the
+/// lexicographically smallest rotation. Equivalent to
+/// [`super::booth::least_rotation`], offered as an alternative derivation
+/// built on Lyndon factorization instead of a KMP-style failure function.
+pub fn least_rotation_via_duval(s: &[u8]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    let doubled: Vec<u8> = s.iter().chain(s.iter()).copied().collect();
+    lyndon_factorization(&doubled)
+        .into_iter()
+        .rfind(|factor| factor.start < n)
+        .map_or(0, |factor| factor.start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::booth;
+
+    fn is_lyndon_word(s: &[u8]) -> bool {
+        if s.is_empty() {
+            return false;
+        }
+        (1..s.len()).all(|k| {
+            let rotated: Vec<u8> = s[k..].iter().chain(s[..k].iter()).copied().collect();
+            s < rotated.as_slice()
+        })
+    }
+
+    #[test]
+    fn factors_are_lyndon_words_in_non_increasing_order_and_cover_the_string() {
+        let alphabet = b"ab";
+        for len in 0..10 {
+            for mask in 0..(1u32 << len) {
+                let s: Vec<u8> = (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                let factors = lyndon_factorization(&s);
+
+                let mut covered = 0;
+                for factor in &factors {
+                    assert_eq!(factor.start, covered);
+                    assert!(is_lyndon_word(&s[factor.clone()]), "{:?} not a Lyndon word in {s:?}", &s[factor.clone()]);
+                    covered = factor.end;
+                }
+                assert_eq!(covered, s.len());
+
+                for pair in factors.windows(2) {
+                    assert!(s[pair[0].clone()] >= s[pair[1].clone()], "factors not non-increasing in {s:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn smallest_suffix_matches_brute_force() {
+        let alphabet = b"abc";
+        for len in 0..8 {
+            for mask in 0..(3u32.pow(len)) {
+                let mut m = mask;
+                let s: Vec<u8> = (0..len)
+                    .map(|_| {
+                        let byte = alphabet[(m % 3) as usize];
+                        m /= 3;
+                        byte
+                    })
+                    .collect();
+                if !s.is_empty() {
+                    let expected = (0..s.len()).min_by_key(|&i| &s[i..]).unwrap();
+                    let got = smallest_suffix_start(&s);
+                    assert_eq!(&s[got..], &s[expected..], "{s:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn least_rotation_via_duval_matches_booth() {
+        let alphabet = b"ab";
+        for len in 0..10 {
+            for mask in 0..(1u32 << len) {
+                let s: Vec<u8> = (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                let rotate = |k: usize| -> Vec<u8> { s[k..].iter().chain(s[..k].iter()).copied().collect() };
+                assert_eq!(rotate(least_rotation_via_duval(&s)), rotate(booth::least_rotation(&s)), "{s:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// One step of an edit script turning `a` into `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// Keep `a[a_index]` unchanged.
+    Keep { a_index: usize },
+    /// Insert `b[b_index]` (not present in `a`).
+    Insert { b_index: usize },
+    /// Delete `a[a_index]` (not present in `b`).
+    Delete { a_index: usize },
+    /// Replace `a[a_index]` with `b[b_index]`.
+    Substitute { a_index: usize, b_index: usize },
+}
+
+/// Levenshtein distance between `a` and `b`, plus one minimal edit script
+/// achieving it. Builds the full O(mn) DP table (unlike
+/// [`super::myers_levenshtein::edit_distance_dp`], which only needs the
+/// distance) so the script can be recovered by walking it backward from
+/// `dp[m][n]`, at each cell preferring `Keep` over the edits whenever
+/// the characters already match.
+pub fn edit_distance_with_script(a: &[u8], b: &[u8]) -> (usize, Vec<EditOp>) {
+    let (m, n) = (a.len(), b.len());
+    let mut dp: Vec<Vec<usize>> = vec![vec![0; n + 1]; m + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dp[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=m {
+        for j in 1..=n {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1]
+            } else {
+                1 + dp[i - 1][j].min(dp[i][j - 1]).min(dp[i - 1][j - 1])
+            };
+        }
+    }
+
+    let distance = dp[m][n];
+    let mut script = Vec::new();
+    let (mut i, mut j) = (m, n);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && dp[i][j] == dp[i - 1][j - 1] {
+            script.push(EditOp::Keep { a_index: i - 1 });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && dp[i][j] == dp[i - 1][j - 1] + 1 {
+            script.push(EditOp::Substitute { a_index: i - 1, b_index: j - 1 });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && dp[i][j] == dp[i - 1][j] + 1 {
+            script.push(EditOp::Delete { a_index: i - 1 });
+            i -= 1;
+        } else {
+            script.push(EditOp::Insert { b_index: j - 1 });
+            j -= 1;
+        }
+    }
+    script.reverse();
+    (distance, script)
+}
+
+/// Edit distance between `a` and `b`, or `None` if it exceeds `max_distance`.
+///
+/// Any alignment path with more than `max_distance` edits must, at every
+/// text position, have drifted more than `max_distance` insertions away
+/// from deletions — so only a diagonal band of width `2 * max_distance +
+/// 1` around the main diagonal can possibly lie on an optimal path within
+/// budget. Restricting the DP to that band turns the O(mn) table into
+/// O(n * max_distance), which is a real saving when `max_distance` is
+/// small and `a`/`b` are long and mostly similar (fuzzy matching being
+/// the typical use case).
+pub fn banded_edit_distance(a: &[u8], b: &[u8], max_distance: usize) -> Option<usize> {
+    let (m, n) = (a.len(), b.len());
+    if m.abs_diff(n) > max_distance {
+        return None;
+    }
+
+    const UNREACHABLE: usize = usize::MAX;
+    let band = 2 * max_distance + 1;
+    // `row[offset]` holds `dp[i][j]` for `j = i + offset - max_distance`,
+    // i.e. only `j` within `max_distance` of `i` is tracked per row.
+    let mut previous = vec![UNREACHABLE; band];
+    let mut current = vec![UNREACHABLE; band];
+
+    let j_for = |i: usize, offset: usize| -> Option<usize> {
+        let j = i as isize + offset as isize - max_distance as isize;
+        if j >= 0 && j as usize <= n {
+            Some(j as usize)
+        } else {
+            None
+        }
+    };
+
+    for (offset, cell) in previous.iter_mut().enumerate() {
+        if let Some(j) = j_for(0, offset) {
+            *cell = j;
+        }
+    }
+
+    for i in 1..=m {
+        current.fill(UNREACHABLE);
+        for offset in 0..band {
+            let Some(j) = j_for(i, offset) else { continue };
+            if j == 0 {
+                current[offset] = i;
+                continue;
+            }
+
+            let diag = previous[offset];
+            let up = if offset + 1 < band { previous[offset + 1] } else { UNREACHABLE };
+            let left = if offset > 0 { current[offset - 1] } else { UNREACHABLE };
+
+            let mut best = UNREACHABLE;
+            if diag != UNREACHABLE {
+                let cost = if a[i - 1] == b[j - 1] { diag } else { diag.saturating_add(1) };
+                best = best.min(cost);
+            }
+            if up != UNREACHABLE {
+                best = best.min(up.saturating_add(1));
+            }
+            if left != UNREACHABLE {
+                best = best.min(left.saturating_add(1));
+            }
+            current[offset] = best;
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    let final_offset = n + max_distance - m;
+    let result = previous.get(final_offset).copied().unwrap_or(UNREACHABLE);
+    if result <= max_distance {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::myers_levenshtein::edit_distance_dp;
+
+    fn apply_script(a: &[u8], b: &[u8], script: &[EditOp]) -> Vec<u8> {
+        let mut result = Vec::new();
+        for op in script {
+            match *op {
+                EditOp::Keep { a_index } => result.push(a[a_index]),
+                EditOp::Insert { b_index } => result.push(b[b_index]),
+                EditOp::Delete { .. } => {}
+                EditOp::Substitute { b_index, .. } => result.push(b[b_index]),
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn script_applies_to_reconstruct_b_and_matches_distance() {
+        let words: &[&[u8]] = &[b"kitten", b"sitting", b"flaw", b"lawn", b"", b"a", b"intention", b"execution"];
+        for &a in words {
+            for &b in words {
+                let (distance, script) = edit_distance_with_script(a, b);
+                assert_eq!(distance, edit_distance_dp(a, b), "a={a:?} b={b:?}");
+                assert_eq!(apply_script(a, b, &script), b, "a={a:?} b={b:?}");
+                let edits = script.iter().filter(|op| !matches!(op, EditOp::Keep { .. })).count();
+                assert_eq!(edits, distance, "a={a:?} b={b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn banded_matches_dp_when_within_budget() {
+        let words: &[&[u8]] = &[b"kitten", b"sitting", b"flaw", b"lawn", b"", b"a", b"abcdef", b"abcxef"];
+        for &a in words {
+            for &b in words {
+                let exact = edit_distance_dp(a, b);
+                for max_distance in exact..=exact + 2 {
+                    assert_eq!(banded_edit_distance(a, b, max_distance), Some(exact), "a={a:?} b={b:?} k={max_distance}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn banded_reports_none_when_over_budget() {
+        assert_eq!(banded_edit_distance(b"kitten", b"sitting", 1), None);
+        assert_eq!(banded_edit_distance(b"kitten", b"sitting", 2), None);
+        assert_eq!(banded_edit_distance(b"kitten", b"sitting", 3), Some(3));
+    }
+}
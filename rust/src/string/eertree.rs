@@ -0,0 +1,239 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// One node of the palindromic tree: `len` is the length of the
+/// palindrome this node represents (`-1` for the imaginary root, which
+/// has no real palindrome but lets every single character be reached by
+/// "wrapping" it — the usual eertree trick for avoiding a special case
+/// at the very first append), `link` is its longest proper palindromic
+/// suffix, and `children[c]` is the palindrome formed by wrapping this
+/// one in `c` on both sides. `count` starts as "how many times this
+/// palindrome was the longest palindromic suffix at some position" and
+/// is turned into a true occurrence count by [`Eertree::occurrence_counts`].
+struct Node {
+    len: i32,
+    link: u32,
+    children: BTreeMap<u8, u32>,
+    count: u64,
+}
+
+const IMAGINARY_ROOT: u32 = 0;
+const EMPTY_ROOT: u32 = 1;
+
+/// A palindromic tree (eertree): every distinct palindromic substring of
+/// an appended-to byte string is exactly one node, reached in amortized
+/// O(1) per appended character. Unlike a suffix automaton this counts
+/// *palindromic* substrings specifically, which a general substring
+/// structure has no shortcut for.
+pub struct Eertree {
+    arena: Vec<Node>,
+    s: Vec<u8>,
+    last: u32,
+}
+
+impl Eertree {
+    pub fn new() -> Self {
+        let imaginary = Node { len: -1, link: IMAGINARY_ROOT, children: BTreeMap::new(), count: 0 };
+        let empty = Node { len: 0, link: IMAGINARY_ROOT, children: BTreeMap::new(), count: 0 };
+        Eertree { arena: vec![imaginary, empty], s: Vec::new(), last: EMPTY_ROOT }
+    }
+
+    pub fn from_bytes(s: &[u8]) -> Self {
+        let mut tree = Self::new();
+        for &byte in s {
+            tree.push(byte);
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+
+    /// Appends `c`, in amortized O(1): find the longest palindromic
+    /// suffix of `s + c` by walking suffix links from the previous
+    /// longest palindromic suffix, creating a new node only the first
+    /// time a given palindrome is seen.
+    pub fn push(&mut self, c: u8) {
+        self.s.push(c);
+        let pos = self.s.len() as i32 - 1;
+
+        let cur = self.find_wrappable(self.last, c, pos);
+
+        if let Some(&next) = self.arena[cur as usize].children.get(&c) {
+            self.arena[next as usize].count += 1;
+            self.last = next;
+            return;
+        }
+
+        let new_len = self.arena[cur as usize].len + 2;
+        let new_index = self.arena.len() as u32;
+        self.arena.push(Node { len: new_len, link: EMPTY_ROOT, children: BTreeMap::new(), count: 1 });
+        self.arena[cur as usize].children.insert(c, new_index);
+
+        let link = if new_len == 1 {
+            EMPTY_ROOT
+        } else {
+            let parent_link = self.arena[cur as usize].link;
+            let suffix_cur = self.find_wrappable(parent_link, c, pos);
+            *self.arena[suffix_cur as usize].children.get(&c).unwrap()
+        };
+        self.arena[new_index as usize].link = link;
+
+        self.last = new_index;
+    }
+
+    /// The number of distinct palindromic substrings seen so far.
+    pub fn distinct_palindromes(&self) -> usize {
+        self.arena.len() - 2
+    }
+
+    /// Total occurrence count of every palindrome node, aligned with
+    /// internal node indices (indices `0` and `1` are the imaginary and
+    /// empty roots, and are not real palindromes). Computed by
+    /// propagating each node's "was the longest suffix here" count up
+    /// its suffix link, the same technique [`super::suffix_automaton`]
+    /// uses for substring occurrence counts.
+    pub fn occurrence_counts(&self) -> Vec<u64> {
+        let mut order: Vec<u32> = (2..self.arena.len() as u32).collect();
+        order.sort_by_key(|&i| core::cmp::Reverse(self.arena[i as usize].len));
+        let mut counts: Vec<u64> = self.arena.iter().map(|node| node.count).collect();
+        for &i in &order {
+            let link = self.arena[i as usize].link;
+            counts[link as usize] += counts[i as usize];
+        }
+        counts
+    }
+
+    /// Walks suffix links from `start` until finding a node whose
+    /// palindrome can be wrapped in `c` on both sides and still be a
+    /// suffix of `s[..=pos]` — i.e. the character `len + 1` positions
+    /// before `pos` is `c` too.
+    fn find_wrappable(&self, start: u32, c: u8, pos: i32) -> u32 {
+        let mut cur = start;
+        loop {
+            let len = self.arena[cur as usize].len;
+            let mirror = pos - len - 1;
+            if mirror >= 0 && self.s[mirror as usize] == c {
+                return cur;
+            }
+            cur = self.arena[cur as usize].link;
+        }
+    }
+}
+
+impl Default for Eertree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().zip(s.iter().rev()).all(|(a, b)| a == b)
+    }
+
+    fn brute_force_distinct_palindromes(s: &[u8]) -> usize {
+        let mut found = alloc::collections::BTreeSet::new();
+        for i in 0..s.len() {
+            for j in i + 1..=s.len() {
+                if is_palindrome(&s[i..j]) {
+                    found.insert(&s[i..j]);
+                }
+            }
+        }
+        found.len()
+    }
+
+    fn brute_force_total_occurrences(s: &[u8]) -> u64 {
+        // Total (distinct palindrome, occurrence count) pairs summed is
+        // just "every substring that happens to be a palindrome", counted
+        // once per occurrence rather than once per distinct value.
+        let mut total = 0u64;
+        for i in 0..s.len() {
+            for j in i + 1..=s.len() {
+                if is_palindrome(&s[i..j]) {
+                    total += 1;
+                }
+            }
+        }
+        total
+    }
+
+    #[test]
+    fn distinct_palindromes_matches_brute_force() {
+        let alphabet = b"ab";
+        for len in 0..10 {
+            for mask in 0..(1u32 << len) {
+                let s: Vec<u8> = (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                let tree = Eertree::from_bytes(&s);
+                assert_eq!(tree.distinct_palindromes(), brute_force_distinct_palindromes(&s), "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn total_occurrences_matches_brute_force() {
+        let alphabet = b"abc";
+        for len in 0..8 {
+            for mask in 0..(3u32.pow(len)) {
+                let mut m = mask;
+                let s: Vec<u8> = (0..len)
+                    .map(|_| {
+                        let byte = alphabet[(m % 3) as usize];
+                        m /= 3;
+                        byte
+                    })
+                    .collect();
+                let tree = Eertree::from_bytes(&s);
+                let total: u64 = tree.occurrence_counts()[2..].iter().sum();
+                assert_eq!(total, brute_force_total_occurrences(&s), "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn appending_incrementally_matches_building_from_bytes() {
+        let s = b"forgeeksskeegfor";
+        let mut incremental = Eertree::new();
+        for &byte in s {
+            incremental.push(byte);
+        }
+        let whole = Eertree::from_bytes(s);
+        assert_eq!(incremental.distinct_palindromes(), whole.distinct_palindromes());
+        assert_eq!(incremental.occurrence_counts(), whole.occurrence_counts());
+    }
+
+    #[test]
+    fn push_terminates_quickly_on_a_single_character() {
+        // Regression guard: an earlier version of `new()` linked the
+        // empty root's suffix link to itself instead of to the
+        // imaginary root, so `find_wrappable` spun forever on the very
+        // first `push`. Run the push on a worker thread with a wall-clock
+        // bound so a future regression fails the test instead of
+        // hanging the whole suite.
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let mut tree = Eertree::new();
+            tree.push(b'a');
+            let _ = tx.send(tree.distinct_palindromes());
+        });
+        let result = rx.recv_timeout(std::time::Duration::from_secs(5));
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn empty_string_has_no_palindromes() {
+        let tree = Eertree::new();
+        assert_eq!(tree.distinct_palindromes(), 0);
+        assert!(tree.is_empty());
+    }
+}
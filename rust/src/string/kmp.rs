@@ -0,0 +1,147 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The prefix function (a.k.a. failure function): `pi[i]` is the length
+/// of the longest proper prefix of `s[..=i]` that is also a suffix of
+/// it. This single array is what both [`kmp_search`] and
+/// [`shortest_period`] build on — it's the reusable piece the rest of
+/// this module is built around, per the usual KMP presentation.
+pub fn prefix_function(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut pi = vec![0usize; n];
+    for i in 1..n {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Every start position in `text` where `pattern` occurs, via the
+/// standard KMP automaton walk (the text is matched against `pattern`
+/// using `pattern`'s own prefix function to skip back on a mismatch
+/// instead of restarting from scratch).
+pub fn kmp_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+    let pi = prefix_function(pattern);
+    let mut matches = Vec::new();
+    let mut k = 0usize;
+    for (i, &byte) in text.iter().enumerate() {
+        while k > 0 && byte != pattern[k] {
+            k = pi[k - 1];
+        }
+        if byte == pattern[k] {
+            k += 1;
+        }
+        if k == pattern.len() {
+            matches.push(i + 1 - k);
+            k = pi[k - 1];
+        }
+    }
+    matches
+}
+
+/// The length of the shortest string `p` is a repetition of a whole
+/// number of copies of (e.g. `"abab"` has period `"ab"`, length 2). If
+/// `s` isn't exactly periodic, the only period it has is itself, so
+/// this returns `s.len()`.
+pub fn shortest_period(s: &[u8]) -> usize {
+    if s.is_empty() {
+        return 0;
+    }
+    let n = s.len();
+    let pi = prefix_function(s);
+    let candidate = n - pi[n - 1];
+    if n.is_multiple_of(candidate) {
+        candidate
+    } else {
+        n
+    }
+}
+
+/// For every prefix length `1..=s.len()`, how many times that prefix
+/// occurs as a substring anywhere in `s` (index `0` of the result is
+/// unused — there is no length-0 prefix to count). Computed by pushing
+/// each position's prefix-function value up the implicit prefix-length
+/// tree it forms, rather than re-scanning `s` once per prefix length.
+pub fn prefix_occurrence_counts(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let pi = prefix_function(s);
+    let mut counts = vec![0usize; n + 1];
+    for &p in &pi {
+        counts[p] += 1;
+    }
+    for i in (1..n).rev() {
+        counts[pi[i - 1]] += counts[i];
+    }
+    for count in &mut counts[1..] {
+        *count += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_matches(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        if pattern.is_empty() || pattern.len() > text.len() {
+            return Vec::new();
+        }
+        (0..=text.len() - pattern.len()).filter(|&i| &text[i..i + pattern.len()] == pattern).collect()
+    }
+
+    fn brute_force_prefix_occurrence_counts(s: &[u8]) -> Vec<usize> {
+        let n = s.len();
+        let mut counts = vec![0usize; n + 1];
+        for len in 1..=n {
+            counts[len] = (0..=n - len).filter(|&i| s[i..i + len] == s[..len]).count();
+        }
+        counts
+    }
+
+    #[test]
+    fn prefix_function_matches_known_values() {
+        assert_eq!(prefix_function(b"abcabcd"), alloc::vec![0, 0, 0, 1, 2, 3, 0]);
+        assert_eq!(prefix_function(b"aaaa"), alloc::vec![0, 1, 2, 3]);
+        assert_eq!(prefix_function(b""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn kmp_search_matches_brute_force() {
+        let cases: &[(&[u8], &[u8])] = &[
+            (b"ababcababcababc", b"abc"),
+            (b"aaaaaa", b"aa"),
+            (b"abcdef", b"xyz"),
+            (b"abc", b""),
+            (b"", b"abc"),
+            (b"mississippi", b"issi"),
+        ];
+        for &(text, pattern) in cases {
+            assert_eq!(kmp_search(text, pattern), brute_force_matches(text, pattern), "text={text:?} pattern={pattern:?}");
+        }
+    }
+
+    #[test]
+    fn shortest_period_matches_known_values() {
+        assert_eq!(shortest_period(b"abab"), 2);
+        assert_eq!(shortest_period(b"abcabcabc"), 3);
+        assert_eq!(shortest_period(b"abcabca"), 7); // not a whole number of repeats
+        assert_eq!(shortest_period(b"aaaa"), 1);
+        assert_eq!(shortest_period(b""), 0);
+    }
+
+    #[test]
+    fn prefix_occurrence_counts_matches_brute_force() {
+        for s in [&b""[..], b"a", b"aaaa", b"abcabcabc", b"mississippi", b"abcabca"] {
+            assert_eq!(prefix_occurrence_counts(s)[1..], brute_force_prefix_occurrence_counts(s)[1..], "{s:?}");
+        }
+    }
+}
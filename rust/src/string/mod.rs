@@ -0,0 +1,15 @@
+//! String-matching and sequence algorithms.
+
+pub mod aho_corasick;
+pub mod booth;
+pub mod duval;
+pub mod edit_script;
+pub mod eertree;
+pub mod kmp;
+pub mod myers_levenshtein;
+pub mod rabin_karp;
+pub mod rolling_hash;
+pub mod runs;
+pub mod suffix_array;
+pub mod suffix_automaton;
+pub mod z_function;
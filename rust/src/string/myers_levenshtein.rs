@@ -0,0 +1,179 @@
+// Myers' bit-vector algorithm for approximate string matching: the same
+// edit-distance recurrence as the textbook O(mn) DP, but packing an
+// entire DP column into the bits of a u64 so the whole column updates in
+// O(1) machine words per text character, for patterns up to 64 bytes.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Plain O(mn) edit-distance DP (Levenshtein distance), used here as the
+/// ground truth [`myers_edit_distance`] and [`find_approx_matches`] are
+/// checked against.
+pub fn edit_distance_dp(a: &[u8], b: &[u8]) -> usize {
+    let (m, n) = (a.len(), b.len());
+    let mut dp: Vec<Vec<usize>> = vec![vec![0; n + 1]; m + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dp[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=m {
+        for j in 1..=n {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1]
+            } else {
+                1 + dp[i - 1][j].min(dp[i][j - 1]).min(dp[i - 1][j - 1])
+            };
+        }
+    }
+    dp[m][n]
+}
+
+/// Per-character equality masks for a pattern of at most 64 bytes: `eq[c]`
+/// has bit `i` set wherever `pattern[i] == c`, letting the bit-vector scan
+/// below look up "does this pattern position match the current text
+/// character" for all positions at once.
+struct PatternMasks {
+    len: usize,
+    eq: [u64; 256],
+}
+
+impl PatternMasks {
+    fn new(pattern: &[u8]) -> Self {
+        assert!(pattern.len() <= 64, "Myers' algorithm needs the pattern to fit in one u64 (<= 64 bytes)");
+        let mut eq = [0u64; 256];
+        for (i, &byte) in pattern.iter().enumerate() {
+            eq[byte as usize] |= 1 << i;
+        }
+        PatternMasks { len: pattern.len(), eq }
+    }
+}
+
+/// One step of Myers' bit-vector recurrence: given the horizontal
+/// "positive"/"negative" vertical-delta vectors `pv`/`mv` and the running
+/// score for the previous text character, folds in `next` and returns the
+/// updated `(pv, mv, score)`.
+///
+/// `free_start` selects which boundary row the implicit row 0 represents:
+/// `false` forces row 0 to grow by one with every text character (`D(0,
+/// j) = j`), the right boundary for aligning the *whole* of `text` against
+/// `pattern`; `true` pins row 0 at zero (`D(0, j) = 0`), so skipping any
+/// number of text characters before the match starts is free, which is
+/// what turns this into substring search instead of whole-string
+/// alignment.
+fn step(masks: &PatternMasks, pv: u64, mv: u64, score: isize, next: u8, free_start: bool) -> (u64, u64, isize) {
+    let top_bit = 1u64 << (masks.len - 1);
+    let eq = masks.eq[next as usize];
+
+    let xv = eq | mv;
+    let xh = (((eq & pv).wrapping_add(pv)) ^ pv) | eq;
+
+    let ph = mv | !(xh | pv);
+    let mh = pv & xh;
+
+    let score = if ph & top_bit != 0 {
+        score + 1
+    } else if mh & top_bit != 0 {
+        score - 1
+    } else {
+        score
+    };
+
+    let ph = (ph << 1) | u64::from(!free_start);
+    let pv = (mh << 1) | !(xv | ph);
+    let mv = ph & xv;
+    (pv, mv, score)
+}
+
+/// Edit distance between `pattern` and `text`, for a pattern of at most 64
+/// bytes. Runs the same recurrence as [`find_approx_matches`] to
+/// completion and reads off the final column's score, equivalent to
+/// [`edit_distance_dp`] but O(n) instead of O(mn).
+pub fn myers_edit_distance(pattern: &[u8], text: &[u8]) -> usize {
+    if pattern.is_empty() {
+        return text.len();
+    }
+    let masks = PatternMasks::new(pattern);
+    let mut pv = u64::MAX;
+    let mut mv = 0u64;
+    let mut score = pattern.len() as isize;
+
+    for &byte in text {
+        let next = step(&masks, pv, mv, score, byte, false);
+        pv = next.0;
+        mv = next.1;
+        score = next.2;
+    }
+
+    score as usize
+}
+
+/// Every end position in `text` where some substring ending there is
+/// within `max_errors` edits of `pattern` (insertions, deletions, and
+/// substitutions), paired with the minimal edit distance achieved there.
+///
+/// Unlike [`myers_edit_distance`], which aligns the whole of `text`
+/// against `pattern`, this tracks the bit-vector score after every text
+/// character, so it finds every approximate occurrence of `pattern`
+/// *within* `text` in a single O(n) pass instead of resetting and
+/// rescanning per candidate start position.
+pub fn find_approx_matches(text: &[u8], pattern: &[u8], max_errors: usize) -> Vec<(usize, usize)> {
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+    let masks = PatternMasks::new(pattern);
+    let mut pv = u64::MAX;
+    let mut mv = 0u64;
+    let mut score = pattern.len() as isize;
+
+    let mut matches = Vec::new();
+    for (end, &byte) in text.iter().enumerate() {
+        let next = step(&masks, pv, mv, score, byte, true);
+        pv = next.0;
+        mv = next.1;
+        score = next.2;
+        if score as usize <= max_errors {
+            matches.push((end, score as usize));
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn myers_matches_dp_on_the_textbook_example() {
+        let (a, b) = (b"sunday", b"saturday");
+        assert_eq!(myers_edit_distance(a, b), edit_distance_dp(a, b));
+        assert_eq!(myers_edit_distance(a, b), 3);
+    }
+
+    #[test]
+    fn myers_matches_dp_on_random_short_strings() {
+        let words: &[&[u8]] = &[b"kitten", b"sitting", b"flaw", b"lawn", b"", b"a", b"abcdef"];
+        for &a in words {
+            for &b in words {
+                assert_eq!(myers_edit_distance(a, b), edit_distance_dp(a, b), "a={a:?} b={b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn find_approx_matches_locates_an_exact_substring() {
+        let matches = find_approx_matches(b"the quick brown fox", b"quick", 0);
+        // "quick" occupies text[4..9], so its last byte is at index 8.
+        assert!(matches.iter().any(|&(end, errs)| errs == 0 && end == 8));
+    }
+
+    #[test]
+    fn find_approx_matches_tolerates_up_to_k_errors() {
+        // "kwick" is two substitutions away from "quick".
+        let matches = find_approx_matches(b"the kwick brown fox", b"quick", 2);
+        assert!(matches.iter().any(|&(_, errs)| errs <= 2));
+        let no_matches = find_approx_matches(b"the kwick brown fox", b"quick", 0);
+        assert!(no_matches.iter().all(|&(_, errs)| errs != 0));
+    }
+}
@@ -1,12 +1,16 @@
-fn rabin_karp(text: &str, pattern: &str) {
-    let d: u64 = 256;        // Number of characters in input alphabet
-    let q: u64 = 101;        // A prime number for modulo
+use alloc::vec::Vec;
+
+/// Rabin-Karp substring search: prints every index in `text` at which
+/// `pattern` occurs, using a rolling hash to skip most mismatches cheaply.
+pub fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    let d: u64 = 256; // Number of characters in input alphabet
+    let q: u64 = 101; // A prime number for modulo
     let m = pattern.len();
     let n = text.len();
 
-    if m > n {
-        println!("Pattern is longer than text — no match.");
-        return;
+    let mut matches = Vec::new();
+    if m > n || m == 0 {
+        return matches;
     }
 
     let text_bytes = text.as_bytes();
@@ -30,11 +34,8 @@ fn rabin_karp(text: &str, pattern: &str) {
     // Slide the pattern over the text
     for i in 0..=(n - m) {
         // Check if hashes match
-        if p == t {
-            // Double-check characters
-            if &text[i..i + m] == pattern {
-                println!("Pattern found at index {}", i);
-            }
+        if p == t && &text[i..i + m] == pattern {
+            matches.push(i);
         }
 
         // Compute hash for next window
@@ -42,11 +43,6 @@ fn rabin_karp(text: &str, pattern: &str) {
             t = (d * (t + q - (text_bytes[i] as u64 * h) % q) + text_bytes[i + m] as u64) % q;
         }
     }
-}
-
-fn main() {
-    let text = "ABCCDDAEFG";
-    let pattern = "CDD";
 
-    rabin_karp(text, pattern);
+    matches
 }
@@ -0,0 +1,132 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+const MOD1: u64 = 1_000_000_007;
+const MOD2: u64 = 998_244_353;
+
+/// Precomputed prefix hashes of a byte string under two independent
+/// polynomial hashes (different `(base, modulus)` pairs), so any
+/// substring's combined hash is an O(1) lookup rather than an O(length)
+/// rehash. Using two hashes instead of one all but eliminates spurious
+/// collisions without resorting to a modulus wide enough to need `u128`
+/// arithmetic.
+pub struct StringHasher {
+    prefix1: Vec<u64>,
+    prefix2: Vec<u64>,
+    pow1: Vec<u64>,
+    pow2: Vec<u64>,
+}
+
+impl StringHasher {
+    pub fn new(s: &[u8]) -> Self {
+        Self::with_seed(s, 0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Builds `s`'s prefix hashes using two bases derived from `seed`
+    /// rather than fixed constants — a solver who doesn't know the
+    /// seed can't construct an "anti-hash test" input engineered to
+    /// collide against a publicly known base, the usual attack on
+    /// naive single-base rolling hashes.
+    pub fn with_seed(s: &[u8], seed: u64) -> Self {
+        let mut rng = seed | 1;
+        let base1 = random_base(&mut rng, MOD1);
+        let base2 = random_base(&mut rng, MOD2);
+
+        let n = s.len();
+        let mut prefix1 = vec![0u64; n + 1];
+        let mut prefix2 = vec![0u64; n + 1];
+        let mut pow1 = vec![1u64; n + 1];
+        let mut pow2 = vec![1u64; n + 1];
+        for i in 0..n {
+            let digit = s[i] as u64 + 1; // +1 so a run of leading zero bytes still changes the hash
+            prefix1[i + 1] = (prefix1[i] * base1 + digit) % MOD1;
+            prefix2[i + 1] = (prefix2[i] * base2 + digit) % MOD2;
+            pow1[i + 1] = pow1[i] * base1 % MOD1;
+            pow2[i + 1] = pow2[i] * base2 % MOD2;
+        }
+
+        StringHasher { prefix1, prefix2, pow1, pow2 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.prefix1.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The combined hash of `s[range]`, in O(1).
+    pub fn hash(&self, range: Range<usize>) -> (u64, u64) {
+        let (l, r) = (range.start, range.end);
+        let h1 = sub_mod(self.prefix1[r], self.prefix1[l] * self.pow1[r - l] % MOD1, MOD1);
+        let h2 = sub_mod(self.prefix2[r], self.prefix2[l] * self.pow2[r - l] % MOD2, MOD2);
+        (h1, h2)
+    }
+
+    /// Whether `s[a]` and `s[b]` are (almost certainly) the same bytes,
+    /// without ever materializing either substring.
+    pub fn substrings_equal(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        (a.end - a.start) == (b.end - b.start) && self.hash(a) == self.hash(b)
+    }
+}
+
+fn sub_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a + modulus - b % modulus) % modulus
+}
+
+fn next_u64(rng: &mut u64) -> u64 {
+    // xorshift64*, the same generator used for seeded randomness
+    // elsewhere in this crate (e.g. `Treap::with_seed`).
+    *rng ^= *rng << 13;
+    *rng ^= *rng >> 7;
+    *rng ^= *rng << 17;
+    *rng
+}
+
+/// A base in `[256, modulus)` — at least 256 so distinct byte values
+/// always contribute distinct digits, regardless of alphabet.
+fn random_base(rng: &mut u64, modulus: u64) -> u64 {
+    256 + next_u64(rng) % (modulus - 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substrings_equal_matches_naive_byte_comparison() {
+        let s = b"abracadabra_abracadabra";
+        let hasher = StringHasher::new(s);
+        for a_start in 0..s.len() {
+            for a_len in 1..=(s.len() - a_start) {
+                for b_start in 0..=(s.len() - a_len) {
+                    let a = a_start..a_start + a_len;
+                    let b = b_start..b_start + a_len;
+                    let expected = s[a.clone()] == s[b.clone()];
+                    assert_eq!(hasher.substrings_equal(a.clone(), b.clone()), expected, "{a:?} vs {b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn different_seeds_still_agree_on_equality() {
+        // "mississippi": bytes 1..4 and 4..7 are both "iss".
+        let s = b"mississippi";
+        for seed in [1u64, 42, 999_999_937] {
+            let hasher = StringHasher::with_seed(s, seed);
+            assert!(hasher.substrings_equal(1..4, 4..7));
+            assert!(!hasher.substrings_equal(0..4, 4..8)); // "miss" != "issi"
+        }
+    }
+
+    #[test]
+    fn empty_string_has_no_substrings_but_does_not_panic() {
+        let hasher = StringHasher::new(b"");
+        assert_eq!(hasher.len(), 0);
+        assert!(hasher.is_empty());
+        assert!(hasher.substrings_equal(0..0, 0..0));
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use super::suffix_array::SubstringComparator;
+
+/// A maximal periodic substring: `s[start..end]` has smallest period
+/// `period` and repeats at least twice (`end - start >= 2 * period`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+    pub period: usize,
+}
+
+/// Enumerates every run in `s` in O(n log n): for each candidate period,
+/// sweep starting positions and extend matches in both directions with
+/// O(1) LCP queries (one suffix array over `s`, one over its reverse for
+/// backward extension), skipping past each extension once found so the
+/// total work across all periods is the harmonic sum O(n log n). Runs
+/// are deduplicated by their `(start, end)` span, keeping the smallest
+/// period found for it — by the periodicity lemma, that is always the
+/// span's true minimal period.
+pub fn find_runs(s: &str) -> Vec<Run> {
+    let bytes = s.as_bytes();
+    let n = bytes.len();
+    if n < 2 {
+        return Vec::new();
+    }
+
+    let forward = SubstringComparator::new(s);
+    let reversed: Vec<u8> = bytes.iter().rev().copied().collect();
+    let backward = SubstringComparator::from_bytes(reversed);
+
+    let mut best: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+
+    for period in 1..n {
+        let mut i = 0;
+        while i + period < n {
+            if bytes[i] != bytes[i + period] {
+                i += 1;
+                continue;
+            }
+
+            let forward_match = forward.suffix_lcp(i, i + period);
+            let backward_match = backward.suffix_lcp(n - 1 - i, n - 1 - i - period);
+
+            let start = i + 1 - backward_match;
+            let end = i + period + forward_match;
+            if end - start >= 2 * period {
+                best.entry((start, end)).or_insert(period);
+            }
+
+            i += forward_match.max(1);
+        }
+    }
+
+    let mut runs: Vec<Run> =
+        best.into_iter().map(|((start, end), period)| Run { start, end, period }).collect();
+    runs.sort_by_key(|r| (r.start, r.end));
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn has_period(bytes: &[u8], l: usize, r: usize, p: usize) -> bool {
+        (l..r - p).all(|x| bytes[x] == bytes[x + p])
+    }
+
+    fn minimal_period(bytes: &[u8], l: usize, r: usize) -> usize {
+        (1..=(r - l)).find(|&p| has_period(bytes, l, r, p)).unwrap()
+    }
+
+    /// Brute-force reference: every maximal periodic substring, found by
+    /// checking periodicity and left/right maximality directly.
+    fn find_runs_brute_force(s: &str) -> HashSet<(usize, usize, usize)> {
+        let bytes = s.as_bytes();
+        let n = bytes.len();
+        let mut runs = HashSet::new();
+        for l in 0..n {
+            for r in (l + 1)..=n {
+                let p = minimal_period(bytes, l, r);
+                if r - l < 2 * p {
+                    continue;
+                }
+                let extends_left = l > 0 && bytes[l - 1] == bytes[l - 1 + p];
+                let extends_right = r < n && bytes[r] == bytes[r - p];
+                if !extends_left && !extends_right {
+                    runs.insert((l, r, p));
+                }
+            }
+        }
+        runs
+    }
+
+    fn as_set(runs: Vec<Run>) -> HashSet<(usize, usize, usize)> {
+        runs.into_iter().map(|r| (r.start, r.end, r.period)).collect()
+    }
+
+    #[test]
+    fn finds_runs_in_a_known_string() {
+        // "aabaabaab" is "aab" repeated three times, plus three shorter
+        // "aa" runs nested inside it — runs may overlap and nest as long
+        // as each is independently maximal for its own period.
+        let runs = find_runs("aabaabaab");
+        assert_eq!(
+            as_set(runs),
+            [(0, 9, 3), (0, 2, 1), (3, 5, 1), (6, 8, 1)].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn no_runs_in_a_string_with_no_repetition() {
+        assert_eq!(find_runs("abcde"), Vec::new());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force_on_short_strings(s in "[ab]{0,12}") {
+            prop_assert_eq!(as_set(find_runs(&s)), find_runs_brute_force(&s));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Builds the suffix array of `s` by rank-doubling: sort suffixes by their
+/// first character, then repeatedly refine the ordering using pairs of
+/// ranks over doubling-length prefixes, for O(n log^2 n) total.
+pub fn suffix_array(s: &[u8]) -> Vec<usize> {
+    build_suffix_array(s)
+}
+
+fn build_suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<i64> = s.iter().map(|&b| b as i64).collect();
+    let mut tmp = vec![0i64; n];
+
+    let mut k = 1;
+    while k < n {
+        let compare = |&a: &usize, &b: &usize| {
+            let key = |i: usize| (rank[i], if i + k < n { rank[i + k] } else { -1 });
+            key(a).cmp(&key(b))
+        };
+        sa.sort_unstable_by(compare);
+
+        tmp[sa[0]] = 0;
+        for i in 1..n {
+            let prev = sa[i - 1];
+            let cur = sa[i];
+            let prev_key = (rank[prev], if prev + k < n { rank[prev + k] } else { -1 });
+            let cur_key = (rank[cur], if cur + k < n { rank[cur + k] } else { -1 });
+            tmp[cur] = tmp[prev] + if prev_key == cur_key { 0 } else { 1 };
+        }
+        rank.clone_from_slice(&tmp);
+        if rank[sa[n - 1]] as usize == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// Kasai's algorithm: the LCP array `lcp[i]` is the longest common prefix
+/// of the suffixes at `sa[i - 1]` and `sa[i]` (`lcp[0]` is unused).
+fn build_lcp_array(s: &[u8], sa: &[usize], rank_of: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut lcp = vec![0usize; n];
+    let mut h = 0usize;
+    for i in 0..n {
+        if rank_of[i] > 0 {
+            let j = sa[rank_of[i] - 1];
+            while i + h < n && j + h < n && s[i + h] == s[j + h] {
+                h += 1;
+            }
+            lcp[rank_of[i]] = h;
+            h = h.saturating_sub(1);
+        } else {
+            h = 0;
+        }
+    }
+    lcp
+}
+
+/// Answers lexicographic-order and longest-common-prefix queries between
+/// arbitrary substrings of a fixed string, backed by a suffix array, an
+/// LCP array, and a sparse table for O(1) range-minimum queries over it.
+/// Built once in O(n log n); every query afterward is O(1).
+pub struct SubstringComparator {
+    s: Vec<u8>,
+    rank_of: Vec<usize>, // rank_of[i] = position of the suffix starting at i in the suffix array
+    sparse: Vec<Vec<usize>>,
+    log: Vec<usize>,
+}
+
+impl SubstringComparator {
+    pub fn new(s: &str) -> Self {
+        Self::from_bytes(s.as_bytes().to_vec())
+    }
+
+    /// Same as `new`, but for raw bytes that need not be valid UTF-8 (e.g.
+    /// a reversed string, used to answer "how far can this match extend
+    /// backward" queries).
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        let n = bytes.len();
+        let sa = build_suffix_array(&bytes);
+        let mut rank_of = vec![0usize; n];
+        for (i, &suffix) in sa.iter().enumerate() {
+            rank_of[suffix] = i;
+        }
+        let lcp = build_lcp_array(&bytes, &sa, &rank_of);
+
+        let mut log = vec![0usize; n + 1];
+        for i in 2..=n {
+            log[i] = log[i / 2] + 1;
+        }
+        let levels = if n > 0 { log[n] + 1 } else { 1 };
+        let mut sparse = vec![lcp.clone(); levels];
+        for level in 1..levels {
+            let half = 1usize << (level - 1);
+            let span = 1usize << level;
+            if span > n {
+                break;
+            }
+            for i in 0..=(n - span) {
+                sparse[level][i] = sparse[level - 1][i].min(sparse[level - 1][i + half]);
+            }
+        }
+
+        SubstringComparator { s: bytes, rank_of, sparse, log }
+    }
+
+    /// Minimum of `lcp[l..=r]` (both suffix-array positions, `l <= r`).
+    fn range_min_lcp(&self, l: usize, r: usize) -> usize {
+        let level = self.log[r - l + 1];
+        let half = 1usize << level;
+        self.sparse[level][l].min(self.sparse[level][r + 1 - half])
+    }
+
+    /// Longest common prefix of the two *full suffixes* starting at `i`
+    /// and `j`, answered in O(1) via the LCP sparse table.
+    pub fn suffix_lcp(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.s.len() - i;
+        }
+        let (mut l, mut r) = (self.rank_of[i], self.rank_of[j]);
+        if l > r {
+            core::mem::swap(&mut l, &mut r);
+        }
+        self.range_min_lcp(l + 1, r)
+    }
+
+    /// Longest common prefix of the substrings `s[a_start..a_start+a_len]`
+    /// and `s[b_start..b_start+b_len]`, clamped to their lengths.
+    pub fn lcp(&self, a_start: usize, a_len: usize, b_start: usize, b_len: usize) -> usize {
+        self.suffix_lcp(a_start, b_start).min(a_len).min(b_len)
+    }
+
+    /// Lexicographically compares `s[a_start..a_start+a_len]` against
+    /// `s[b_start..b_start+b_len]`.
+    pub fn compare(&self, a_start: usize, a_len: usize, b_start: usize, b_len: usize) -> Ordering {
+        let common = self.lcp(a_start, a_len, b_start, b_len);
+        match (common == a_len, common == b_len) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.s[a_start + common].cmp(&self.s[b_start + common]),
+        }
+    }
+}
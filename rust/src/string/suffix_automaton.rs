@@ -0,0 +1,233 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+const NULL: u32 = u32::MAX;
+
+/// One arena slot. `len` is the length of the longest string that
+/// reaches this state, `link` is its suffix link (the state for the
+/// longest proper suffix of that string that isn't equivalent to it),
+/// and `is_clone` marks states created by [`SuffixAutomaton::push`]'s
+/// split step rather than directly for a new prefix — the distinction
+/// [`SuffixAutomaton::occurrence_counts`] needs to seed its counts
+/// correctly.
+struct State {
+    len: usize,
+    link: u32,
+    transitions: BTreeMap<u8, u32>,
+    is_clone: bool,
+}
+
+impl State {
+    fn new(len: usize) -> Self {
+        State { len, link: NULL, transitions: BTreeMap::new(), is_clone: false }
+    }
+}
+
+/// A suffix automaton: the smallest DFA recognizing exactly the
+/// substrings of a string, built online one byte at a time (Blumer et
+/// al.'s algorithm) in O(n) amortized states and transitions. Every
+/// distinct substring corresponds to exactly one path from the root
+/// (state `0`), and every state's `len - link.len` is the count of
+/// distinct substrings ending there — which is what makes
+/// [`count_distinct_substrings`](Self::count_distinct_substrings) and
+/// [`occurrence_counts`](Self::occurrence_counts) a single linear pass
+/// over the states rather than an enumeration of substrings.
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    last: u32,
+}
+
+impl SuffixAutomaton {
+    pub fn new() -> Self {
+        SuffixAutomaton { states: alloc::vec![State::new(0)], last: 0 }
+    }
+
+    pub fn from_bytes(s: &[u8]) -> Self {
+        let mut automaton = Self::new();
+        for &byte in s {
+            automaton.push(byte);
+        }
+        automaton
+    }
+
+    /// Extends the automaton by one character. This is the "online"
+    /// half of the algorithm: the whole string never needs to be known
+    /// up front, only the next byte.
+    pub fn push(&mut self, byte: u8) {
+        let cur = self.new_state(self.states[self.last as usize].len + 1);
+
+        let mut p = self.last;
+        while p != NULL && !self.states[p as usize].transitions.contains_key(&byte) {
+            self.states[p as usize].transitions.insert(byte, cur);
+            p = self.states[p as usize].link;
+        }
+
+        if p == NULL {
+            self.states[cur as usize].link = 0;
+        } else {
+            let q = self.states[p as usize].transitions[&byte];
+            if self.states[p as usize].len + 1 == self.states[q as usize].len {
+                self.states[cur as usize].link = q;
+            } else {
+                let clone = self.new_state(self.states[p as usize].len + 1);
+                self.states[clone as usize].transitions = self.states[q as usize].transitions.clone();
+                self.states[clone as usize].link = self.states[q as usize].link;
+                self.states[clone as usize].is_clone = true;
+
+                let mut cursor = p;
+                while cursor != NULL && self.states[cursor as usize].transitions.get(&byte) == Some(&q) {
+                    self.states[cursor as usize].transitions.insert(byte, clone);
+                    cursor = self.states[cursor as usize].link;
+                }
+                self.states[q as usize].link = clone;
+                self.states[cur as usize].link = clone;
+            }
+        }
+
+        self.last = cur;
+    }
+
+    /// Count of distinct substrings of the string built so far.
+    pub fn count_distinct_substrings(&self) -> u64 {
+        let mut total = 0u64;
+        for i in 1..self.states.len() {
+            let link_len = self.states[self.states[i].link as usize].len;
+            total += (self.states[i].len - link_len) as u64;
+        }
+        total
+    }
+
+    /// How many times the substring(s) ending at each state occur in
+    /// the original string, indexed by state id (state `0`, the root,
+    /// is always `0`). Computed by processing states in descending
+    /// `len` order and pushing each state's count up its suffix link —
+    /// every occurrence of a longer substring is also an occurrence of
+    /// every suffix-linked ancestor's substrings.
+    pub fn occurrence_counts(&self) -> Vec<u64> {
+        let mut order: Vec<u32> = (0..self.states.len() as u32).collect();
+        order.sort_unstable_by_key(|&i| core::cmp::Reverse(self.states[i as usize].len));
+
+        let mut counts: Vec<u64> =
+            self.states.iter().map(|state| if state.is_clone || state.len == 0 { 0 } else { 1 }).collect();
+        for &i in &order {
+            let link = self.states[i as usize].link;
+            if link != NULL {
+                counts[link as usize] += counts[i as usize];
+            }
+        }
+        counts
+    }
+
+    /// The longest substring common to both `a` and `b` (either, if
+    /// there's a tie). Builds an automaton over `a`, then walks `b`
+    /// through it one byte at a time, following suffix links to drop
+    /// back to a shorter match whenever the next byte has no
+    /// transition — the same idea Z/KMP use for string matching,
+    /// generalized to "match against every substring of `a` at once".
+    pub fn longest_common_substring(a: &[u8], b: &[u8]) -> Vec<u8> {
+        let automaton = SuffixAutomaton::from_bytes(a);
+
+        let mut node = 0u32;
+        let mut length = 0usize;
+        let mut best_len = 0usize;
+        let mut best_end = 0usize;
+
+        for (i, &byte) in b.iter().enumerate() {
+            while node != 0 && !automaton.states[node as usize].transitions.contains_key(&byte) {
+                node = automaton.states[node as usize].link;
+                length = automaton.states[node as usize].len;
+            }
+            if let Some(&next) = automaton.states[node as usize].transitions.get(&byte) {
+                node = next;
+                length += 1;
+            }
+            if length > best_len {
+                best_len = length;
+                best_end = i + 1;
+            }
+        }
+
+        b[best_end - best_len..best_end].to_vec()
+    }
+
+    fn new_state(&mut self, len: usize) -> u32 {
+        self.states.push(State::new(len));
+        (self.states.len() - 1) as u32
+    }
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_distinct_substrings(s: &[u8]) -> usize {
+        let mut seen = alloc::collections::BTreeSet::new();
+        for start in 0..s.len() {
+            for end in start + 1..=s.len() {
+                seen.insert(&s[start..end]);
+            }
+        }
+        seen.len()
+    }
+
+    fn brute_force_occurrences(s: &[u8], sub: &[u8]) -> usize {
+        if sub.is_empty() {
+            return 0;
+        }
+        (0..=s.len().saturating_sub(sub.len())).filter(|&i| &s[i..i + sub.len()] == sub).count()
+    }
+
+    fn brute_force_lcs(a: &[u8], b: &[u8]) -> usize {
+        let mut best = 0;
+        for i in 0..a.len() {
+            for j in (i + 1)..=a.len() {
+                if b.windows(j - i).any(|w| w == &a[i..j]) {
+                    best = best.max(j - i);
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn count_distinct_substrings_matches_brute_force() {
+        for s in [&b""[..], b"a", b"aa", b"abcbc", b"banana", b"aaaaa", b"abcdefg"] {
+            let automaton = SuffixAutomaton::from_bytes(s);
+            assert_eq!(automaton.count_distinct_substrings() as usize, brute_force_distinct_substrings(s), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn occurrence_counts_match_brute_force_for_every_substring() {
+        let s = b"abcabcabc";
+        let automaton = SuffixAutomaton::from_bytes(s);
+        let counts = automaton.occurrence_counts();
+
+        // Re-walk every substring of `s` through the built automaton and
+        // check its state's count against a brute-force scan.
+        for start in 0..s.len() {
+            let mut node = 0u32;
+            for end in start + 1..=s.len() {
+                node = automaton.states[node as usize].transitions[&s[end - 1]];
+                let sub = &s[start..end];
+                assert_eq!(counts[node as usize] as usize, brute_force_occurrences(s, sub), "{sub:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn longest_common_substring_matches_brute_force() {
+        let cases: &[(&[u8], &[u8])] =
+            &[(b"abcdef", b"zcdefg"), (b"abc", b"xyz"), (b"banana", b"ananas"), (b"", b"abc"), (b"abc", b"abc")];
+        for &(a, b) in cases {
+            let found = SuffixAutomaton::longest_common_substring(a, b);
+            assert_eq!(found.len(), brute_force_lcs(a, b), "a={a:?} b={b:?}");
+        }
+    }
+}
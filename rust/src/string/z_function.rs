@@ -0,0 +1,112 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The Z-array: `z[i]` is the length of the longest common prefix of
+/// `s` and `s[i..]` (`z[0]` is conventionally `0`, since "the whole
+/// string vs. itself" isn't a useful answer). Computed in O(n) by
+/// reusing the most recent match window `[l, r)` to skip already-known
+/// agreement instead of comparing from scratch at every position.
+pub fn z_function(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0usize; n];
+    let mut l = 0usize;
+    let mut r = 0usize;
+    for i in 1..n {
+        if i < r {
+            z[i] = core::cmp::min(r - i, z[i - l]);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+/// Every start position in `text` where `pattern` occurs, via the
+/// Z-array of `pattern` concatenated with `text`: a match at text
+/// position `i` is exactly a combined-string position `pattern.len() +
+/// i` whose Z-value reaches at least `pattern.len()` (no separator byte
+/// is needed between them — a Z-value that overruns into `text` just
+/// means the match happens to extend further, which still proves the
+/// first `pattern.len()` bytes agree).
+pub fn z_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+    let mut combined = pattern.to_vec();
+    combined.extend_from_slice(text);
+    let z = z_function(&combined);
+    let m = pattern.len();
+    (m..combined.len()).filter(|&i| z[i] >= m).map(|i| i - m).collect()
+}
+
+/// The length of the shortest string `s` is a whole number of copies
+/// of — the same notion [`crate::string::kmp::shortest_period`]
+/// computes from the prefix function, derived here instead from the
+/// Z-array: the smallest `k` dividing `n` such that `s[k..]` and
+/// `s[..n - k]` agree for the whole remaining length is exactly the
+/// period, because that agreement is what "repeating a `k`-byte block"
+/// means. `s[..k]` is then the compressed block for that period.
+pub fn shortest_period(s: &[u8]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    let z = z_function(s);
+    for (k, &z_k) in z.iter().enumerate().take(n).skip(1) {
+        if n.is_multiple_of(k) && z_k == n - k {
+            return k;
+        }
+    }
+    n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::kmp;
+
+    fn brute_force_matches(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        if pattern.is_empty() || pattern.len() > text.len() {
+            return Vec::new();
+        }
+        (0..=text.len() - pattern.len()).filter(|&i| &text[i..i + pattern.len()] == pattern).collect()
+    }
+
+    #[test]
+    fn z_function_matches_known_values() {
+        assert_eq!(z_function(b"aaabaab"), alloc::vec![0, 2, 1, 0, 2, 1, 0]);
+        assert_eq!(z_function(b"abcabc"), alloc::vec![0, 0, 0, 3, 0, 0]);
+        assert_eq!(z_function(b""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn z_search_matches_brute_force() {
+        let cases: &[(&[u8], &[u8])] = &[
+            (b"ababcababcababc", b"abc"),
+            (b"aaaaaa", b"aa"),
+            (b"abcdef", b"xyz"),
+            (b"abc", b""),
+            (b"", b"abc"),
+            (b"mississippi", b"issi"),
+        ];
+        for &(text, pattern) in cases {
+            assert_eq!(z_search(text, pattern), brute_force_matches(text, pattern), "text={text:?} pattern={pattern:?}");
+        }
+    }
+
+    #[test]
+    fn shortest_period_matches_kmp_on_random_short_strings() {
+        let alphabet = b"ab";
+        for len in 0..10 {
+            for mask in 0..(1u32 << len) {
+                let s: Vec<u8> = (0..len).map(|bit| alphabet[((mask >> bit) & 1) as usize]).collect();
+                assert_eq!(shortest_period(&s), kmp::shortest_period(&s), "{s:?}");
+            }
+        }
+    }
+}
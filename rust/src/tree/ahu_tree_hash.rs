@@ -0,0 +1,120 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// AHU (Aho-Hopcroft-Ullman) canonical hashing for rooted trees: each
+/// node's canonical label is built bottom-up from the *sorted multiset* of
+/// its children's labels, so two rooted trees are isomorphic iff their
+/// roots get the same label.
+///
+/// Computes a canonical label for every node of a rooted tree, bottom-up.
+/// Returns the label assigned to `root`.
+pub fn ahu_label(adj: &[Vec<usize>], root: usize) -> u64 {
+    ahu_label_in(adj, root, adj.len())
+}
+
+/// Returns whether the two rooted trees are isomorphic.
+pub fn rooted_isomorphic(adj_a: &[Vec<usize>], root_a: usize, adj_b: &[Vec<usize>], root_b: usize) -> bool {
+    // Labels must be computed in the same namespace so equal structures
+    // collide to equal ids; combine both trees into one disjoint structure
+    // and label them in a single pass sharing one interning table, rather
+    // than labelling each tree with its own fresh table (which would make
+    // `label_a == label_b` meaningless coincidence).
+    let offset = adj_a.len();
+    let n = adj_a.len() + adj_b.len();
+    let mut combined = vec![Vec::new(); n];
+    for (u, neighbors) in adj_a.iter().enumerate() {
+        combined[u] = neighbors.clone();
+    }
+    for (u, neighbors) in adj_b.iter().enumerate() {
+        combined[u + offset] = neighbors.iter().map(|&v| v + offset).collect();
+    }
+
+    let mut label_id: BTreeMap<Vec<u64>, u64> = BTreeMap::new();
+    let mut label = vec![0u64; n];
+    let mut visited = vec![false; n];
+    label_tree_into(&combined, root_a, &mut label, &mut visited, &mut label_id);
+    label_tree_into(&combined, root_b + offset, &mut label, &mut visited, &mut label_id);
+    label[root_a] == label[root_b + offset]
+}
+
+fn ahu_label_in(adj: &[Vec<usize>], root: usize, n: usize) -> u64 {
+    let mut label_id: BTreeMap<Vec<u64>, u64> = BTreeMap::new();
+    let mut label = vec![0u64; n];
+    let mut visited = vec![false; n];
+    label_tree_into(adj, root, &mut label, &mut visited, &mut label_id)
+}
+
+/// Labels every unvisited node reachable from `root`, bottom-up, interning
+/// each node's sorted child-label multiset into the shared `label_id`
+/// table so callers labelling more than one tree get a shared namespace.
+fn label_tree_into(
+    adj: &[Vec<usize>],
+    root: usize,
+    label: &mut [u64],
+    visited: &mut [bool],
+    label_id: &mut BTreeMap<Vec<u64>, u64>,
+) -> u64 {
+    let n = adj.len();
+    let mut parent = vec![usize::MAX; n];
+    let mut order = Vec::new();
+    let mut stack = vec![(root, usize::MAX)];
+    while let Some((u, p)) = stack.pop() {
+        if visited[u] {
+            continue;
+        }
+        visited[u] = true;
+        parent[u] = p;
+        order.push(u);
+        for &v in &adj[u] {
+            if v != p {
+                stack.push((v, u));
+            }
+        }
+    }
+
+    for &u in order.iter().rev() {
+        let mut child_labels: Vec<u64> =
+            adj[u].iter().filter(|&&v| v != parent[u] && visited[v]).map(|&v| label[v]).collect();
+        child_labels.sort_unstable();
+        let next_id = label_id.len() as u64;
+        label[u] = *label_id.entry(child_labels).or_insert(next_id);
+    }
+    label[root]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_node_tree_has_a_stable_label() {
+        assert_eq!(ahu_label(&[Vec::new()], 0), ahu_label(&[Vec::new()], 0));
+    }
+
+    #[test]
+    fn isomorphic_trees_with_differently_ordered_children_match() {
+        // Tree A:     0          Tree B:   0
+        //           / | \                / | \
+        //          1  2  3              1  2  3
+        //          |                       |
+        //          4                       4
+        let adj_a = vec![vec![1, 2, 3], vec![0, 4], vec![0], vec![0], vec![1]];
+        let adj_b = vec![vec![1, 2, 3], vec![0], vec![0, 4], vec![0], vec![2]];
+        assert!(rooted_isomorphic(&adj_a, 0, &adj_b, 0));
+
+        // Same shape as A but the grandchild hangs off a different child —
+        // still isomorphic as an unordered rooted tree.
+        let adj_c = vec![vec![1, 2, 3], vec![0], vec![0], vec![0, 4], vec![3]];
+        assert!(rooted_isomorphic(&adj_a, 0, &adj_c, 0));
+    }
+
+    #[test]
+    fn trees_with_different_shapes_do_not_match() {
+        // A has one child with a grandchild; D has two children each with
+        // their own grandchild — not isomorphic.
+        let adj_a = vec![vec![1, 2, 3], vec![0, 4], vec![0], vec![0], vec![1]];
+        let adj_d = vec![vec![1, 2], vec![0, 3], vec![0, 4], vec![1], vec![2]];
+        assert!(!rooted_isomorphic(&adj_a, 0, &adj_d, 0));
+    }
+}
@@ -0,0 +1,38 @@
+// An owned binary tree, for algorithms whose output *is* a tree (optimal
+// BST construction, Huffman-style builds) rather than ones that only
+// traverse an existing adjacency list.
+
+use alloc::boxed::Box;
+
+/// A binary tree over keys of type `K`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryTree<K> {
+    Leaf,
+    Node { key: K, left: Box<BinaryTree<K>>, right: Box<BinaryTree<K>> },
+}
+
+impl<K> BinaryTree<K> {
+    pub fn leaf() -> Self {
+        BinaryTree::Leaf
+    }
+
+    pub fn node(key: K, left: Self, right: Self) -> Self {
+        BinaryTree::Node { key, left: Box::new(left), right: Box::new(right) }
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        matches!(self, BinaryTree::Leaf)
+    }
+
+    /// Number of keys stored in the tree.
+    pub fn len(&self) -> usize {
+        match self {
+            BinaryTree::Leaf => 0,
+            BinaryTree::Node { left, right, .. } => 1 + left.len() + right.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Euler tour technique for static trees: a single DFS assigns each node a
+/// `tin`/`tout` pair such that a node's entire subtree maps to the
+/// contiguous range `[tin[v], tout[v])`. Subtree-sum/update queries then
+/// reduce to range queries on a Fenwick tree over the flattened array.
+pub struct EulerTour {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+    order: Vec<usize>, // order[tin[v]] == v
+}
+
+impl EulerTour {
+    pub fn build(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        let mut tin = vec![0usize; n];
+        let mut tout = vec![0usize; n];
+        let mut order = Vec::with_capacity(n);
+        let mut timer = 0usize;
+
+        let mut stack = vec![(root, usize::MAX, false)];
+        while let Some((u, parent, leaving)) = stack.pop() {
+            if leaving {
+                tout[u] = timer;
+                continue;
+            }
+            tin[u] = timer;
+            order.push(u);
+            timer += 1;
+            stack.push((u, parent, true));
+            for &v in &adj[u] {
+                if v != parent {
+                    stack.push((v, u, false));
+                }
+            }
+        }
+
+        EulerTour { tin, tout, order }
+    }
+
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the flattened-array range `[lo, hi)` covering `v`'s subtree.
+    pub fn subtree_range(&self, v: usize) -> (usize, usize) {
+        (self.tin[v], self.tout[v])
+    }
+
+    pub fn is_ancestor(&self, u: usize, v: usize) -> bool {
+        self.tin[u] <= self.tin[v] && self.tout[v] <= self.tout[u]
+    }
+}
+
+/// Fenwick tree over the flattened array, for O(log n) subtree-sum and
+/// point-update once node values are mapped via `subtree_range`.
+pub struct Fenwick {
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    pub fn new(n: usize) -> Self {
+        Fenwick { tree: vec![0; n + 1] }
+    }
+
+    pub fn add(&mut self, mut i: usize, delta: i64) {
+        i += 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    pub fn prefix_sum(&self, mut i: usize) -> i64 {
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    pub fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        self.prefix_sum(hi) - self.prefix_sum(lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tree() -> Vec<Vec<usize>> {
+        // Tree:        0
+        //            / | \
+        //           1  2  3
+        //          /|
+        //         4 5
+        vec![vec![1, 2, 3], vec![0, 4, 5], vec![0], vec![0], vec![1], vec![1]]
+    }
+
+    #[test]
+    fn subtree_range_covers_exactly_the_subtrees_descendants() {
+        let tour = EulerTour::build(&sample_tree(), 0);
+        let (lo, hi) = tour.subtree_range(1);
+        let covered: Vec<usize> = tour.order()[lo..hi].to_vec();
+        let mut sorted = covered.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn is_ancestor_matches_subtree_containment() {
+        let tour = EulerTour::build(&sample_tree(), 0);
+        assert!(tour.is_ancestor(1, 5));
+        assert!(tour.is_ancestor(0, 5));
+        assert!(!tour.is_ancestor(2, 5));
+        assert!(!tour.is_ancestor(5, 1));
+    }
+
+    #[test]
+    fn fenwick_subtree_sums_reflect_point_updates() {
+        let adj = sample_tree();
+        let values = [10, 20, 30, 40, 50, 60];
+        let tour = EulerTour::build(&adj, 0);
+        let mut fenwick = Fenwick::new(adj.len());
+        for (v, &val) in values.iter().enumerate() {
+            fenwick.add(tour.tin[v], val);
+        }
+
+        let (lo, hi) = tour.subtree_range(1);
+        assert_eq!(fenwick.range_sum(lo, hi), 20 + 50 + 60);
+
+        // Update node 5's value from 60 to 100.
+        fenwick.add(tour.tin[5], 40);
+        let (lo, hi) = tour.subtree_range(1);
+        assert_eq!(fenwick.range_sum(lo, hi), 20 + 50 + 100);
+    }
+}
@@ -0,0 +1,176 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Euler tour tree: represents a dynamic forest as a balanced-BST-ordered
+/// sequence of "visit" tokens (2n - 1 tokens for an n-node tree), so that
+/// link/cut/connected all reduce to splits and merges of the sequence.
+/// This simplified version uses an order-statistics `Vec<usize>` sequence
+/// keyed by token id rather than a full balanced tree, trading asymptotics
+/// for clarity — sufficient for small-to-medium forests.
+pub struct EulerTourTree {
+    n: usize,
+    // occurrences[v] lists every appearance of vertex v in the tour, in
+    // left-to-right sequence order (each edge traversal adds one).
+    sequence: Vec<usize>,      // the tour itself, as vertex ids
+    position: Vec<Vec<usize>>, // position[v] = indices into `sequence` where v occurs
+}
+
+impl EulerTourTree {
+    pub fn new(n: usize) -> Self {
+        let mut sequence = Vec::with_capacity(n);
+        let mut position = vec![Vec::new(); n];
+        for (v, occurrences) in position.iter_mut().enumerate() {
+            occurrences.push(sequence.len());
+            sequence.push(v);
+        }
+        EulerTourTree { n, sequence, position }
+    }
+
+    fn first_occurrence(&self, v: usize) -> usize {
+        self.position[v][0]
+    }
+
+    /// Links `u` and `v`, assumed to be roots of distinct trees (i.e. this
+    /// models forming a new tree edge between two tour fragments). The
+    /// combined tour is `tour(u) ++ [v] ++ tour(v) ++ [v]` splice-inserted
+    /// right after `u`'s first occurrence.
+    pub fn link(&mut self, u: usize, v: usize) {
+        let tour_v = self.extract_component(v);
+        let insert_at = self.first_occurrence(u) + 1;
+
+        let mut spliced = Vec::with_capacity(tour_v.len() + 2);
+        spliced.push(u);
+        spliced.extend(tour_v);
+        spliced.push(u);
+
+        self.sequence.splice(insert_at..insert_at, spliced);
+        self.rebuild_positions();
+    }
+
+    /// Removes and returns the full tour belonging to `v`'s component.
+    fn extract_component(&mut self, v: usize) -> Vec<usize> {
+        let comp = self.component_of(v);
+        let start = comp.0;
+        let end = comp.1;
+        let tour: Vec<usize> = self.sequence[start..end].to_vec();
+        self.sequence.drain(start..end);
+        self.rebuild_positions();
+        tour
+    }
+
+    /// Returns the `[start, end)` range in `sequence` spanning `v`'s tree.
+    /// Found by a BFS purely over the occurrence structure, independent of
+    /// how `link` spliced things together: the component is the minimal
+    /// contiguous run containing every occurrence reachable by repeatedly
+    /// widening to cover nested subtrees.
+    fn component_of(&self, v: usize) -> (usize, usize) {
+        // Walk outward from v's occurrences while the surrounding tokens
+        // still belong to the same balanced parenthesis structure: every
+        // tour is a valid sequence of matched visits, so the component is
+        // found by scanning outward until depth returns to 0 relative to
+        // v's own nesting level.
+        let positions = &self.position[v];
+        let mut lo = positions[0];
+        let mut hi = positions[positions.len() - 1] + 1;
+
+        // Expand left while the token immediately outside still pairs
+        // with something inside the current window (same vertex appears
+        // again further left/right), i.e. while the window isn't yet a
+        // balanced run on its own.
+        loop {
+            let mut counts = BTreeMap::new();
+            for &x in &self.sequence[lo..hi] {
+                *counts.entry(x).or_insert(0) += 1;
+            }
+            let mut expanded = false;
+            if lo > 0 {
+                let left = self.sequence[lo - 1];
+                if counts.get(&left).copied().unwrap_or(0) % 2 == 1 {
+                    lo -= 1;
+                    expanded = true;
+                }
+            }
+            if hi < self.sequence.len() {
+                let right = self.sequence[hi];
+                if counts.get(&right).copied().unwrap_or(0) % 2 == 1 {
+                    hi += 1;
+                    expanded = true;
+                }
+            }
+            if !expanded {
+                break;
+            }
+        }
+        (lo, hi)
+    }
+
+    fn rebuild_positions(&mut self) {
+        for p in self.position.iter_mut() {
+            p.clear();
+        }
+        for (i, &v) in self.sequence.iter().enumerate() {
+            self.position[v].push(i);
+        }
+    }
+
+    /// Returns whether `u` and `v` are in the same tree.
+    pub fn connected(&self, u: usize, v: usize) -> bool {
+        let (lo, hi) = self.component_of(u);
+        self.position[v].iter().any(|&p| p >= lo && p < hi)
+    }
+
+    /// Cuts the tree edge between `u` and `v`, assumed to have been created
+    /// by a prior `link(u, v)` call whose `u, <v's tour>, u` bracket is
+    /// still intact and unsplit by later structural changes.
+    pub fn cut(&mut self, u: usize, v: usize) {
+        let (lo, hi) = self.component_of(v);
+        assert!(lo > 0 && self.sequence[lo - 1] == u, "no intact u-v bracket to cut");
+        assert!(hi < self.sequence.len() && self.sequence[hi] == u, "no intact u-v bracket to cut");
+
+        let v_tour: Vec<usize> = self.sequence[lo..hi].to_vec();
+        self.sequence.drain((lo - 1)..(hi + 1)); // remove both bracketing `u`s and v's tour
+        self.sequence.extend(v_tour); // v's component now stands on its own
+        self.rebuild_positions();
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linking_connects_and_cutting_disconnects() {
+        let mut forest = EulerTourTree::new(6);
+        forest.link(0, 1);
+        forest.link(1, 2);
+        forest.link(3, 4);
+
+        assert!(forest.connected(0, 2));
+        assert!(!forest.connected(0, 3));
+        assert_eq!(forest.n(), 6);
+
+        forest.link(2, 3);
+        assert!(forest.connected(0, 4));
+
+        forest.cut(2, 3);
+        assert!(!forest.connected(0, 4));
+        assert!(!forest.connected(2, 3));
+        assert!(forest.connected(0, 2));
+        assert!(forest.connected(3, 4));
+    }
+
+    #[test]
+    fn a_fresh_forest_has_every_vertex_isolated() {
+        let forest = EulerTourTree::new(4);
+        for u in 0..4 {
+            for v in 0..4 {
+                assert_eq!(forest.connected(u, v), u == v);
+            }
+        }
+    }
+}
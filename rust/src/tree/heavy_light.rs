@@ -0,0 +1,360 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::swap;
+
+struct SegTree {
+    n: usize,
+    tree: Vec<i64>,
+}
+
+impl SegTree {
+    fn new(n: usize) -> Self {
+        SegTree { n, tree: vec![i64::MIN; 2 * n] }
+    }
+
+    fn update(&mut self, mut i: usize, value: i64) {
+        i += self.n;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i].max(self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Maximum over the half-open range `[l, r)`.
+    fn query(&self, mut l: usize, mut r: usize) -> i64 {
+        let mut result = i64::MIN;
+        l += self.n;
+        r += self.n;
+        while l < r {
+            if l & 1 == 1 {
+                result = result.max(self.tree[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                result = result.max(self.tree[r]);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        result
+    }
+}
+
+struct SumSegTree {
+    n: usize,
+    tree: Vec<i64>,
+}
+
+impl SumSegTree {
+    fn new(n: usize) -> Self {
+        SumSegTree { n, tree: vec![0; 2 * n] }
+    }
+    fn update(&mut self, mut i: usize, value: i64) {
+        i += self.n;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i] + self.tree[2 * i + 1];
+        }
+    }
+    fn query(&self, mut l: usize, mut r: usize) -> i64 {
+        let mut result = 0;
+        l += self.n;
+        r += self.n;
+        while l < r {
+            if l & 1 == 1 {
+                result += self.tree[l];
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                result += self.tree[r];
+            }
+            l /= 2;
+            r /= 2;
+        }
+        result
+    }
+}
+
+struct MinSegTree {
+    n: usize,
+    tree: Vec<i64>,
+}
+
+impl MinSegTree {
+    fn new(n: usize) -> Self {
+        MinSegTree { n, tree: vec![i64::MAX; 2 * n] }
+    }
+    fn update(&mut self, mut i: usize, value: i64) {
+        i += self.n;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i].min(self.tree[2 * i + 1]);
+        }
+    }
+    fn query(&self, mut l: usize, mut r: usize) -> i64 {
+        let mut result = i64::MAX;
+        l += self.n;
+        r += self.n;
+        while l < r {
+            if l & 1 == 1 {
+                result = result.min(self.tree[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                result = result.min(self.tree[r]);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        result
+    }
+}
+
+/// Heavy-light decomposition: flattens a tree into O(log n) contiguous
+/// chains so that path queries (e.g. max edge weight between two nodes)
+/// can be answered with O(log n) segment-tree range queries per chain hop.
+///
+/// `lca`, `path_length` and `kth_node_on_path` build on the same chain
+/// walk as the aggregation queries, so callers get the common tree-query
+/// patterns without needing to understand the decomposition themselves.
+pub struct HeavyLight {
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    head: Vec<usize>,      // chain head for each node
+    pos: Vec<usize>,       // index into the flattened segment-tree array
+    vertex_at: Vec<usize>, // inverse of `pos`
+    seg_max: SegTree,
+    seg_min: MinSegTree,
+    seg_sum: SumSegTree,
+}
+
+impl HeavyLight {
+    /// Builds the decomposition over a rooted tree with edge weights
+    /// attached to the child endpoint: `node_weight[v]` is the weight of
+    /// the edge from `v` to its parent (`node_weight[root]` is unused).
+    pub fn new(adj: &[Vec<usize>], root: usize, node_weight: &[i64]) -> Self {
+        let n = adj.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![0usize; n];
+        let mut size = vec![1usize; n];
+        let mut order = Vec::new();
+
+        // Iterative post-order to compute subtree sizes.
+        let mut stack = vec![(root, usize::MAX)];
+        let mut visit_order = Vec::new();
+        while let Some((u, p)) = stack.pop() {
+            parent[u] = p;
+            visit_order.push(u);
+            for &v in &adj[u] {
+                if v != p {
+                    depth[v] = depth[u] + 1;
+                    stack.push((v, u));
+                }
+            }
+        }
+        for &u in visit_order.iter().rev() {
+            if parent[u] != usize::MAX {
+                size[parent[u]] += size[u];
+            }
+        }
+        order.extend(visit_order);
+
+        // Decompose into chains: descend into the heaviest child each time.
+        let mut head = vec![usize::MAX; n];
+        let mut pos = vec![0usize; n];
+        let mut heavy_child = vec![usize::MAX; n];
+        for &u in &order {
+            let mut best = usize::MAX;
+            let mut best_size = 0;
+            for &v in &adj[u] {
+                if v != parent[u] && size[v] > best_size {
+                    best_size = size[v];
+                    best = v;
+                }
+            }
+            heavy_child[u] = best;
+        }
+
+        let mut counter = 0usize;
+        let mut stack = vec![root];
+        head[root] = root;
+        while let Some(mut u) = stack.pop() {
+            // Walk down a whole heavy chain starting at `u`.
+            head[u] = if parent[u] != usize::MAX && heavy_child[parent[u]] == u { head[parent[u]] } else { u };
+            loop {
+                pos[u] = counter;
+                counter += 1;
+                for &v in &adj[u] {
+                    if v != parent[u] && v != heavy_child[u] {
+                        stack.push(v);
+                    }
+                }
+                if heavy_child[u] == usize::MAX {
+                    break;
+                }
+                head[heavy_child[u]] = head[u];
+                u = heavy_child[u];
+            }
+        }
+
+        let mut vertex_at = vec![0usize; n];
+        for v in 0..n {
+            vertex_at[pos[v]] = v;
+        }
+
+        let mut seg_max = SegTree::new(n);
+        let mut seg_min = MinSegTree::new(n);
+        let mut seg_sum = SumSegTree::new(n);
+        for v in 0..n {
+            if v != root {
+                seg_max.update(pos[v], node_weight[v]);
+                seg_min.update(pos[v], node_weight[v]);
+                seg_sum.update(pos[v], node_weight[v]);
+            }
+        }
+
+        HeavyLight { parent, depth, head, pos, vertex_at, seg_max, seg_min, seg_sum }
+    }
+
+    /// Lowest common ancestor of `u` and `v`.
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]];
+        }
+        if self.depth[u] < self.depth[v] { u } else { v }
+    }
+
+    /// Number of edges on the path between `u` and `v`.
+    pub fn path_length(&self, u: usize, v: usize) -> usize {
+        let anc = self.lca(u, v);
+        self.depth[u] + self.depth[v] - 2 * self.depth[anc]
+    }
+
+    /// The `k`-th vertex (0-indexed from `u`) on the path from `u` to `v`.
+    pub fn kth_node_on_path(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let anc = self.lca(u, v);
+        let dist_to_anc = self.depth[u] - self.depth[anc];
+        if k <= dist_to_anc {
+            // k-th ancestor of u.
+            self.kth_ancestor(u, k)
+        } else {
+            // Mirror: count from v's side instead.
+            let remaining = self.path_length(u, v) - k;
+            self.kth_ancestor(v, remaining)
+        }
+    }
+
+    fn kth_ancestor(&self, mut u: usize, mut k: usize) -> Option<usize> {
+        loop {
+            if k == 0 {
+                return Some(u);
+            }
+            let chain_depth = self.depth[u] - self.depth[self.head[u]];
+            if k <= chain_depth {
+                return Some(self.vertex_at[self.pos[u] - k]);
+            }
+            k -= chain_depth + 1;
+            u = self.parent[self.head[u]];
+        }
+    }
+
+    /// Maximum edge weight on the path between `u` and `v`.
+    pub fn query_path_max(&self, mut u: usize, mut v: usize) -> i64 {
+        let mut result = i64::MIN;
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                swap(&mut u, &mut v);
+            }
+            result = result.max(self.seg_max.query(self.pos[self.head[u]], self.pos[u] + 1));
+            u = self.parent[self.head[u]];
+        }
+        if u != v {
+            let (lo, hi) = if self.depth[u] < self.depth[v] { (u, v) } else { (v, u) };
+            result = result.max(self.seg_max.query(self.pos[lo] + 1, self.pos[hi] + 1));
+        }
+        result
+    }
+
+    /// Minimum edge weight on the path between `u` and `v`.
+    pub fn query_path_min(&self, mut u: usize, mut v: usize) -> i64 {
+        let mut result = i64::MAX;
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                swap(&mut u, &mut v);
+            }
+            result = result.min(self.seg_min.query(self.pos[self.head[u]], self.pos[u] + 1));
+            u = self.parent[self.head[u]];
+        }
+        if u != v {
+            let (lo, hi) = if self.depth[u] < self.depth[v] { (u, v) } else { (v, u) };
+            result = result.min(self.seg_min.query(self.pos[lo] + 1, self.pos[hi] + 1));
+        }
+        result
+    }
+
+    /// Sum of edge weights on the path between `u` and `v`.
+    pub fn query_path_sum(&self, mut u: usize, mut v: usize) -> i64 {
+        let mut result = 0;
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                swap(&mut u, &mut v);
+            }
+            result += self.seg_sum.query(self.pos[self.head[u]], self.pos[u] + 1);
+            u = self.parent[self.head[u]];
+        }
+        if u != v {
+            let (lo, hi) = if self.depth[u] < self.depth[v] { (u, v) } else { (v, u) };
+            result += self.seg_sum.query(self.pos[lo] + 1, self.pos[hi] + 1);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> HeavyLight {
+        // Tree rooted at 0, edge weights attached to the child.
+        let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0, 5], vec![1], vec![1], vec![2]];
+        let node_weight = vec![0, 5, 3, 10, 2, 7]; // weight of edge (parent, v)
+        HeavyLight::new(&adj, 0, &node_weight)
+    }
+
+    #[test]
+    fn path_aggregations_match_hand_computed_values() {
+        let hl = sample();
+        assert_eq!(hl.query_path_max(3, 4), 10);
+        assert_eq!(hl.query_path_max(3, 5), 10);
+        assert_eq!(hl.query_path_max(4, 2), 5);
+        assert_eq!(hl.query_path_min(3, 5), 3);
+        assert_eq!(hl.query_path_sum(3, 5), 10 + 5 + 3 + 7);
+    }
+
+    #[test]
+    fn lca_and_path_length_match_hand_computed_values() {
+        let hl = sample();
+        assert_eq!(hl.lca(3, 5), 0);
+        assert_eq!(hl.path_length(3, 5), 4);
+        assert_eq!(hl.lca(3, 4), 1);
+        assert_eq!(hl.path_length(3, 4), 2);
+    }
+
+    #[test]
+    fn kth_node_on_path_walks_from_u_to_v_in_order() {
+        let hl = sample();
+        let expected = [3, 1, 0, 2, 5];
+        for (k, &node) in expected.iter().enumerate() {
+            assert_eq!(hl.kth_node_on_path(3, 5, k), Some(node));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Lowest common ancestor via an Euler tour of the tree reduced to a range
+/// minimum query, answered in O(1) with a sparse table after an O(n log n)
+/// build. Much faster per-query than binary lifting when there are many
+/// queries on a static tree.
+pub struct LcaSparseTable {
+    first_occurrence: Vec<usize>,
+    table: Vec<Vec<(usize, usize)>>, // (depth, node) pairs, table[0] is the Euler tour
+    log: Vec<usize>,
+}
+
+impl LcaSparseTable {
+    /// Builds the structure for a rooted tree given as an adjacency list,
+    /// rooted at `root`.
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        let mut euler = Vec::new(); // (depth, node), one entry per Euler-tour step
+        let mut first_occurrence = vec![usize::MAX; n];
+        let mut depth = vec![0usize; n];
+
+        // Iterative Euler tour: revisit `u` after returning from each child,
+        // so every edge is crossed twice (matching the recursive definition).
+        let mut visit_stack: Vec<(usize, usize, usize)> = vec![(root, usize::MAX, 0)]; // (node, parent, child_index)
+        depth[root] = 0;
+        loop {
+            let (u, parent, idx) = *visit_stack.last().unwrap();
+            if idx == 0 && first_occurrence[u] == usize::MAX {
+                first_occurrence[u] = euler.len();
+            }
+            if idx == 0 {
+                euler.push((depth[u], u));
+            }
+            let children: Vec<usize> = adj[u].iter().cloned().filter(|&v| v != parent).collect();
+            if idx < children.len() {
+                let child = children[idx];
+                depth[child] = depth[u] + 1;
+                visit_stack.last_mut().unwrap().2 += 1;
+                visit_stack.push((child, u, 0));
+            } else {
+                visit_stack.pop();
+                if let Some(top) = visit_stack.last() {
+                    euler.push((depth[top.0], top.0));
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let m = euler.len();
+        let mut log = vec![0usize; m + 1];
+        for i in 2..=m {
+            log[i] = log[i / 2] + 1;
+        }
+
+        let k = log[m] + 1;
+        let mut table = vec![euler.clone(); k];
+        for level in 1..k {
+            let half = 1 << (level - 1);
+            for i in 0..=(m - (1 << level)) {
+                table[level][i] = table[level - 1][i].min(table[level - 1][i + half]);
+            }
+        }
+
+        LcaSparseTable { first_occurrence, table, log }
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`.
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let mut l = self.first_occurrence[u];
+        let mut r = self.first_occurrence[v];
+        if l > r {
+            core::mem::swap(&mut l, &mut r);
+        }
+        let level = self.log[r - l + 1];
+        let half = 1usize << level;
+        self.table[level][l].min(self.table[level][r + 1 - half]).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tree:        0
+    //            / | \
+    //           1  2  3
+    //          /|     |
+    //         4 5      6
+    fn sample_tree() -> Vec<Vec<usize>> {
+        vec![
+            vec![1, 2, 3],
+            vec![0, 4, 5],
+            vec![0],
+            vec![0, 6],
+            vec![1],
+            vec![1],
+            vec![3],
+        ]
+    }
+
+    #[test]
+    fn finds_lca_of_siblings() {
+        let lca = LcaSparseTable::new(&sample_tree(), 0);
+        assert_eq!(lca.lca(4, 5), 1);
+    }
+
+    #[test]
+    fn finds_lca_across_distant_subtrees() {
+        let lca = LcaSparseTable::new(&sample_tree(), 0);
+        assert_eq!(lca.lca(4, 6), 0);
+        assert_eq!(lca.lca(5, 2), 0);
+    }
+
+    #[test]
+    fn lca_of_a_vertex_with_itself_is_itself() {
+        let lca = LcaSparseTable::new(&sample_tree(), 0);
+        assert_eq!(lca.lca(6, 6), 6);
+    }
+
+    #[test]
+    fn matches_brute_force_depth_walk_on_every_pair() {
+        let adj = sample_tree();
+        let n = adj.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![0usize; n];
+        let mut stack = vec![0usize];
+        let mut visited = vec![false; n];
+        visited[0] = true;
+        while let Some(u) = stack.pop() {
+            for &v in &adj[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    parent[v] = u;
+                    depth[v] = depth[u] + 1;
+                    stack.push(v);
+                }
+            }
+        }
+        let brute_force_lca = |mut u: usize, mut v: usize| {
+            while depth[u] > depth[v] {
+                u = parent[u];
+            }
+            while depth[v] > depth[u] {
+                v = parent[v];
+            }
+            while u != v {
+                u = parent[u];
+                v = parent[v];
+            }
+            u
+        };
+
+        let lca = LcaSparseTable::new(&adj, 0);
+        for u in 0..n {
+            for v in 0..n {
+                assert_eq!(lca.lca(u, v), brute_force_lca(u, v), "u={u} v={v}");
+            }
+        }
+    }
+}
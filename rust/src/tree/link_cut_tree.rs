@@ -0,0 +1,241 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Link-cut tree: represents a dynamic forest as a collection of
+/// splay-tree "preferred paths", supporting link/cut/connected in
+/// amortized O(log n) each. Unlike a static union-find, edges can be
+/// removed as well as added.
+pub struct LinkCutTree {
+    nodes: Vec<Node>,
+}
+
+struct Node {
+    parent: usize,
+    children: [usize; 2],
+    flipped: bool,
+}
+
+const NIL: usize = usize::MAX;
+
+impl LinkCutTree {
+    pub fn new(n: usize) -> Self {
+        LinkCutTree {
+            nodes: (0..n).map(|_| Node { parent: NIL, children: [NIL, NIL], flipped: false }).collect(),
+        }
+    }
+
+    fn is_root(&self, x: usize) -> bool {
+        let p = self.nodes[x].parent;
+        p == NIL || (self.nodes[p].children[0] != x && self.nodes[p].children[1] != x)
+    }
+
+    fn push_down(&mut self, x: usize) {
+        if self.nodes[x].flipped {
+            self.nodes[x].children.swap(0, 1);
+            for i in 0..2 {
+                let c = self.nodes[x].children[i];
+                if c != NIL {
+                    self.nodes[c].flipped = !self.nodes[c].flipped;
+                }
+            }
+            self.nodes[x].flipped = false;
+        }
+    }
+
+    fn side(&self, x: usize) -> usize {
+        if self.nodes[self.nodes[x].parent].children[0] == x { 0 } else { 1 }
+    }
+
+    fn attach(&mut self, parent: usize, child: usize, side: usize) {
+        self.nodes[parent].children[side] = child;
+        if child != NIL {
+            self.nodes[child].parent = parent;
+        }
+    }
+
+    fn rotate(&mut self, x: usize) {
+        let p = self.nodes[x].parent;
+        let g = self.nodes[p].parent;
+        let side = self.side(x);
+        let was_root = self.is_root(p);
+
+        self.attach(p, self.nodes[x].children[1 - side], side);
+        self.attach(x, p, 1 - side);
+        self.nodes[x].parent = g;
+        if !was_root {
+            let g_side = if self.nodes[g].children[0] == p { 0 } else { 1 };
+            self.nodes[g].children[g_side] = x;
+        }
+    }
+
+    /// Pushes down lazy flips from the path root to `x`, then splays `x`
+    /// to the root of its splay tree.
+    fn splay(&mut self, x: usize) {
+        let mut path = vec![x];
+        let mut cur = x;
+        while !self.is_root(cur) {
+            cur = self.nodes[cur].parent;
+            path.push(cur);
+        }
+        for &n in path.iter().rev() {
+            self.push_down(n);
+        }
+        while !self.is_root(x) {
+            let p = self.nodes[x].parent;
+            if !self.is_root(p) {
+                if self.side(x) == self.side(p) {
+                    self.rotate(p);
+                } else {
+                    self.rotate(x);
+                }
+            }
+            self.rotate(x);
+        }
+    }
+
+    /// Makes the path from the preferred-path root to `x` a single
+    /// splay tree, with `x` at its root. The classic link-cut "access".
+    fn access(&mut self, x: usize) {
+        self.splay(x);
+        self.nodes[x].children[1] = NIL;
+        let mut cur = x;
+        loop {
+            let p = self.nodes[cur].parent;
+            if p == NIL {
+                break;
+            }
+            self.splay(p);
+            self.nodes[p].children[1] = cur;
+            self.nodes[cur].parent = p;
+            self.splay(x);
+            cur = x;
+            if self.nodes[x].parent == NIL {
+                break;
+            }
+        }
+    }
+
+    /// Makes `x` the root of its represented tree.
+    fn make_root(&mut self, x: usize) {
+        self.access(x);
+        self.nodes[x].flipped = !self.nodes[x].flipped;
+    }
+
+    /// Finds the root of the represented tree containing `x`.
+    pub fn find_root(&mut self, x: usize) -> usize {
+        self.access(x);
+        let mut cur = x;
+        loop {
+            self.push_down(cur);
+            if self.nodes[cur].children[0] == NIL {
+                break;
+            }
+            cur = self.nodes[cur].children[0];
+        }
+        self.splay(cur);
+        cur
+    }
+
+    /// Returns whether `u` and `v` are in the same tree.
+    pub fn connected(&mut self, u: usize, v: usize) -> bool {
+        if u == v {
+            return true;
+        }
+        self.find_root(u) == self.find_root(v)
+    }
+
+    /// Links `u` and `v` (assumed to be in different trees).
+    pub fn link(&mut self, u: usize, v: usize) {
+        self.make_root(u);
+        self.nodes[u].parent = v;
+    }
+
+    /// Cuts the edge between `u` and `v` (assumed to exist).
+    pub fn cut(&mut self, u: usize, v: usize) {
+        self.make_root(u);
+        self.access(v);
+        // After access, `u` is the left child of `v` if the edge exists.
+        if self.nodes[v].children[0] == u {
+            self.nodes[v].children[0] = NIL;
+            self.nodes[u].parent = NIL;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linking_connects_and_cutting_disconnects() {
+        let mut lct = LinkCutTree::new(6);
+        lct.link(0, 1);
+        lct.link(1, 2);
+        lct.link(3, 4);
+
+        assert!(lct.connected(0, 2));
+        assert!(!lct.connected(0, 3));
+
+        lct.link(2, 3);
+        assert!(lct.connected(0, 4));
+
+        lct.cut(1, 2);
+        assert!(!lct.connected(0, 4));
+        assert!(lct.connected(2, 4));
+    }
+
+    #[test]
+    fn a_single_node_is_connected_to_itself() {
+        let mut lct = LinkCutTree::new(3);
+        assert!(lct.connected(0, 0));
+        assert!(!lct.connected(0, 1));
+    }
+
+    #[test]
+    fn repeated_link_cut_matches_a_brute_force_union_find_oracle() {
+        // Build a random forest via union-find-style operations and check
+        // `connected` against the brute-force transitive closure at each step.
+        let n = 8;
+        let mut lct = LinkCutTree::new(n);
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let ops: [(usize, usize, bool); 7] =
+            [(0, 1, true), (1, 2, true), (3, 4, true), (4, 5, true), (2, 3, true), (1, 2, false), (6, 7, true)];
+
+        for &(u, v, is_link) in &ops {
+            if is_link {
+                lct.link(u, v);
+                edges.push((u, v));
+            } else {
+                lct.cut(u, v);
+                edges.retain(|&(a, b)| !((a == u && b == v) || (a == v && b == u)));
+            }
+
+            for a in 0..n {
+                for b in 0..n {
+                    assert_eq!(lct.connected(a, b), brute_force_connected(n, &edges, a, b), "a={a} b={b}");
+                }
+            }
+        }
+    }
+
+    fn brute_force_connected(n: usize, edges: &[(usize, usize)], start: usize, target: usize) -> bool {
+        let mut visited = vec![false; n];
+        let mut stack = vec![start];
+        visited[start] = true;
+        while let Some(u) = stack.pop() {
+            if u == target {
+                return true;
+            }
+            for &(a, b) in edges {
+                let v = if a == u { Some(b) } else if b == u { Some(a) } else { None };
+                if let Some(v) = v {
+                    if !visited[v] {
+                        visited[v] = true;
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+        start == target
+    }
+}
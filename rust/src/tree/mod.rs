@@ -0,0 +1,11 @@
+//! Tree-specific algorithms (diameter, center, centroid, and friends).
+
+pub mod ahu_tree_hash;
+pub mod binary_tree;
+pub mod euler_tour_subtree;
+pub mod euler_tour_tree;
+pub mod heavy_light;
+pub mod lca_sparse_table;
+pub mod link_cut_tree;
+pub mod tree_multiset_hash;
+pub mod utilities;
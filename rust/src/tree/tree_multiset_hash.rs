@@ -0,0 +1,134 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+const PRIME_MODULUS: u128 = 2_147_483_647; // Mersenne prime 2^31 - 1, keeps arithmetic in u64/u128 range
+
+/// Randomized hashing for multisets and rooted trees, built from a prime
+/// field: each distinct element/child-hash maps to a random residue, and a
+/// multiset's hash is the *product* of its members' residues mod a large
+/// prime. Two multisets collide with negligible probability unless they
+/// are genuinely equal, and the technique composes naturally for trees:
+/// `hash(subtree) = f(own value, multiset-hash of children's hashes)`.
+///
+/// Residues are memoized per instance (rather than behind a global), so a
+/// fresh [`MultisetHasher`] starts its own pseudo-random assignment from
+/// the same seed and two instances never interfere with each other.
+pub struct MultisetHasher {
+    assigned: BTreeMap<u64, u128>,
+    seed: u64,
+}
+
+impl MultisetHasher {
+    pub fn new() -> Self {
+        MultisetHasher { assigned: BTreeMap::new(), seed: 0x9E37_79B9_7F4A_7C15 }
+    }
+
+    /// Deterministically maps an arbitrary key to a pseudo-random nonzero
+    /// residue mod `PRIME_MODULUS`, memoized so repeated keys get the same
+    /// residue within one instance (stands in for a true random oracle).
+    fn residue_for(&mut self, key: u64) -> u128 {
+        if let Some(&r) = self.assigned.get(&key) {
+            return r;
+        }
+        // splitmix64-style scramble, seeded by the key and the instance seed.
+        let mut x = key.wrapping_add(self.seed);
+        x ^= x >> 30;
+        x = x.wrapping_mul(0xBF58476D1CE4E5B9);
+        x ^= x >> 27;
+        x = x.wrapping_mul(0x94D049BB133111EB);
+        x ^= x >> 31;
+        let r = 1 + (x as u128 % (PRIME_MODULUS - 1));
+        self.assigned.insert(key, r);
+        r
+    }
+
+    /// Hash of a multiset of `u64` elements: the product of each element's
+    /// residue, mod `PRIME_MODULUS`. Order-independent, and equal multisets
+    /// always produce equal hashes.
+    pub fn multiset_hash(&mut self, elements: &[u64]) -> u128 {
+        let mut h: u128 = 1;
+        for &e in elements {
+            h = (h * self.residue_for(e)) % PRIME_MODULUS;
+        }
+        h
+    }
+
+    /// Hashes a rooted tree bottom-up: each node's hash folds its own label
+    /// with the multiset-hash of its children's hashes, so isomorphic
+    /// subtrees (same label, same multiset of child hashes) collide.
+    pub fn tree_hash(&mut self, adj: &[Vec<usize>], labels: &[u64], root: usize) -> u128 {
+        let n = adj.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut order = Vec::with_capacity(n);
+        let mut stack = vec![(root, usize::MAX)];
+        while let Some((u, p)) = stack.pop() {
+            parent[u] = p;
+            order.push(u);
+            for &v in &adj[u] {
+                if v != p {
+                    stack.push((v, u));
+                }
+            }
+        }
+
+        let mut hash = vec![0u128; n];
+        for &u in order.iter().rev() {
+            let child_hashes: Vec<u64> = adj[u]
+                .iter()
+                .filter(|&&v| v != parent[u])
+                .map(|&v| (hash[v] % (u64::MAX as u128)) as u64)
+                .collect();
+            let children_part = self.multiset_hash(&child_hashes);
+            hash[u] = (self.residue_for(labels[u]) * (1 + children_part)) % PRIME_MODULUS;
+        }
+        hash[root]
+    }
+}
+
+impl Default for MultisetHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_multisets_hash_equal_regardless_of_order() {
+        let mut hasher = MultisetHasher::new();
+        let a = [3u64, 1, 4, 1, 5];
+        let b = [1u64, 5, 4, 1, 3];
+        assert_eq!(hasher.multiset_hash(&a), hasher.multiset_hash(&b));
+    }
+
+    #[test]
+    fn different_multisets_hash_differently() {
+        let mut hasher = MultisetHasher::new();
+        let a = [3u64, 1, 4, 1, 5];
+        let c = [3u64, 1, 4, 1, 6];
+        assert_ne!(hasher.multiset_hash(&a), hasher.multiset_hash(&c));
+    }
+
+    #[test]
+    fn isomorphic_trees_with_differently_ordered_children_hash_equal() {
+        let mut hasher = MultisetHasher::new();
+        let adj_a = vec![vec![1, 2], vec![0], vec![0, 3], vec![2]];
+        let labels_a = vec![0u64, 0, 0, 0];
+        let adj_b = vec![vec![1, 2], vec![0, 3], vec![0], vec![1]];
+        let labels_b = vec![0u64, 0, 0, 0];
+
+        assert_eq!(hasher.tree_hash(&adj_a, &labels_a, 0), hasher.tree_hash(&adj_b, &labels_b, 0));
+    }
+
+    #[test]
+    fn trees_with_different_labels_hash_differently() {
+        let mut hasher = MultisetHasher::new();
+        let adj = vec![vec![1, 2], vec![0], vec![0]];
+        let labels_same = vec![0u64, 0, 0];
+        let labels_different = vec![0u64, 0, 1];
+        assert_ne!(hasher.tree_hash(&adj, &labels_same, 0), hasher.tree_hash(&adj, &labels_different, 0));
+    }
+}
@@ -0,0 +1,102 @@
+// Tree utilities: diameter (longest path), center (the one or two
+// midpoint vertices of the diameter, minimizing eccentricity), and
+// centroid (the vertex whose removal leaves subtrees of at most n/2).
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// BFS from `start`, returning distances and the farthest vertex found.
+fn bfs_farthest(adj: &[Vec<usize>], start: usize) -> (Vec<i64>, usize) {
+    let n = adj.len();
+    let mut dist = vec![-1i64; n];
+    dist[start] = 0;
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+    let mut farthest = start;
+    while let Some(u) = queue.pop_front() {
+        if dist[u] > dist[farthest] {
+            farthest = u;
+        }
+        for &v in &adj[u] {
+            if dist[v] == -1 {
+                dist[v] = dist[u] + 1;
+                queue.push_back(v);
+            }
+        }
+    }
+    (dist, farthest)
+}
+
+/// Returns `(diameter_length, path)`, the number of edges on the longest
+/// path and the path itself as a vertex sequence.
+pub fn diameter(adj: &[Vec<usize>]) -> (i64, Vec<usize>) {
+    let (_, a) = bfs_farthest(adj, 0);
+    let (dist_from_a, b) = bfs_farthest(adj, a);
+
+    // Reconstruct the path a -> b by walking from b back toward a,
+    // following any neighbor whose distance-from-a is one less.
+    let mut path = vec![b];
+    let mut cur = b;
+    while cur != a {
+        for &v in &adj[cur] {
+            if dist_from_a[v] == dist_from_a[cur] - 1 {
+                path.push(v);
+                cur = v;
+                break;
+            }
+        }
+    }
+    (dist_from_a[b], path)
+}
+
+/// The center of the tree: one vertex if the diameter has even length,
+/// two adjacent vertices if odd.
+pub fn center(adj: &[Vec<usize>]) -> Vec<usize> {
+    let (len, path) = diameter(adj);
+    let mid = (len / 2) as usize;
+    if len % 2 == 0 {
+        vec![path[mid]]
+    } else {
+        vec![path[mid], path[mid + 1]]
+    }
+}
+
+/// The centroid(s) of the tree: the vertex/vertices whose removal leaves
+/// every remaining component with at most `n / 2` vertices.
+pub fn centroids(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    let mut size = vec![1usize; n];
+    let mut order = Vec::with_capacity(n);
+    let mut parent = vec![usize::MAX; n];
+
+    let mut stack = vec![(0usize, usize::MAX)];
+    while let Some((u, p)) = stack.pop() {
+        parent[u] = p;
+        order.push(u);
+        for &v in &adj[u] {
+            if v != p {
+                stack.push((v, u));
+            }
+        }
+    }
+    for &u in order.iter().rev() {
+        if parent[u] != usize::MAX {
+            size[parent[u]] += size[u];
+        }
+    }
+
+    let mut result = Vec::new();
+    for v in 0..n {
+        let mut max_component = n - size[v];
+        for &u in &adj[v] {
+            if u != parent[v] {
+                max_component = max_component.max(size[u]);
+            }
+        }
+        if max_component <= n / 2 {
+            result.push(v);
+        }
+    }
+    result
+}
@@ -0,0 +1,74 @@
+//! `wasm-bindgen` bindings exposing a `Graph` type plus Dijkstra and
+//! Floyd-Warshall to JavaScript, so an in-browser visualizer can drive the
+//! same algorithms the native crate uses. Only compiled for `wasm32`
+//! targets with the `wasm` feature enabled — native builds, tests, and
+//! benches never see this module.
+//!
+//! Vertex ids and weights cross the JS boundary as `u32`/`i32` rather than
+//! `usize`/`i64`: `wasm-bindgen` maps those straight onto JS `number`s, while
+//! 64-bit integers require `BigInt` on the JS side, which visualizer code
+//! shouldn't have to deal with for graphs small enough to render.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::graph::dijkstra::dijkstra_with_path;
+use crate::graph::floyd_warshall::floyd_warshall;
+use crate::graph::types::{AdjList, Edge, WeightedEdge};
+
+/// A graph built incrementally from JavaScript, then handed to whichever
+/// shortest-path algorithm the visualizer wants to run.
+#[wasm_bindgen]
+pub struct Graph {
+    vertices: usize,
+    edges: Vec<WeightedEdge>,
+}
+
+#[wasm_bindgen]
+impl Graph {
+    #[wasm_bindgen(constructor)]
+    pub fn new(vertices: u32) -> Self {
+        Graph { vertices: vertices as usize, edges: Vec::new() }
+    }
+
+    pub fn add_edge(&mut self, from: u32, to: u32, weight: i32) {
+        self.edges.push(WeightedEdge { from: from as usize, to: to as usize, weight: weight as i64 });
+    }
+
+    fn adjacency_list(&self) -> AdjList {
+        let mut adj: AdjList = vec![Vec::new(); self.vertices];
+        for edge in &self.edges {
+            adj[edge.from].push(Edge { to: edge.to, weight: edge.weight });
+        }
+        adj
+    }
+
+    /// Shortest distance and path from `start` to `end`, as JSON
+    /// (`{"distance": ..., "path": [...]}`), or `"null"` if unreachable.
+    pub fn dijkstra_path_json(&self, start: u32, end: u32) -> String {
+        let result = dijkstra_with_path(&self.adjacency_list(), start as usize, end as usize)
+            .map(|(distance, path)| PathResult { distance, path });
+        serde_json::to_string(&result).unwrap_or_else(|_| "null".into())
+    }
+
+    /// All-pairs distances as a JSON 2D array, `dist[u][v]`, with `null`
+    /// in place of `f64::INFINITY` for unreachable pairs (JSON has no
+    /// infinity literal).
+    pub fn floyd_warshall_json(&self) -> String {
+        let dist = floyd_warshall(self.vertices, &self.edges);
+        let json_friendly: Vec<Vec<Option<f64>>> = dist
+            .into_iter()
+            .map(|row| row.into_iter().map(|d| if d.is_infinite() { None } else { Some(d) }).collect())
+            .collect();
+        serde_json::to_string(&json_friendly).unwrap_or_else(|_| "null".into())
+    }
+}
+
+#[derive(Serialize)]
+struct PathResult {
+    distance: i64,
+    path: Vec<usize>,
+}
@@ -0,0 +1,62 @@
+//! Property-based tests for [`dsa::collections::btree`]: random
+//! insert/remove/get/range sequences, checked against a naive sorted
+//! `Vec` map, across a few different minimum degrees.
+
+use proptest::prelude::*;
+
+use dsa::collections::btree::BTree;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Insert(i64, i64),
+    Remove(i64),
+    Get(i64),
+    Range(i64, i64),
+}
+
+fn ops() -> impl Strategy<Value = (usize, Vec<Op>)> {
+    let op = prop_oneof![
+        (-20i64..20, -1000i64..1000).prop_map(|(k, v)| Op::Insert(k, v)),
+        (-20i64..20).prop_map(Op::Remove),
+        (-20i64..20).prop_map(Op::Get),
+        (-20i64..20, -20i64..20).prop_map(|(a, b)| Op::Range(a.min(b), a.max(b))),
+    ];
+    (2usize..5, proptest::collection::vec(op, 0..60))
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_sorted_map((min_degree, ops) in ops()) {
+        let mut naive: Vec<(i64, i64)> = Vec::new();
+        let mut tree = BTree::new(min_degree);
+
+        for op in ops {
+            match op {
+                Op::Insert(k, v) => {
+                    let old = naive.iter().position(|&(key, _)| key == k).map(|pos| naive[pos].1);
+                    if let Some(pos) = naive.iter().position(|&(key, _)| key == k) {
+                        naive[pos].1 = v;
+                    } else {
+                        naive.push((k, v));
+                        naive.sort_unstable_by_key(|&(key, _)| key);
+                    }
+                    prop_assert_eq!(tree.insert(k, v), old);
+                }
+                Op::Remove(k) => {
+                    let old = naive.iter().position(|&(key, _)| key == k).map(|pos| naive.remove(pos).1);
+                    prop_assert_eq!(tree.remove(&k), old);
+                }
+                Op::Get(k) => {
+                    let expected = naive.iter().find(|&&(key, _)| key == k).map(|&(_, v)| v);
+                    prop_assert_eq!(tree.get(&k), expected.as_ref());
+                }
+                Op::Range(lo, hi) => {
+                    let expected: Vec<(&i64, &i64)> =
+                        naive.iter().filter(|&&(k, _)| k >= lo && k < hi).map(|(k, v)| (k, v)).collect();
+                    prop_assert_eq!(tree.range(&lo, &hi), expected);
+                }
+            }
+            prop_assert_eq!(tree.len(), naive.len());
+        }
+    }
+}
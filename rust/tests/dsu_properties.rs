@@ -0,0 +1,66 @@
+//! Property-based tests for [`dsa::collections::dsu`]: random union/find
+//! sequences, checked against a naive `Vec`-of-sets reference.
+
+use proptest::prelude::*;
+
+use dsa::collections::dsu::Dsu;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Union(usize, usize),
+    Connected(usize, usize),
+    SetSize(usize),
+    ComponentCount,
+}
+
+fn ops(n: usize) -> impl Strategy<Value = Vec<Op>> {
+    proptest::collection::vec(
+        prop_oneof![
+            (0..n, 0..n).prop_map(|(a, b)| Op::Union(a, b)),
+            (0..n, 0..n).prop_map(|(a, b)| Op::Connected(a, b)),
+            (0..n).prop_map(Op::SetSize),
+            Just(Op::ComponentCount),
+        ],
+        0..60,
+    )
+}
+
+fn naive_root(naive: &[usize], mut x: usize) -> usize {
+    while naive[x] != x {
+        x = naive[x];
+    }
+    x
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_reference((n, ops) in (1usize..12).prop_flat_map(|n| (Just(n), ops(n)))) {
+        let mut dsu = Dsu::new(n);
+        let mut naive: Vec<usize> = (0..n).collect();
+
+        for op in ops {
+            match op {
+                Op::Union(a, b) => {
+                    let (ra, rb) = (naive_root(&naive, a), naive_root(&naive, b));
+                    if ra != rb {
+                        naive[ra] = rb;
+                    }
+                    dsu.union(a, b);
+                }
+                Op::Connected(a, b) => {
+                    let expected = naive_root(&naive, a) == naive_root(&naive, b);
+                    prop_assert_eq!(dsu.connected(a, b), expected);
+                }
+                Op::SetSize(x) => {
+                    let root = naive_root(&naive, x);
+                    let expected = (0..n).filter(|&i| naive_root(&naive, i) == root).count();
+                    prop_assert_eq!(dsu.set_size(x), expected);
+                }
+                Op::ComponentCount => {
+                    let expected = (0..n).filter(|&i| naive_root(&naive, i) == i).count();
+                    prop_assert_eq!(dsu.component_count(), expected);
+                }
+            }
+        }
+    }
+}
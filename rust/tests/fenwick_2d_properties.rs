@@ -0,0 +1,53 @@
+//! Property-based tests for [`dsa::collections::fenwick_2d`]: random
+//! point updates and rectangle-sum queries on random matrices, checked
+//! against naive recomputation over a `Vec<Vec<i64>>`.
+
+use proptest::prelude::*;
+
+use dsa::collections::fenwick_2d::Fenwick2D;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add(usize, usize, i64),
+    RectSum(usize, usize, usize, usize),
+}
+
+/// A random `rows x cols` matrix (each dimension `1..8`), plus a sequence
+/// of updates/queries whose indices are always valid for that shape.
+fn matrix_and_ops() -> impl Strategy<Value = (Vec<Vec<i64>>, Vec<Op>)> {
+    (1usize..8, 1usize..8).prop_flat_map(|(rows, cols)| {
+        let matrix = proptest::collection::vec(proptest::collection::vec(-50i64..50, cols), rows);
+        let op = prop_oneof![
+            (0..rows, 0..cols, -50i64..50).prop_map(|(r, c, v)| Op::Add(r, c, v)),
+            (0..rows, 0..rows, 0..cols, 0..cols).prop_map(|(a, b, c, d)| Op::RectSum(a.min(b), a.max(b) + 1, c.min(d), c.max(d) + 1)),
+        ];
+        (matrix, proptest::collection::vec(op, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_matrix((matrix, ops) in matrix_and_ops()) {
+        let (rows, cols) = (matrix.len(), matrix[0].len());
+        let mut naive = matrix.clone();
+        let mut tree = Fenwick2D::new(rows, cols);
+        for (r, row) in matrix.iter().enumerate() {
+            for (c, &v) in row.iter().enumerate() {
+                tree.add(r, c, v);
+            }
+        }
+
+        for op in ops {
+            match op {
+                Op::Add(r, c, v) => {
+                    naive[r][c] += v;
+                    tree.add(r, c, v);
+                }
+                Op::RectSum(r1, r2, c1, c2) => {
+                    let expected: i64 = naive[r1..r2].iter().flat_map(|row| &row[c1..c2]).sum();
+                    prop_assert_eq!(tree.rect_sum(r1, r2, c1, c2), expected);
+                }
+            }
+        }
+    }
+}
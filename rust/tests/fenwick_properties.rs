@@ -0,0 +1,57 @@
+//! Property-based tests for [`dsa::collections::fenwick`]: random
+//! sequences of point updates (always non-negative, the precondition
+//! `lower_bound` relies on) and range/lower-bound queries, checked
+//! against a naive `Vec`.
+
+use proptest::prelude::*;
+
+use dsa::collections::fenwick::Fenwick;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add(usize, i64),
+    RangeSum(usize, usize),
+    LowerBound(i64),
+}
+
+fn values_and_ops() -> impl Strategy<Value = (Vec<i64>, Vec<Op>)> {
+    proptest::collection::vec(0i64..20, 1..16).prop_flat_map(|values| {
+        let n = values.len();
+        let total: i64 = values.iter().sum();
+        let op = prop_oneof![
+            (0..n, 0i64..20).prop_map(|(i, v)| Op::Add(i, v)),
+            (0..n, 0..n).prop_map(|(a, b)| Op::RangeSum(a.min(b), a.max(b) + 1)),
+            (0..=total + 20).prop_map(Op::LowerBound),
+        ];
+        (Just(values), proptest::collection::vec(op, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_vector((values, ops) in values_and_ops()) {
+        let mut naive = values.clone();
+        let mut fenwick = Fenwick::build(&values);
+
+        for op in ops {
+            match op {
+                Op::Add(i, v) => {
+                    naive[i] += v;
+                    fenwick.add(i, v);
+                }
+                Op::RangeSum(l, r) => {
+                    let expected: i64 = naive[l..r].iter().sum();
+                    prop_assert_eq!(fenwick.range_sum(l, r), expected);
+                }
+                Op::LowerBound(target) => {
+                    let pos = fenwick.lower_bound(target);
+                    let prefix: i64 = naive[..pos].iter().sum();
+                    prop_assert!(prefix <= target);
+                    if pos < naive.len() {
+                        prop_assert!(prefix + naive[pos] > target);
+                    }
+                }
+            }
+        }
+    }
+}
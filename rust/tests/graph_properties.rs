@@ -0,0 +1,72 @@
+//! Property-based tests for the graph shortest-path algorithms: random
+//! weighted graphs generated by proptest, checked against invariants that
+//! hand-picked unit tests tend to miss edge cases for.
+
+use proptest::prelude::*;
+
+use dsa::graph::dijkstra::{dijkstra, dijkstra_with_path};
+use dsa::graph::floyd_warshall::floyd_warshall;
+use dsa::graph::types::{AdjList, Edge, WeightedEdge};
+
+/// Generates a random directed graph on `n` vertices with non-negative
+/// integer weights, as both an adjacency list and an edge list.
+fn graph_strategy(n: usize) -> impl Strategy<Value = (AdjList, Vec<WeightedEdge>)> {
+    let edge = (0..n, 0..n, 0i64..20).prop_filter("no self loops", |&(u, v, _)| u != v);
+    proptest::collection::vec(edge, 0..(n * n).min(20)).prop_map(move |raw_edges| {
+        let mut adj = vec![Vec::new(); n];
+        let mut edges = Vec::new();
+        for (u, v, w) in raw_edges {
+            adj[u].push(Edge { to: v, weight: w });
+            edges.push(WeightedEdge { from: u, to: v, weight: w });
+        }
+        (adj, edges)
+    })
+}
+
+fn sized_graph() -> impl Strategy<Value = (usize, AdjList, Vec<WeightedEdge>)> {
+    (2usize..8).prop_flat_map(|n| graph_strategy(n).prop_map(move |(adj, edges)| (n, adj, edges)))
+}
+
+proptest! {
+    /// Floyd-Warshall and Dijkstra must agree on every pairwise distance
+    /// whenever all weights are non-negative.
+    #[test]
+    fn floyd_warshall_matches_dijkstra((n, adj, edges) in sized_graph()) {
+        let fw = floyd_warshall(n, &edges);
+        for (src, fw_row) in fw.iter().enumerate().take(n) {
+            let dij = dijkstra(&adj, src);
+            for (dst, &fw_dist) in fw_row.iter().enumerate().take(n) {
+                let dij_dist = if dij[dst] == i64::MAX { f64::INFINITY } else { dij[dst] as f64 };
+                prop_assert_eq!(dij_dist, fw_dist);
+            }
+        }
+    }
+
+    /// A path returned by `dijkstra_with_path` must start at the source,
+    /// end at the destination, and its edge weights must sum to the
+    /// distance it reports.
+    #[test]
+    fn dijkstra_path_weight_matches_distance(
+        (n, adj, _edges) in sized_graph(),
+        src_raw in 0usize..8,
+        dst_raw in 0usize..8,
+    ) {
+        let src = src_raw % n;
+        let dst = dst_raw % n;
+        if let Some((dist, path)) = dijkstra_with_path(&adj, src, dst) {
+            prop_assert_eq!(path.first().copied(), Some(src));
+            prop_assert_eq!(path.last().copied(), Some(dst));
+
+            let mut sum = 0i64;
+            for pair in path.windows(2) {
+                // Take the cheapest parallel edge between these two
+                // vertices: dijkstra would have relaxed through whichever
+                // one was smallest, not necessarily the first in the list.
+                let weight = adj[pair[0]].iter().filter(|e| e.to == pair[1]).map(|e| e.weight).min();
+                prop_assert!(weight.is_some());
+                sum += weight.unwrap();
+            }
+            prop_assert_eq!(sum, dist);
+        }
+    }
+}
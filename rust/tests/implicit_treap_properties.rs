@@ -0,0 +1,72 @@
+//! Property-based tests for [`dsa::collections::implicit_treap`]: random
+//! insert/erase/reverse/sum sequences checked against a naive `Vec`.
+
+use proptest::prelude::*;
+
+use dsa::collections::implicit_treap::ImplicitTreap;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Insert(usize, i64),
+    Erase(usize),
+    Reverse(usize, usize),
+    Sum(usize, usize),
+    Get(usize),
+}
+
+fn ops() -> impl Strategy<Value = Vec<Op>> {
+    proptest::collection::vec(
+        prop_oneof![
+            (0usize..40, -50i64..50).prop_map(|(i, v)| Op::Insert(i, v)),
+            (0usize..40).prop_map(Op::Erase),
+            (0usize..40, 0usize..40).prop_map(|(a, b)| Op::Reverse(a.min(b), a.max(b))),
+            (0usize..40, 0usize..40).prop_map(|(a, b)| Op::Sum(a.min(b), a.max(b))),
+            (0usize..40).prop_map(Op::Get),
+        ],
+        0..60,
+    )
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_vector(ops in ops()) {
+        let mut naive: Vec<i64> = Vec::new();
+        let mut treap = ImplicitTreap::new();
+
+        for op in ops {
+            match op {
+                Op::Insert(i, v) => {
+                    let i = i.min(naive.len());
+                    naive.insert(i, v);
+                    treap.insert(i, v);
+                }
+                Op::Erase(i) => {
+                    if i < naive.len() {
+                        prop_assert_eq!(treap.erase(i), naive.remove(i));
+                    }
+                }
+                Op::Reverse(l, r) => {
+                    let r = r.min(naive.len());
+                    if l < r {
+                        naive[l..r].reverse();
+                        treap.reverse_range(l, r);
+                    }
+                }
+                Op::Sum(l, r) => {
+                    let r = r.min(naive.len());
+                    if l < r {
+                        let expected: i64 = naive[l..r].iter().sum();
+                        prop_assert_eq!(treap.range_sum(l, r), expected);
+                    }
+                }
+                Op::Get(i) => {
+                    if i < naive.len() {
+                        prop_assert_eq!(treap.get(i), naive[i]);
+                    }
+                }
+            }
+            prop_assert_eq!(treap.len(), naive.len());
+            prop_assert_eq!(treap.to_vec(), naive.clone());
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Property-based tests for [`dsa::collections::merge_sort_tree`]: random
+//! static arrays checked against naive sort-and-scan for both
+//! `count_le` and `kth_smallest` over random ranges.
+
+use proptest::prelude::*;
+
+use dsa::collections::merge_sort_tree::MergeSortTree;
+
+#[derive(Debug, Clone, Copy)]
+enum Query {
+    CountLe(usize, usize, i64),
+    KthSmallest(usize, usize, usize),
+}
+
+/// A random array of length `1..16`, plus a sequence of range queries
+/// (and, for `KthSmallest`, a rank) always valid for that array.
+fn values_and_queries() -> impl Strategy<Value = (Vec<i64>, Vec<Query>)> {
+    proptest::collection::vec(-50i64..50, 1..16).prop_flat_map(|values| {
+        let n = values.len();
+        let query = (0..n, 0..n).prop_flat_map(move |(a, b)| {
+            let (l, r) = (a.min(b), a.max(b) + 1);
+            prop_oneof![
+                (-50i64..50).prop_map(move |x| Query::CountLe(l, r, x)),
+                (0..r - l).prop_map(move |k| Query::KthSmallest(l, r, k)),
+            ]
+        });
+        (Just(values), proptest::collection::vec(query, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn queries_match_naive_sort_and_scan((values, queries) in values_and_queries()) {
+        let tree = MergeSortTree::build(&values);
+
+        for query in queries {
+            match query {
+                Query::CountLe(l, r, x) => {
+                    let expected = values[l..r].iter().filter(|&&v| v <= x).count();
+                    prop_assert_eq!(tree.count_le(l, r, x), expected);
+                }
+                Query::KthSmallest(l, r, k) => {
+                    let mut sorted = values[l..r].to_vec();
+                    sorted.sort_unstable();
+                    prop_assert_eq!(tree.kth_smallest(l, r, k), sorted[k]);
+                }
+            }
+        }
+    }
+}
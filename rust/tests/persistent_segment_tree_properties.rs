@@ -0,0 +1,35 @@
+//! Property-based tests for [`dsa::collections::persistent_segment_tree`]:
+//! random arrays and `(l, r, k)` queries, checked against brute-force
+//! sorting of the slice `values[l..=r]`.
+
+use proptest::prelude::*;
+
+use dsa::collections::persistent_segment_tree::build_prefix_roots;
+
+/// A random array of length `1..20`, plus a `(l, r, k)` query with `l <=
+/// r < len` and `k <= r - l` (so it always names a real element of
+/// `values[l..=r]`).
+fn values_and_query() -> impl Strategy<Value = (Vec<i64>, usize, usize, usize)> {
+    proptest::collection::vec(-20i64..20, 1..20).prop_flat_map(|values| {
+        let n = values.len();
+        (Just(values), 0..n, 0..n).prop_flat_map(|(values, a, b)| {
+            let (l, r) = (a.min(b), a.max(b));
+            (0..=(r - l)).prop_map(move |k| (values.clone(), l, r, k))
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn kth_smallest_matches_brute_force_sort((values, l, r, k) in values_and_query()) {
+        let (tree, roots, sorted_values) = build_prefix_roots(&values);
+        let rank = tree.kth_smallest(roots[l], roots[r + 1], k);
+        let actual = sorted_values[rank];
+
+        let mut slice = values[l..=r].to_vec();
+        slice.sort_unstable();
+        let expected = slice[k];
+
+        prop_assert_eq!(actual, expected);
+    }
+}
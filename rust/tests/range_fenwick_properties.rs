@@ -0,0 +1,47 @@
+//! Property-based tests for [`dsa::collections::range_fenwick`]: random
+//! range-add updates and prefix/range-sum queries, checked against a
+//! naive `Vec`.
+
+use proptest::prelude::*;
+
+use dsa::collections::range_fenwick::RangeFenwick;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    RangeAdd(usize, usize, i64),
+    RangeSum(usize, usize),
+}
+
+fn values_and_ops() -> impl Strategy<Value = (Vec<i64>, Vec<Op>)> {
+    proptest::collection::vec(-50i64..50, 1..16).prop_flat_map(|values| {
+        let n = values.len();
+        let op = prop_oneof![
+            (0..n, 0..n, -50i64..50).prop_map(|(a, b, delta)| Op::RangeAdd(a.min(b), a.max(b) + 1, delta)),
+            (0..n, 0..n).prop_map(|(a, b)| Op::RangeSum(a.min(b), a.max(b) + 1)),
+        ];
+        (Just(values), proptest::collection::vec(op, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_vector((values, ops) in values_and_ops()) {
+        let mut naive = values.clone();
+        let mut tree = RangeFenwick::build(&values);
+
+        for op in ops {
+            match op {
+                Op::RangeAdd(l, r, delta) => {
+                    for v in &mut naive[l..r] {
+                        *v += delta;
+                    }
+                    tree.range_add(l, r, delta);
+                }
+                Op::RangeSum(l, r) => {
+                    let expected: i64 = naive[l..r].iter().sum();
+                    prop_assert_eq!(tree.range_sum(l, r), expected);
+                }
+            }
+        }
+    }
+}
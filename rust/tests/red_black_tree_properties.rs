@@ -0,0 +1,66 @@
+//! Property-based tests for [`dsa::collections::red_black_tree`]: random
+//! sequences of insert/erase/contains/range, checked against a naive
+//! sorted `Vec` set.
+
+use proptest::prelude::*;
+
+use dsa::collections::red_black_tree::RedBlackTree;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Insert(i64),
+    Erase(i64),
+    Contains(i64),
+    Range(i64, i64),
+}
+
+fn ops() -> impl Strategy<Value = Vec<Op>> {
+    proptest::collection::vec(
+        prop_oneof![
+            (-20i64..20).prop_map(Op::Insert),
+            (-20i64..20).prop_map(Op::Erase),
+            (-20i64..20).prop_map(Op::Contains),
+            (-20i64..20, -20i64..20).prop_map(|(a, b)| Op::Range(a.min(b), a.max(b))),
+        ],
+        0..60,
+    )
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_sorted_set(ops in ops()) {
+        let mut naive: Vec<i64> = Vec::new();
+        let mut tree = RedBlackTree::new();
+
+        for op in ops {
+            match op {
+                Op::Insert(v) => {
+                    let was_new = !naive.contains(&v);
+                    if was_new {
+                        naive.push(v);
+                        naive.sort_unstable();
+                    }
+                    prop_assert_eq!(tree.insert(v), was_new);
+                }
+                Op::Erase(v) => {
+                    let erased_naive = if let Some(pos) = naive.iter().position(|&x| x == v) {
+                        naive.remove(pos);
+                        true
+                    } else {
+                        false
+                    };
+                    prop_assert_eq!(tree.erase(&v), erased_naive);
+                }
+                Op::Contains(v) => {
+                    prop_assert_eq!(tree.contains(&v), naive.contains(&v));
+                }
+                Op::Range(lo, hi) => {
+                    let expected: Vec<&i64> = naive.iter().filter(|&&x| x >= lo && x < hi).collect();
+                    prop_assert_eq!(tree.range(&lo, &hi), expected);
+                }
+            }
+            prop_assert_eq!(tree.len(), naive.len());
+            prop_assert_eq!(tree.iter().copied().collect::<Vec<i64>>(), naive.clone());
+        }
+    }
+}
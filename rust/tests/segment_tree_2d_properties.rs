@@ -0,0 +1,75 @@
+//! Property-based tests for [`dsa::collections::segment_tree_2d`]: random
+//! grids, point updates, and rectangle queries, checked against a naive
+//! 2D `Vec` that recomputes each query by folding over the rectangle.
+
+use proptest::prelude::*;
+
+use dsa::collections::segment_tree::{Max, Sum};
+use dsa::collections::segment_tree_2d::SegmentTree2D;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Update(usize, usize, i64),
+    Query(usize, usize, usize, usize),
+}
+
+/// A random `rows x cols` grid (each dimension `1..8`), plus a sequence of
+/// updates/queries whose indices are always valid for that grid.
+fn grid_and_ops() -> impl Strategy<Value = (Vec<Vec<i64>>, Vec<Op>)> {
+    (1usize..8, 1usize..8).prop_flat_map(|(rows, cols)| {
+        let grid = proptest::collection::vec(proptest::collection::vec(-20i64..20, cols), rows);
+        grid.prop_flat_map(move |grid| {
+            let op = prop_oneof![
+                (0..rows, 0..cols, -20i64..20).prop_map(|(r, c, v)| Op::Update(r, c, v)),
+                (0..rows, 0..rows, 0..cols, 0..cols).prop_map(|(ra, rb, ca, cb)| {
+                    Op::Query(ra.min(rb), ra.max(rb) + 1, ca.min(cb), ca.max(cb) + 1)
+                }),
+            ];
+            (Just(grid), proptest::collection::vec(op, 0..30))
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn sum_matches_naive_grid((grid, ops) in grid_and_ops()) {
+        let mut naive = grid.clone();
+        let mut tree = SegmentTree2D::build(
+            &grid.iter().map(|row| row.iter().map(|&v| Sum(v)).collect()).collect::<Vec<_>>(),
+        );
+
+        for op in ops {
+            match op {
+                Op::Update(r, c, v) => {
+                    naive[r][c] = v;
+                    tree.update(r, c, Sum(v));
+                }
+                Op::Query(r1, r2, c1, c2) => {
+                    let expected: i64 = naive[r1..r2].iter().flat_map(|row| &row[c1..c2]).sum();
+                    prop_assert_eq!(tree.query(r1, r2, c1, c2).0, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_matches_naive_grid((grid, ops) in grid_and_ops()) {
+        let mut naive = grid.clone();
+        let mut tree = SegmentTree2D::build(
+            &grid.iter().map(|row| row.iter().map(|&v| Max(v)).collect()).collect::<Vec<_>>(),
+        );
+
+        for op in ops {
+            match op {
+                Op::Update(r, c, v) => {
+                    naive[r][c] = v;
+                    tree.update(r, c, Max(v));
+                }
+                Op::Query(r1, r2, c1, c2) => {
+                    let expected = naive[r1..r2].iter().flat_map(|row| &row[c1..c2]).copied().max().unwrap();
+                    prop_assert_eq!(tree.query(r1, r2, c1, c2).0, expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Property-based tests for [`dsa::collections::segment_tree`]: random
+//! sequences of point updates and range queries, checked against a naive
+//! `Vec` that just recomputes each query by folding over the range.
+
+use proptest::prelude::*;
+
+use dsa::collections::segment_tree::{Min, SegmentTree, Sum};
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Update(usize, i64),
+    Query(usize, usize),
+}
+
+/// A random starting vector of length `1..16`, plus a sequence of
+/// updates/queries whose indices are always valid for that length.
+fn values_and_ops() -> impl Strategy<Value = (Vec<i64>, Vec<Op>)> {
+    proptest::collection::vec(-50i64..50, 1..16).prop_flat_map(|values| {
+        let n = values.len();
+        let op = prop_oneof![
+            (0..n, -50i64..50).prop_map(|(i, v)| Op::Update(i, v)),
+            (0..n, 0..n).prop_map(|(a, b)| Op::Query(a.min(b), a.max(b) + 1)),
+        ];
+        (Just(values), proptest::collection::vec(op, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn sum_matches_naive_vector((values, ops) in values_and_ops()) {
+        let mut naive = values.clone();
+        let mut tree = SegmentTree::build(&values.iter().map(|&v| Sum(v)).collect::<Vec<_>>());
+
+        for op in ops {
+            match op {
+                Op::Update(i, v) => {
+                    naive[i] = v;
+                    tree.update(i, Sum(v));
+                }
+                Op::Query(l, r) => {
+                    let expected: i64 = naive[l..r].iter().sum();
+                    prop_assert_eq!(tree.query(l, r).0, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn min_matches_naive_vector((values, ops) in values_and_ops()) {
+        let mut naive = values.clone();
+        let mut tree = SegmentTree::build(&values.iter().map(|&v| Min(v)).collect::<Vec<_>>());
+
+        for op in ops {
+            match op {
+                Op::Update(i, v) => {
+                    naive[i] = v;
+                    tree.update(i, Min(v));
+                }
+                Op::Query(l, r) => {
+                    let expected = naive[l..r].iter().copied().min().unwrap();
+                    prop_assert_eq!(tree.query(l, r).0, expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Property-based tests for [`dsa::collections::skip_list`]: random
+//! sequences of insert/erase/rank/select, checked against a naive sorted
+//! `Vec` multiset.
+
+use proptest::prelude::*;
+
+use dsa::collections::skip_list::SkipList;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Insert(i64),
+    Erase(i64),
+    Rank(i64),
+    Select(usize),
+}
+
+fn ops() -> impl Strategy<Value = Vec<Op>> {
+    proptest::collection::vec(
+        prop_oneof![
+            (-20i64..20).prop_map(Op::Insert),
+            (-20i64..20).prop_map(Op::Erase),
+            (-20i64..20).prop_map(Op::Rank),
+            (0usize..40).prop_map(Op::Select),
+        ],
+        0..60,
+    )
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_sorted_multiset(ops in ops()) {
+        let mut naive: Vec<i64> = Vec::new();
+        let mut list = SkipList::new();
+
+        for op in ops {
+            match op {
+                Op::Insert(v) => {
+                    list.insert(v);
+                    let pos = naive.partition_point(|&x| x < v);
+                    naive.insert(pos, v);
+                }
+                Op::Erase(v) => {
+                    let erased_naive = if let Some(pos) = naive.iter().position(|&x| x == v) {
+                        naive.remove(pos);
+                        true
+                    } else {
+                        false
+                    };
+                    prop_assert_eq!(list.erase(&v), erased_naive);
+                }
+                Op::Rank(v) => {
+                    let expected = naive.partition_point(|&x| x < v);
+                    prop_assert_eq!(list.rank(&v), expected);
+                }
+                Op::Select(k) => {
+                    prop_assert_eq!(list.select(k), naive.get(k));
+                }
+            }
+            prop_assert_eq!(list.len(), naive.len());
+            prop_assert_eq!(list.iter().copied().collect::<Vec<i64>>(), naive.clone());
+        }
+    }
+}
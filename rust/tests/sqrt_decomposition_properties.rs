@@ -0,0 +1,87 @@
+//! Property-based tests for [`dsa::collections::sqrt_decomposition`]:
+//! random update/query sequences against both structures, checked
+//! against a naive `Vec`.
+
+use proptest::prelude::*;
+
+use dsa::collections::segment_tree::Sum;
+use dsa::collections::sqrt_decomposition::{SqrtDecomposition, SqrtRangeAdd};
+
+#[derive(Debug, Clone, Copy)]
+enum PointOp {
+    Update(usize, i64),
+    Query(usize, usize),
+}
+
+fn values_and_point_ops() -> impl Strategy<Value = (Vec<i64>, Vec<PointOp>)> {
+    proptest::collection::vec(-50i64..50, 1..16).prop_flat_map(|values| {
+        let n = values.len();
+        let op = prop_oneof![
+            (0..n, -50i64..50).prop_map(|(i, v)| PointOp::Update(i, v)),
+            (0..n, 0..n).prop_map(|(a, b)| PointOp::Query(a.min(b), a.max(b) + 1)),
+        ];
+        (Just(values), proptest::collection::vec(op, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn point_update_matches_naive_vector((values, ops) in values_and_point_ops()) {
+        let mut naive = values.clone();
+        let sums: Vec<Sum> = values.iter().copied().map(Sum).collect();
+        let mut table = SqrtDecomposition::build(&sums);
+
+        for op in ops {
+            match op {
+                PointOp::Update(i, v) => {
+                    naive[i] = v;
+                    table.update(i, Sum(v));
+                }
+                PointOp::Query(l, r) => {
+                    let expected: i64 = naive[l..r].iter().sum();
+                    prop_assert_eq!(table.query(l, r).0, expected);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RangeOp {
+    RangeAdd(usize, usize, i64),
+    RangeSum(usize, usize),
+}
+
+fn values_and_range_ops() -> impl Strategy<Value = (Vec<i64>, Vec<RangeOp>)> {
+    proptest::collection::vec(-50i64..50, 1..16).prop_flat_map(|values| {
+        let n = values.len();
+        let op = prop_oneof![
+            (0..n, 0..n, -50i64..50).prop_map(|(a, b, delta)| RangeOp::RangeAdd(a.min(b), a.max(b) + 1, delta)),
+            (0..n, 0..n).prop_map(|(a, b)| RangeOp::RangeSum(a.min(b), a.max(b) + 1)),
+        ];
+        (Just(values), proptest::collection::vec(op, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn range_add_matches_naive_vector((values, ops) in values_and_range_ops()) {
+        let mut naive = values.clone();
+        let mut table = SqrtRangeAdd::build(&values);
+
+        for op in ops {
+            match op {
+                RangeOp::RangeAdd(l, r, delta) => {
+                    for v in &mut naive[l..r] {
+                        *v += delta;
+                    }
+                    table.range_add(l, r, delta);
+                }
+                RangeOp::RangeSum(l, r) => {
+                    let expected: i64 = naive[l..r].iter().sum();
+                    prop_assert_eq!(table.range_sum(l, r), expected);
+                }
+            }
+        }
+    }
+}
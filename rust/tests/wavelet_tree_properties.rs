@@ -0,0 +1,65 @@
+//! Cross-validates three different structures that all answer "count of
+//! elements `<= x` in `[l, r)`" and "`k`-th smallest in `[l, r)`" —
+//! [`WaveletTree`], [`MergeSortTree`], and a [`PersistentSegmentTree`]
+//! over per-prefix roots — against each other and against a naive scan.
+
+use proptest::prelude::*;
+
+use dsa::collections::merge_sort_tree::MergeSortTree;
+use dsa::collections::persistent_segment_tree::build_prefix_roots;
+use dsa::collections::wavelet_tree::WaveletTree;
+
+#[derive(Debug, Clone, Copy)]
+enum Query {
+    CountLe(usize, usize, i64),
+    KthSmallest(usize, usize, usize),
+}
+
+fn values_and_queries() -> impl Strategy<Value = (Vec<i64>, Vec<Query>)> {
+    proptest::collection::vec(-20i64..20, 1..24).prop_flat_map(|values| {
+        let n = values.len();
+        let query = prop_oneof![
+            (0..n, 0..n, -20i64..20).prop_map(|(a, b, x)| Query::CountLe(a.min(b), a.max(b) + 1, x)),
+            (0..n, 0..n, 0..n).prop_map(|(a, b, k)| {
+                let (l, r) = (a.min(b), a.max(b) + 1);
+                Query::KthSmallest(l, r, k % (r - l))
+            }),
+        ];
+        (Just(values), proptest::collection::vec(query, 0..30))
+    })
+}
+
+proptest! {
+    #[test]
+    fn wavelet_merge_sort_and_persistent_tree_all_agree((values, queries) in values_and_queries()) {
+        let wavelet = WaveletTree::build(&values);
+        let merge_sort = MergeSortTree::build(&values);
+        let (persistent, roots, sorted_values) = build_prefix_roots(&values);
+
+        for query in queries {
+            match query {
+                Query::CountLe(l, r, x) => {
+                    let expected = values[l..r].iter().filter(|&&v| v <= x).count();
+                    prop_assert_eq!(wavelet.count_le(l, r, x), expected);
+                    prop_assert_eq!(merge_sort.count_le(l, r, x), expected);
+
+                    let rank = sorted_values.partition_point(|&v| v <= x);
+                    let persistent_count =
+                        (persistent.query(roots[r], 0, rank).0 - persistent.query(roots[l], 0, rank).0) as usize;
+                    prop_assert_eq!(persistent_count, expected);
+                }
+                Query::KthSmallest(l, r, k) => {
+                    let mut sorted_slice = values[l..r].to_vec();
+                    sorted_slice.sort_unstable();
+                    let expected = sorted_slice[k];
+
+                    prop_assert_eq!(wavelet.kth_smallest(l, r, k), expected);
+                    prop_assert_eq!(merge_sort.kth_smallest(l, r, k), expected);
+
+                    let persistent_rank = persistent.kth_smallest(roots[l], roots[r], k);
+                    prop_assert_eq!(sorted_values[persistent_rank], expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Property-based tests for [`dsa::collections::weighted_dsu`]: random
+//! `union(u, v, w)`/`diff` sequences, checked against a naive reference
+//! that walks full (uncompressed) chains instead of path-compressing.
+
+use proptest::prelude::*;
+
+use dsa::collections::weighted_dsu::WeightedDsu;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Union(usize, usize, i64),
+    Diff(usize, usize),
+    Connected(usize, usize),
+}
+
+fn ops(n: usize) -> impl Strategy<Value = Vec<Op>> {
+    proptest::collection::vec(
+        prop_oneof![
+            (0..n, 0..n, -20i64..20).prop_map(|(u, v, w)| Op::Union(u, v, w)),
+            (0..n, 0..n).prop_map(|(u, v)| Op::Diff(u, v)),
+            (0..n, 0..n).prop_map(|(u, v)| Op::Connected(u, v)),
+        ],
+        0..60,
+    )
+}
+
+fn naive_find(parent: &[usize], potential: &[i64], mut x: usize) -> (usize, i64) {
+    let mut acc = 0;
+    while parent[x] != x {
+        acc += potential[x];
+        x = parent[x];
+    }
+    (x, acc)
+}
+
+fn naive_union(parent: &mut [usize], potential: &mut [i64], u: usize, v: usize, w: i64) -> bool {
+    let (ru, pu) = naive_find(parent, potential, u);
+    let (rv, pv) = naive_find(parent, potential, v);
+    if ru == rv {
+        return pv - pu == w;
+    }
+    parent[ru] = rv;
+    potential[ru] = pv - pu - w;
+    true
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_reference((n, ops) in (1usize..12).prop_flat_map(|n| (Just(n), ops(n)))) {
+        let mut dsu = WeightedDsu::new(n);
+        let mut parent: Vec<usize> = (0..n).collect();
+        let mut potential: Vec<i64> = vec![0; n];
+
+        for op in ops {
+            match op {
+                Op::Union(u, v, w) => {
+                    let expected = naive_union(&mut parent, &mut potential, u, v, w);
+                    prop_assert_eq!(dsu.union(u, v, w), expected);
+                }
+                Op::Diff(u, v) => {
+                    let (ru, pu) = naive_find(&parent, &potential, u);
+                    let (rv, pv) = naive_find(&parent, &potential, v);
+                    let expected = if ru == rv { Some(pv - pu) } else { None };
+                    prop_assert_eq!(dsu.diff(u, v), expected);
+                }
+                Op::Connected(u, v) => {
+                    let (ru, _) = naive_find(&parent, &potential, u);
+                    let (rv, _) = naive_find(&parent, &potential, v);
+                    prop_assert_eq!(dsu.connected(u, v), ru == rv);
+                }
+            }
+        }
+    }
+}